@@ -0,0 +1,309 @@
+//! This module provides [`DiscardAdvisor`] that estimates the candidate
+//! discards of the running round in the background.
+//!
+//! Estimating discards plays out thousands of rounds, so it runs on its own
+//! thread to keep the game loop responsive. Trials are run in batches and
+//! the estimates are refined after every batch, so that coarse estimates are
+//! shown quickly and get more precise while the player thinks. The estimates
+//! refer to cards by their index in hand, so they are tied to the hand along
+//! with the hands and discards left when the advisor was spawned. Stale
+//! estimates are detected once a hand is played, discarded or reordered.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use balatro_tui_core::{
+    card::Card,
+    engine::Engine,
+    error::CoreError,
+    rng::seeded_rng,
+    solver::{advise_discards, DiscardEstimate, Position},
+};
+use color_eyre::{eyre::eyre, Result};
+
+/// Number of trials used to estimate every candidate discard once the
+/// estimation has finished.
+const ADVISOR_TRIALS: usize = 512;
+
+/// Number of trials run between two refinements of the estimates.
+const ADVISOR_BATCH_TRIALS: usize = 16;
+
+/// Estimates of the candidate discards refined so far.
+#[derive(Clone, Debug)]
+pub struct Estimation {
+    /// Number of trials the estimates are based on.
+    pub trials: usize,
+    /// Estimates of candidate discards in recommended order.
+    pub estimates: Vec<DiscardEstimate>,
+}
+
+/// Result of a background estimation, with errors formatted as text.
+type EstimationResult = Result<Estimation, String>;
+
+/// Estimates the candidate discards of a round on a background thread.
+#[derive(Clone, Debug)]
+pub struct DiscardAdvisor {
+    /// Cards in hand when the advisor was spawned, in hand order.
+    hand: Vec<Card>,
+    /// Hands and discards left when the advisor was spawned. Every hand
+    /// played or discarded changes them, so along with the hand they identify
+    /// the state of the round.
+    resources: (usize, usize),
+    /// Result of the estimation, updated after every batch of trials.
+    result: Arc<Mutex<Option<EstimationResult>>>,
+}
+
+impl DiscardAdvisor {
+    /// Spawn a background thread estimating the candidate discards of the
+    /// running round of the given [`Engine`].
+    ///
+    /// The state of the round is copied, as the hand and deck of the engine
+    /// must not stay locked during estimation. Estimates are seeded by the seed
+    /// of the run and the state of the round, so that they are reproducible.
+    ///
+    /// The background thread stops once [`ADVISOR_TRIALS`] trials have been
+    /// run or the advisor has been dropped.
+    pub fn spawn(engine: &Engine) -> Result<Self> {
+        let snapshot = engine.snapshot()?;
+        let hand = engine
+            .run()
+            .round
+            .hand
+            .try_read()
+            .map_err(CoreError::from)?
+            .clone();
+        let deck = engine
+            .run()
+            .deck
+            .try_read()
+            .map_err(CoreError::from)?
+            .clone();
+        let thread_hand = hand.clone();
+        let resources = (snapshot.hands_left, snapshot.discards_left);
+        let result = Arc::new(Mutex::new(None));
+        let thread_result = Arc::clone(&result);
+
+        drop(thread::spawn(move || {
+            let mut rng = seeded_rng(&format!(
+                "{}-{}-{}",
+                snapshot.seed, snapshot.hands_left, snapshot.discards_left
+            ));
+            let position = Position {
+                hand: &thread_hand,
+                deck: &deck,
+                hands_left: snapshot.hands_left,
+                discards_left: snapshot.discards_left,
+                score: &snapshot.score,
+                target_score: &snapshot.target_score,
+            };
+            let mut probability_sums = HashMap::<Vec<usize>, f64>::new();
+            let mut trials = 0;
+            while trials < ADVISOR_TRIALS && Arc::strong_count(&thread_result) > 1 {
+                let estimation = advise_discards(position, ADVISOR_BATCH_TRIALS, &mut rng)
+                    .map(|batch| {
+                        trials = trials.saturating_add(ADVISOR_BATCH_TRIALS);
+                        merge_batch(&mut probability_sums, batch, trials)
+                    })
+                    .map_err(|err| err.to_string());
+                let failed = estimation.is_err();
+                if let Ok(mut guard) = thread_result.lock() {
+                    *guard = Some(estimation);
+                }
+                if failed {
+                    break;
+                }
+            }
+        }));
+
+        Ok(Self {
+            hand,
+            resources,
+            result,
+        })
+    }
+
+    /// Returns `true` if the estimates do not belong to the current state of
+    /// the given [`Engine`].
+    pub fn is_stale(&self, engine: &Engine) -> Result<bool> {
+        let round = &engine.run().round;
+        Ok(self.resources != (round.hands_count, round.discards_count)
+            || self.hand != *round.hand.try_read().map_err(CoreError::from)?)
+    }
+
+    /// Returns the estimates refined so far, if the first batch of trials has
+    /// finished.
+    pub fn estimates(&self) -> Result<Option<Estimation>> {
+        self.result
+            .lock()
+            .map_err(|err| eyre!(err.to_string()))?
+            .clone()
+            .transpose()
+            .map_err(|err| eyre!(err))
+    }
+}
+
+/// Adds the estimates of a batch of trials to the sums of probabilities of
+/// every candidate discard, and returns the averaged estimates after the given
+/// total number of trials.
+///
+/// Estimates are sorted as by [`advise_discards()`], from the most to the least
+/// likely to beat the blind, with ties broken in favour of keeping the hand,
+/// then discarding fewer cards.
+fn merge_batch(
+    probability_sums: &mut HashMap<Vec<usize>, f64>,
+    batch: Vec<DiscardEstimate>,
+    trials: usize,
+) -> Estimation {
+    for estimate in batch {
+        *probability_sums.entry(estimate.indices).or_default() += estimate.win_probability;
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "Intended: Trial counts are far below the precision limit of f64."
+    )]
+    let batches = trials.div_ceil(ADVISOR_BATCH_TRIALS).max(1) as f64;
+    let mut estimates = probability_sums
+        .iter()
+        .map(|(indices, probability_sum)| DiscardEstimate {
+            indices: indices.clone(),
+            win_probability: probability_sum / batches,
+        })
+        .collect::<Vec<_>>();
+    estimates.sort_by(|first, second| {
+        second
+            .win_probability
+            .total_cmp(&first.win_probability)
+            .then_with(|| first.indices.len().cmp(&second.indices.len()))
+            .then_with(|| first.indices.cmp(&second.indices))
+    });
+
+    Estimation { trials, estimates }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use balatro_tui_core::{action::Action, card::parse_hand, puzzle::Puzzle};
+
+    use super::*;
+
+    /// Waits for the advisor to run all trials and returns the cards of the
+    /// top recommended discard in the current hand of the engine.
+    fn recommended_cards(advisor: &DiscardAdvisor, engine: &Engine) -> Vec<Card> {
+        let estimation = loop {
+            if let Some(estimation) = advisor
+                .estimates()
+                .unwrap()
+                .filter(|estimation| estimation.trials >= ADVISOR_TRIALS)
+            {
+                break estimation;
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        let hand = engine.run().round.hand.try_read().unwrap().clone();
+
+        estimation.estimates[0]
+            .indices
+            .iter()
+            .map(|&idx| hand[idx])
+            .collect()
+    }
+
+    #[test]
+    fn reordering_the_hand_refreshes_recommended_cards() {
+        let puzzle = Puzzle {
+            name: "Third Ace".to_owned(),
+            hand: parse_hand("AS AH 2C").unwrap(),
+            draw_order: parse_hand("AD").unwrap(),
+            target_score: 100,
+            hands: 1,
+            discards: 1,
+            ..Puzzle::default()
+        };
+        let mut engine = Engine::with_puzzle(&puzzle).unwrap();
+        let advisor = DiscardAdvisor::spawn(&engine).unwrap();
+        let discard = parse_hand("2C").unwrap();
+
+        assert_eq!(recommended_cards(&advisor, &engine), discard);
+
+        let idx = engine
+            .run()
+            .round
+            .hand
+            .try_read()
+            .unwrap()
+            .iter()
+            .position(|card| discard.contains(card))
+            .unwrap();
+        drop(engine.apply(Action::MoveLeft(idx)).unwrap());
+
+        assert!(advisor.is_stale(&engine).unwrap());
+        let refreshed = DiscardAdvisor::spawn(&engine).unwrap();
+
+        assert!(!refreshed.is_stale(&engine).unwrap());
+        assert_eq!(recommended_cards(&refreshed, &engine), discard);
+    }
+
+    #[test]
+    fn merged_batches_average_probabilities_in_recommended_order() {
+        let mut probability_sums = HashMap::new();
+        let first_batch = vec![
+            DiscardEstimate {
+                indices: vec![0, 1],
+                win_probability: 0.5,
+            },
+            DiscardEstimate {
+                indices: vec![2],
+                win_probability: 0.25,
+            },
+            DiscardEstimate {
+                indices: vec![],
+                win_probability: 0.0,
+            },
+        ];
+        let second_batch = vec![
+            DiscardEstimate {
+                indices: vec![2],
+                win_probability: 0.75,
+            },
+            DiscardEstimate {
+                indices: vec![0, 1],
+                win_probability: 0.5,
+            },
+            DiscardEstimate {
+                indices: vec![],
+                win_probability: 0.5,
+            },
+        ];
+
+        drop(merge_batch(
+            &mut probability_sums,
+            first_batch,
+            ADVISOR_BATCH_TRIALS,
+        ));
+        let estimation = merge_batch(
+            &mut probability_sums,
+            second_batch,
+            ADVISOR_BATCH_TRIALS * 2,
+        );
+
+        assert_eq!(estimation.trials, ADVISOR_BATCH_TRIALS * 2);
+        assert_eq!(
+            estimation
+                .estimates
+                .iter()
+                .map(|estimate| (estimate.indices.clone(), estimate.win_probability))
+                .collect::<Vec<_>>(),
+            vec![
+                (vec![2], 0.5_f64),
+                (vec![0, 1], 0.5_f64),
+                (vec![], 0.25_f64)
+            ]
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! This module provides the challenges bundled with the game and
+//! [`ChallengePicker`] to choose one of them.
+//!
+//! Challenges are defined as data in `challenges.toml` at the root of the
+//! crate, which is embedded into the binary.
+
+use std::collections::BTreeSet;
+
+use balatro_tui_core::challenge::Challenge;
+use color_eyre::eyre::{Context, OptionExt, Result};
+use serde::Deserialize;
+
+/// Definitions of the bundled challenges.
+const BUNDLED_CHALLENGES: &str = include_str!("../challenges.toml");
+
+/// Root of the bundled challenges file.
+#[derive(Clone, Debug, Deserialize)]
+struct ChallengeSet {
+    /// Challenges in the order in which they are listed.
+    challenges: Vec<Challenge>,
+}
+
+/// Returns the challenges bundled with the game.
+pub fn bundled() -> Result<Vec<Challenge>> {
+    Ok(toml::from_str::<ChallengeSet>(BUNDLED_CHALLENGES)
+        .wrap_err("Bundled challenges are invalid.")?
+        .challenges)
+}
+
+/// Returns the bundled challenge with the given identifier, if any.
+pub fn find(id: &str) -> Result<Option<Challenge>> {
+    Ok(bundled()?.into_iter().find(|challenge| challenge.id == id))
+}
+
+/// State of the challenge picker screen.
+#[derive(Clone, Debug)]
+pub struct ChallengePicker {
+    /// Challenges that can be picked.
+    pub challenges: Vec<Challenge>,
+    /// Identifiers of challenges completed by the player.
+    pub completed: BTreeSet<String>,
+    /// Index of the highlighted challenge.
+    pub highlighted: usize,
+}
+
+impl ChallengePicker {
+    /// Create a new [`ChallengePicker`] over the bundled challenges.
+    pub fn new(completed: BTreeSet<String>) -> Result<Self> {
+        Ok(Self {
+            challenges: bundled()?,
+            completed,
+            highlighted: 0,
+        })
+    }
+
+    /// Highlight the next challenge, cycling back to the first one.
+    pub fn move_next(&mut self) {
+        self.highlighted = self
+            .highlighted
+            .saturating_add(1)
+            .checked_rem(self.challenges.len())
+            .unwrap_or(0);
+    }
+
+    /// Highlight the previous challenge, cycling to the last one.
+    pub fn move_prev(&mut self) {
+        self.highlighted = self
+            .highlighted
+            .checked_sub(1)
+            .unwrap_or_else(|| self.challenges.len().saturating_sub(1));
+    }
+
+    /// Returns the highlighted challenge.
+    pub fn picked(&self) -> Result<&Challenge> {
+        self.challenges
+            .get(self.highlighted)
+            .ok_or_eyre("No challenge available to pick.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use balatro_tui_core::engine::Engine;
+
+    use super::*;
+
+    #[test]
+    fn bundled_challenges_start_runs() {
+        let challenges = bundled().unwrap();
+        let ids = challenges
+            .iter()
+            .map(|challenge| challenge.id.as_str())
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(ids.len(), challenges.len(), "Challenge ids must be unique");
+        for challenge in &challenges {
+            drop(Engine::with_challenge(challenge, "SEED".to_owned()).unwrap());
+        }
+    }
+
+    #[test]
+    fn picker_cycles() {
+        let mut picker = ChallengePicker::new(BTreeSet::new()).unwrap();
+        let last = picker.challenges.len() - 1;
+
+        picker.move_prev();
+        assert_eq!(picker.highlighted, last);
+        picker.move_next();
+        assert_eq!(picker.highlighted, 0);
+        assert_eq!(picker.picked().unwrap().id, "one_shot");
+    }
+}
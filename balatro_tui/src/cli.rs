@@ -0,0 +1,95 @@
+//! This module provides command line argument definitions for the game.
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use crate::{protocol::Protocol, simulate::SimulateArgs};
+
+/// Command line arguments for Balatro TUI.
+#[derive(Clone, Debug, Default, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Continue the last saved run instead of starting a new one.
+    #[arg(short, long = "continue")]
+    pub resume: bool,
+    /// Play back a replay file instead of playing. The replay of the last run
+    /// is stored as `replay.json` in the game data directory.
+    ///
+    /// During playback, use Space to pause/resume, `n` to step through actions
+    /// and `+`/`-` to change the playback speed.
+    #[arg(long, value_name = "FILE", conflicts_with = "resume")]
+    pub replay: Option<PathBuf>,
+    /// Seed of a new run. A random seed is used if not provided.
+    #[arg(long, conflicts_with_all = ["resume", "replay"])]
+    pub seed: Option<String>,
+    /// Pick a challenge to play instead of a standard run. Completed
+    /// challenges are recorded in the player profile.
+    #[arg(long, conflicts_with_all = ["resume", "replay", "seed", "deck_file"])]
+    pub challenges: bool,
+    /// Solve the puzzle defined in a TOML or JSON puzzle file instead of
+    /// playing a run. See `balatro_tui/puzzles` for a puzzle pack.
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["resume", "replay", "seed", "deck_file", "challenges"]
+    )]
+    pub puzzle: Option<PathBuf>,
+    /// Play a new run with the deck defined in a TOML or JSON deck file
+    /// instead of the standard deck. See `balatro_tui/decks` for examples.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["resume", "replay"])]
+    pub deck_file: Option<PathBuf>,
+    /// Play over standard input and output using the given protocol instead
+    /// of the terminal interface.
+    #[arg(
+        long,
+        value_enum,
+        conflicts_with_all = ["resume", "replay", "deck_file", "challenges", "puzzle"]
+    )]
+    pub protocol: Option<Protocol>,
+    /// Render using ASCII characters only, for terminals without Unicode
+    /// support. ASCII mode is also used when the locale is not UTF-8.
+    #[arg(long)]
+    pub ascii: bool,
+    /// Subcommand to run instead of playing.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Subcommands for Balatro TUI.
+#[derive(Clone, Debug, Subcommand)]
+pub enum Command {
+    /// Play many headless games with a strategy and print statistics about
+    /// them.
+    Simulate(SimulateArgs),
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::error::ErrorKind;
+
+    use super::*;
+
+    #[test]
+    fn protocol_conflicts_with_run_modes() {
+        for flag in [
+            "--continue",
+            "--replay=replay.json",
+            "--deck-file=deck.toml",
+            "--challenges",
+            "--puzzle=puzzle.toml",
+        ] {
+            assert_eq!(
+                Cli::try_parse_from(["balatro_tui", "--protocol=jsonl", flag])
+                    .unwrap_err()
+                    .kind(),
+                ErrorKind::ArgumentConflict,
+                "--protocol must conflict with {flag}"
+            );
+        }
+
+        let cli = Cli::try_parse_from(["balatro_tui", "--protocol=jsonl", "--seed=ABC"]).unwrap();
+
+        assert!(cli.protocol.is_some());
+    }
+}
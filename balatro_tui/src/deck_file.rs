@@ -0,0 +1,80 @@
+//! This module loads custom decks from deck files.
+//!
+//! A deck file is a [`DeckDefinition`] written in TOML or JSON, as decided by
+//! the file extension. Cards are listed in card notation along with an optional
+//! count, which defaults to `1`.
+//!
+//! ```toml
+//! name = "Aces"
+//! cards = [
+//!     { card = "AS", count = 2 },
+//!     { card = "A♥" },
+//! ]
+//! ```
+//!
+//! Invalid cards are reported with the line and column at which they occur.
+//! Example deck files are bundled in the `decks` directory of the crate.
+
+use std::path::Path;
+
+use balatro_tui_core::{
+    deck::{Deck, DeckDefinition},
+    engine::STANDARD_HAND_SIZE,
+};
+use color_eyre::eyre::{bail, Result};
+
+use crate::storage::read_definition;
+
+/// Loads a deck from a deck file.
+///
+/// The deck must hold at least a full hand of cards.
+pub fn load(path: &Path) -> Result<Deck> {
+    let deck = read_definition::<DeckDefinition>(path)?.build();
+
+    if deck.len() < STANDARD_HAND_SIZE {
+        bail!(
+            "Deck file {} must contain at least {STANDARD_HAND_SIZE} cards, found {}.",
+            path.display(),
+            deck.len()
+        );
+    }
+
+    Ok(deck)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::parse_definition;
+
+    #[test]
+    fn bundled_decks_are_valid() {
+        let decks = [
+            (include_str!("../decks/all_face_cards.toml"), "toml", 48),
+            (include_str!("../decks/flush.json"), "json", 52),
+        ];
+
+        for (content, extension, size) in decks {
+            assert_eq!(
+                parse_definition::<DeckDefinition>(content, extension)
+                    .unwrap()
+                    .build()
+                    .len(),
+                size
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_card_reports_line() {
+        let error = parse_definition::<DeckDefinition>(
+            "name = \"Broken\"\ncards = [\n    { card = \"KX\" },\n]\n",
+            "toml",
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(error.contains("line 3"), "{error}");
+        assert!(error.contains("invalid card `KX`"), "{error}");
+    }
+}
@@ -99,7 +99,6 @@ impl EventHandler {
         let mut tick = interval(tick_duration);
 
         #[expect(
-            clippy::pattern_type_mismatch,
             clippy::ignored_unit_patterns,
             clippy::integer_division_remainder_used,
             reason = "False positive: Tokio's select! macro has different semantics than match statements."
@@ -1,69 +1,266 @@
-//! [`Game`] is a logical wrapper around the main flow of the game, ie, [`Run`].
-//! [`Game`] provides additional functionalities outside of the lifetime of an
-//! instance of [`Run`].
+//! [`Game`] is a TUI adapter over the headless game [`Engine`].
 //!
-//! The entrypoint of game is [`Game::new()`] to create the instance of a new
-//! game and [`Game::start()`] to spawn a new instance of a running game.
+//! [`Game`] translates input events into [`Action`] for the [`Engine`] and
+//! renders its state, and provides additional functionalities outside of the
+//! lifetime of an instance of [`Run`].
+//!
+//! The entrypoint of game is [`Game::title()`] to show the title screen,
+//! [`Game::new()`] to create the instance of a new game,
+//! [`Game::challenges()`] to pick a [`Challenge`] to play,
+//! [`Game::with_puzzle()`] to solve a [`Puzzle`],
+//! [`Game::resume()`] to continue a saved run or [`Game::playback()`] to watch
+//! a [`Replay`], and [`Game::start()`] to spawn a new instance of a running
+//! game.
+//!
+//! The run is auto-saved at safe points, ie, at the start of a round and after
+//! every change to the hand, eg, a hand played or discarded. As there is no
+//! shop yet and a run is a single round, saving only at the start of a round
+//! would lose the whole run on quitting, so the state between two hands, which
+//! is fully captured by the [`Run`], is treated as a safe point as well. The
+//! saved run is removed once the run is over. Every [`Action`] applied to the
+//! run is recorded by the [`Engine`] into a [`Replay`] which is saved alongside
+//! the run and kept after the run is over. Completed challenges and lifetime
+//! stats of runs are recorded in the player [`Profile`]. Puzzles and played
+//! back replays are not recorded.
+//!
+//! Keys are mapped to [`KeyAction`] by the [`KeyMap`] loaded from the
+//! keybindings config, and the game is rendered with the [`Theme`] loaded from
+//! the theme config. By default, a hint of the best play available in hand
+//! can be toggled with `h` and the cards of the best play can be selected with
+//! `b`. An overlay estimating the chance of beating the blind after the best
+//! candidate discards can be toggled with `a`. The lifetime stats of the
+//! player can be shown with `p`, the cards left in the deck with `v`, and the
+//! scoring hands and blinds of the run with `i`. The hand is sorted by rank or
+//! by suit, toggled with `s`, and the card under the cursor can be moved with
+//! `<` and `>`.
+//!
+//! Cards in hand can also be played with the mouse. Hovering a card moves the
+//! cursor to it and clicking a card toggles its selection. Clicking the
+//! buttons below the hand acts as pressing the key of their [`KeyAction`].
+//!
+//! The run is rendered in a compact layout with a condensed header and
+//! narrower cards on terminals smaller than 150x40. On terminals smaller than
+//! 80x24, the game is paused behind an overlay asking to enlarge the terminal.
+//!
+//! Screens such as the title screen and the pause menu are stacked over the
+//! run as [`Screen`] overlays, and the topmost screen receives the input. The
+//! pause menu is opened with `Esc` or `q`, and `Ctrl-C` quits immediately.
 
 use std::{
     num::NonZeroUsize,
-    str::FromStr,
     sync::{Arc, RwLock},
+    time::Instant,
 };
 
 use balatro_tui_core::{
-    blind::Blind,
+    action::Action,
     card::Card,
-    deck::{Deck, DeckConstExt},
-    round::{Round, RoundProperties},
-    run::{Run, RunProperties, RunState},
-    scorer::Scorer,
+    challenge::Challenge,
+    deck::Deck,
+    engine::{Engine, Outcome, Snapshot, MAXIMUM_SELECTABLE_CARDS},
+    profile::Profile,
+    puzzle::Puzzle,
+    replay::Replay,
+    rng::random_seed,
+    run::{Run, RunState},
+    score::Score,
+    scorer::{Scorer, ScoringHand},
 };
 use balatro_tui_widgets::{
-    CardListWidget, CardListWidgetState, RoundInfoWidget, RoundScoreWidget, RunStatsWidget,
-    RunStatsWidgetState, ScorerPreviewWidget, ScorerPreviewWidgetState, SelectableList,
-    SplashScreenWidget,
+    ButtonWidget, CardListWidget, CardListWidgetState, ChallengeListWidget, DeckViewWidget,
+    DiscardAdvisorWidget, MenuWidget, PlayHintWidget, ProfileStatsWidget, RoundInfoWidget,
+    RoundScoreWidget, RunInfoWidget, RunStatsWidget, RunStatsWidgetState, ScorerPreviewWidget,
+    ScorerPreviewWidgetState, SelectableList, SplashScreenWidget, Theme, BUTTON_HEIGHT,
+    CARD_COMPACT_CONTENT_HEIGHT,
 };
 use color_eyre::{
-    eyre::{bail, Context, OptionExt},
+    eyre::{Context, OptionExt},
     Result,
 };
-use crossterm::event::{KeyCode, KeyModifiers};
-use rand::{
-    distributions::{Alphanumeric, DistString},
-    thread_rng,
-};
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
-    layout::{Constraint, Flex, Layout, Margin, Rect},
-    style::Color,
-    widgets::{Block, BorderType, Borders},
+    layout::{Constraint, Flex, Layout, Margin, Position, Rect, Size},
+    style::{Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
 use crate::{
+    advisor::DiscardAdvisor,
+    challenge::{self, ChallengePicker},
     event::{Event, EventHandler},
-    iter_index_ext::IterIndexExt,
+    keymap::{self, KeyAction, KeyMap},
+    menu::{Menu, PauseItem, SettingsItem, TitleItem},
+    playback::ReplayPlayer,
+    storage::{
+        clear_saved_run, has_saved_run, load_profile, load_saved_run, save_profile, save_replay,
+        save_run,
+    },
+    theme,
     tui::Tui,
 };
 
 /// Tick rate at which the game runs/receives updates.
 pub const TICK_RATE: u64 = 144;
 
-/// Maximum selectable cards to form a hand.
-///
-/// As per standard rules this is set to `5`.
-pub const MAXIMUM_SELECTABLE_CARDS: usize = 5;
+/// Name of the standard deck in the lifetime stats of the player.
+const STANDARD_DECK_NAME: &str = "Standard";
+
+/// Name of custom decks in the lifetime stats of the player.
+const CUSTOM_DECK_NAME: &str = "Custom";
+
+/// Height of the lifetime stats overlay.
+const STATS_HEIGHT: u16 = 20;
+
+/// Width of the lifetime stats overlay.
+const STATS_WIDTH: u16 = 50;
+
+/// Height of the deck view overlay.
+const DECK_VIEW_HEIGHT: u16 = 11;
+
+/// Width of the deck view overlay.
+const DECK_VIEW_WIDTH: u16 = 65;
+
+/// Height of the run info overlay.
+const RUN_INFO_HEIGHT: u16 = 26;
+
+/// Width of the run info overlay.
+const RUN_INFO_WIDTH: u16 = 60;
+
+/// Width of the pause and settings menu overlays.
+const MENU_WIDTH: u16 = 40;
+
+/// Height of the pause and settings menu overlays besides their items, ie,
+/// borders, help line and spacing.
+const MENU_CHROME_HEIGHT: u16 = 4;
+
+/// Splash shown on the title screen.
+const TITLE_SPLASH: &str = "Balatro";
+
+/// Buttons shown below the hand, along with the action they trigger when
+/// clicked.
+const BUTTONS: [(KeyAction, &str); 4] = [
+    (KeyAction::Play, "Play"),
+    (KeyAction::Discard, "Discard"),
+    (KeyAction::Sort, "Sort"),
+    (KeyAction::RunInfo, "Run Info"),
+];
+
+/// Width of the buttons shown below the hand.
+const BUTTON_WIDTH: u16 = 14;
+
+/// Minimum terminal size to render the run in [`RunLayout::Full`].
+const FULL_LAYOUT_MIN_SIZE: Size = Size::new(150, 40);
+
+/// Minimum terminal size to render the run in [`RunLayout::Compact`].
+const COMPACT_LAYOUT_MIN_SIZE: Size = Size::new(80, 24);
+
+/// Height of the condensed header of [`RunLayout::Compact`], including
+/// borders.
+const COMPACT_HEADER_HEIGHT: u16 = 4;
+
+/// Layouts the game is rendered in, depending on the size of the terminal.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum RunLayout {
+    /// Round details are shown in a side panel next to the hand.
+    #[default]
+    Full,
+    /// Round details are condensed into a header above the hand, and cards are
+    /// rendered narrower.
+    Compact,
+    /// The terminal is too small to render the game. The game is paused until
+    /// the terminal is enlarged.
+    TooSmall,
+}
+
+impl RunLayout {
+    /// Returns the layout fitting in a terminal of the given size.
+    const fn fitting(size: Size) -> Self {
+        if size.width >= FULL_LAYOUT_MIN_SIZE.width && size.height >= FULL_LAYOUT_MIN_SIZE.height {
+            Self::Full
+        } else if size.width >= COMPACT_LAYOUT_MIN_SIZE.width
+            && size.height >= COMPACT_LAYOUT_MIN_SIZE.height
+        {
+            Self::Compact
+        } else {
+            Self::TooSmall
+        }
+    }
+}
+
+/// Screens shown as overlays over the run.
+#[derive(Clone, Debug)]
+pub enum Screen {
+    /// Title screen, shown in place of the run.
+    Title(Menu<TitleItem>),
+    /// Challenge picker, shown in place of the run. The run of the picked
+    /// challenge starts once a challenge is picked.
+    Challenges(ChallengePicker),
+    /// Pause menu shown over the run.
+    Pause(Menu<PauseItem>),
+    /// Settings menu.
+    Settings(Menu<SettingsItem>),
+    /// Lifetime stats of the player.
+    Stats,
+    /// Composition of the deck, ie, the cards in hand, played or discarded and
+    /// left in the draw pile.
+    Deck,
+    /// Scoring hands of the run and blinds of the current ante.
+    RunInfo,
+}
+
+impl Screen {
+    /// Returns `true` if the screen is shown in place of the run rather than
+    /// over it.
+    const fn hides_run(&self) -> bool {
+        matches!(*self, Self::Title(_) | Self::Challenges(_))
+    }
+}
 
 /// [`Game`] struct holds the state for the running game, including [`Run`]
 /// surrounding states, that allow early closure of a run.
 #[derive(Clone, Debug)]
 pub struct Game {
-    /// An instance of a [`Run`]. The run is the actual handler for most
-    /// operations. [`Game`] simply forwards the requests to [`Run`] to handle.
-    run: Run,
+    /// An instance of the [`Engine`] driving the [`Run`]. [`Game`] simply
+    /// forwards the requests to [`Engine`] to handle.
+    engine: Engine,
     /// A cached card list widget state. This caching is required for showing
     /// selection and hovering for [`CardListWidget`].
     card_list_widget_state: Option<CardListWidgetState>,
+    /// Plays back a [`Replay`] in place of user input when set. Auto-saving is
+    /// disabled during playback.
+    playback: Option<ReplayPlayer>,
+    /// Whether the hint panel showing the best play in hand is visible.
+    show_hint: bool,
+    /// Whether the discard advisor overlay is visible.
+    show_advisor: bool,
+    /// Discard advisor estimating the current round, if the overlay has been
+    /// shown.
+    advisor: Option<DiscardAdvisor>,
+    /// Challenge of the run, if any.
+    challenge: Option<Challenge>,
+    /// Stack of [`Screen`] overlays shown over the run. The topmost screen
+    /// receives the input events.
+    screens: Vec<Screen>,
+    /// Player profile, loaded on first use.
+    profile: Option<Profile>,
+    /// Instant at which the run started. Not known for resumed runs.
+    run_started: Option<Instant>,
+    /// Mapping of keys to actions of the player.
+    keymap: KeyMap,
+    /// Colors and glyphs used to render the game.
+    theme: Theme,
+    /// Areas of the buttons as last rendered, along with the action they
+    /// trigger when clicked. Used for hit-testing mouse clicks.
+    button_areas: Vec<(KeyAction, Rect)>,
+    /// Last known position of the mouse, used to highlight hovered buttons.
+    mouse_position: Option<Position>,
+    /// Layout fitting the terminal as of the last draw.
+    run_layout: RunLayout,
+    /// Whether the game loop is to be exited.
+    quit: bool,
 }
 
 impl Game {
@@ -74,42 +271,120 @@ impl Game {
     #[must_use = "Created game instance must be used."]
     #[inline]
     pub fn new() -> Result<Self> {
-        let deck = Arc::new(RwLock::new(Deck::standard()));
-        let max_discards = 3;
-        let max_hands = 3;
-        let run_properties = RunProperties {
-            hand_size: 10,
-            max_discards,
-            max_hands,
-            seed: Alphanumeric.sample_string(&mut thread_rng(), 16),
-            starting_money: 10,
-        };
-        let round_properties = RoundProperties {
-            hand_size: 10,
-            ante: NonZeroUsize::new(1).ok_or_eyre("Could not create ante number")?,
-            round_number: NonZeroUsize::new(1).ok_or_eyre("Could not create round number")?,
-        };
-        Ok(Self {
-            run: Run {
-                deck: Arc::clone(&deck),
-                run_state: RunState::Running,
-                money: run_properties.starting_money,
-                properties: run_properties,
-                round: Round {
-                    blind: Blind::Small,
-                    deck: Arc::clone(&deck),
-                    discards_count: max_discards,
-                    hand: Arc::new(RwLock::new(vec![])),
-                    hands_count: max_hands,
-                    history: vec![],
-                    properties: round_properties,
-                    score: 0,
-                },
-                upcoming_round_number: NonZeroUsize::new(1)
-                    .ok_or_eyre("Could not create upcoming round number")?,
-            },
+        Self::with_seed(random_seed())
+    }
+
+    /// Create a new instance of a game whose run is seeded with the given seed.
+    ///
+    /// Games created with the same seed deal the same cards given the same
+    /// actions.
+    #[must_use = "Created game instance must be used."]
+    #[inline]
+    pub fn with_seed(seed: String) -> Result<Self> {
+        Ok(Self::from_engine(Engine::new(seed)?))
+    }
+
+    /// Create a new instance of a game whose run is seeded with the given seed
+    /// and played with the given deck instead of the standard deck.
+    #[must_use = "Created game instance must be used."]
+    #[inline]
+    pub fn with_deck(seed: String, deck: Deck) -> Result<Self> {
+        Ok(Self::from_engine(Engine::with_deck(seed, deck)?))
+    }
+
+    /// Create a new instance of a game whose run is the given [`Puzzle`].
+    #[must_use = "Created game instance must be used."]
+    #[inline]
+    pub fn with_puzzle(puzzle: &Puzzle) -> Result<Self> {
+        Ok(Self::from_engine(Engine::with_puzzle(puzzle)?))
+    }
+
+    /// Create an instance of a game that shows the title screen. A run starts
+    /// once it is picked from the title screen.
+    #[must_use = "Created game instance must be used."]
+    #[inline]
+    pub fn title() -> Result<Self> {
+        let mut game = Self::new()?;
+        game.screens.push(Self::title_screen()?);
+        Ok(game)
+    }
+
+    /// Create an instance of a game that shows the challenge picker over the
+    /// title screen. The run of the picked challenge starts once a challenge
+    /// is picked.
+    #[must_use = "Created game instance must be used."]
+    #[inline]
+    pub fn challenges() -> Result<Self> {
+        let mut game = Self::title()?;
+        game.open_challenge_picker()?;
+        Ok(game)
+    }
+
+    /// Create an instance of a game that continues an already started [`Run`],
+    /// eg, one loaded from a save, along with the [`Replay`] recorded so far.
+    #[must_use = "Created game instance must be used."]
+    #[inline]
+    pub fn resume(run: Run, replay: Replay) -> Result<Self> {
+        let challenge = replay
+            .challenge
+            .as_deref()
+            .map(challenge::find)
+            .transpose()?
+            .flatten();
+        let mut game = Self::from_engine(Engine::resume(run, replay));
+        game.challenge = challenge;
+        game.run_started = None;
+        Ok(game)
+    }
+
+    /// Create an instance of a game driven by the given [`Engine`].
+    #[must_use = "Created game instance must be used."]
+    #[inline]
+    pub fn from_engine(engine: Engine) -> Self {
+        Self {
+            engine,
             card_list_widget_state: None,
-        })
+            playback: None,
+            show_hint: false,
+            show_advisor: false,
+            advisor: None,
+            challenge: None,
+            screens: vec![],
+            profile: None,
+            run_started: Some(Instant::now()),
+            keymap: KeyMap::default(),
+            theme: Theme::default(),
+            button_areas: vec![],
+            mouse_position: None,
+            run_layout: RunLayout::default(),
+            quit: false,
+        }
+    }
+
+    /// Create an instance of a game that plays back the given [`Replay`]
+    /// instead of accepting user input.
+    #[must_use = "Created game instance must be used."]
+    #[inline]
+    pub fn playback(replay: Replay) -> Result<Self> {
+        let challenge = replay
+            .challenge
+            .as_deref()
+            .map(|id| {
+                challenge::find(id)?
+                    .ok_or_eyre(format!("Challenge {id} of the replay is not available."))
+            })
+            .transpose()?;
+        let mut game = Self::from_engine(Self::replay_engine(&replay, challenge.as_ref())?);
+        game.challenge = challenge;
+        game.playback = Some(ReplayPlayer::new(replay));
+        Ok(game)
+    }
+
+    /// Force rendering using ASCII characters only, eg, for terminals without
+    /// Unicode support. Otherwise ASCII mode is picked by the theme config.
+    #[inline]
+    pub const fn set_ascii(&mut self, ascii: bool) {
+        self.theme.ascii = ascii;
     }
 
     /// Main entrypoint of the game.
@@ -117,21 +392,23 @@ impl Game {
     /// Creates a new [`Tui`] instance and initializes the [`EventHandler`].
     /// Runs the round initialization routine and the game `update` loop
     pub async fn start(&mut self) -> Result<()> {
+        // Load configs before entering TUI to report errors on the terminal
+        self.keymap = keymap::load()?;
+        self.theme = theme::load(self.theme.ascii)?;
+
         // Enter TUI
         let mut tui = Tui::new()?;
         tui.enter().wrap_err("Error occurred while entering Tui")?;
+        self.run_layout = RunLayout::fitting(tui.size()?);
 
         // Spawn EventHandler
         let mut event_handler = EventHandler::new(TICK_RATE);
 
-        // Start a run
-        self.run.start()?;
+        // Save at round start
+        self.autosave()?;
 
         // Cached card state
-        self.card_list_widget_state = Some(
-            CardListWidgetState::from(Arc::<RwLock<Vec<Card>>>::clone(&self.run.round.hand))
-                .selection_limit(Some(MAXIMUM_SELECTABLE_CARDS))?,
-        );
+        self.reset_card_list_state()?;
 
         // Draw loop
         loop {
@@ -141,13 +418,27 @@ impl Game {
 
             let continue_game = Self::evaluate_exit(event, |ev: Event| {
                 send_result = event_handler.send_event(ev);
-            })?;
+            });
 
             send_result?;
 
-            self.handle_run_events(event)?;
-            self.handle_round_events(event)?;
-            self.handle_deck_events(event)?;
+            if self.run_layout == RunLayout::TooSmall {
+                // Input and playback are paused until the terminal is enlarged
+            } else if let Some(player) = self.playback.as_mut() {
+                if let Some(action) = player.handle_event(event) {
+                    self.apply_action(action)?;
+                } else if let Event::Key(key_event) = event {
+                    self.quit = self.keymap.action(key_event) == Some(KeyAction::Menu);
+                } else {
+                    // Other events are not used during playback
+                }
+            } else if !self.screens.is_empty() {
+                self.handle_screen_events(event)?;
+            } else {
+                self.handle_mouse_events(event);
+                self.handle_round_events(event)?;
+                self.handle_deck_events(event)?;
+            }
 
             let mut draw_result: Result<()> = Ok(());
 
@@ -159,7 +450,7 @@ impl Game {
 
             draw_result?;
 
-            if !continue_game {
+            if !continue_game || self.quit {
                 break;
             }
         }
@@ -173,33 +464,69 @@ impl Game {
     /// Draw loop for game state
     ///
     /// Runs every tick provided by the rendering interface.
+    fn draw(&mut self, frame: &mut Frame<'_>, area: Rect) -> Result<()> {
+        self.run_layout = RunLayout::fitting(area.as_size());
+        if self.run_layout == RunLayout::TooSmall {
+            self.button_areas.clear();
+            self.draw_enlarge_overlay(frame, area);
+            return Ok(());
+        }
+
+        frame.render_widget(Block::new().style(self.theme.style()), area);
+        if !self.screens.iter().any(Screen::hides_run) {
+            self.draw_run(frame, area)?;
+        }
+        for screen in &self.screens {
+            self.draw_screen(frame, area, screen)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the run.
     #[expect(
         clippy::too_many_lines,
         reason = "Refactor: Create CoreRenderer structs to render core widgets."
     )]
-    fn draw(&mut self, frame: &mut Frame<'_>, area: Rect) -> Result<()> {
+    fn draw_run(&mut self, frame: &mut Frame<'_>, area: Rect) -> Result<()> {
         // Prepare variables
-        let scoring_hand_opt = Scorer::get_scoring_hand(
-            &self
-                .run
-                .round
-                .hand
-                .try_read()
-                .or_else(|err| bail!("Could not attain read lock for hand: {err}."))?
-                .peek_at_index_set(
-                    &self
-                        .card_list_widget_state
-                        .as_ref()
-                        .ok_or_eyre("Card list widget state not initialized yet.")?
-                        .selected,
-                )?,
-        )?
-        .0;
-        let (chips, multiplier) = if let Some(scoring_hand) = scoring_hand_opt {
+        let snapshot = self.engine.snapshot()?;
+        let face_down = self.engine.is_face_down();
+        let (chips, multiplier) = if let Some(scoring_hand) = snapshot.scoring_hand {
             Scorer::get_chips_and_multiplier(scoring_hand)?
         } else {
             (0, 0)
         };
+        let puzzle = self.engine.replay().puzzle.as_ref();
+        let (blind_text, win_message, loss_message) = if let Some(run_puzzle) = puzzle {
+            (
+                run_puzzle.name.clone(),
+                format!("You solved the {} puzzle!", run_puzzle.name),
+                format!("You failed the {} puzzle!", run_puzzle.name),
+            )
+        } else if let Some(run_challenge) = self.challenge.as_ref() {
+            (
+                snapshot.blind.to_string(),
+                format!("You completed the {} challenge!", run_challenge.name),
+                "You lost the game!".to_owned(),
+            )
+        } else {
+            (
+                snapshot.blind.to_string(),
+                "You won the game!".to_owned(),
+                "You lost the game!".to_owned(),
+            )
+        };
+
+        let compact = self.run_layout == RunLayout::Compact;
+        // Compact widgets render their content without any margin
+        let content_area = |container_area: Rect| {
+            if compact {
+                container_area
+            } else {
+                container_area.inner(Margin::new(1, 1))
+            }
+        };
 
         // Prepare areas
         let mut splash_state_area = Layout::vertical([Constraint::Ratio(2, 3)])
@@ -208,117 +535,222 @@ impl Game {
         splash_state_area = Layout::horizontal([Constraint::Ratio(2, 3)])
             .flex(Flex::Center)
             .areas::<1>(splash_state_area)[0];
-        let [meta_area, play_area] =
-            Layout::horizontal([Constraint::Percentage(25), Constraint::Fill(1)]).areas(area);
-        let [
-            round_info_area,
-            round_score_area,
-            scoring_area,
-            run_stats_area,
-        ] = Layout::vertical([
-            Constraint::Length(15),
-            Constraint::Length(9),
-            Constraint::Length(12),
-            Constraint::Length(17),
+        let [meta_area, play_area] = if compact {
+            Layout::vertical([
+                Constraint::Length(COMPACT_HEADER_HEIGHT),
+                Constraint::Fill(1),
+            ])
+            .areas(area)
+        } else {
+            Layout::horizontal([Constraint::Percentage(25), Constraint::Fill(1)]).areas(area)
+        };
+        let [round_info_area, round_score_area, scoring_area, run_stats_area] = if compact {
+            let [upper_header_area, lower_header_area] =
+                Layout::vertical([Constraint::Length(1); 2])
+                    .areas(meta_area.inner(Margin::new(2, 1)));
+            let [round_info_header_area, round_score_header_area] =
+                Layout::horizontal([Constraint::Fill(3), Constraint::Fill(2)])
+                    .areas(upper_header_area);
+            let [run_stats_header_area, scoring_header_area] =
+                Layout::horizontal([Constraint::Fill(3), Constraint::Fill(2)])
+                    .areas(lower_header_area);
+            [
+                round_info_header_area,
+                round_score_header_area,
+                scoring_header_area,
+                run_stats_header_area,
+            ]
+        } else {
+            Layout::vertical([
+                Constraint::Length(15),
+                Constraint::Length(9),
+                Constraint::Length(12),
+                Constraint::Length(17),
+            ])
+            .flex(Flex::Center)
+            .areas(meta_area.inner(Margin::new(1, 0)))
+        };
+        let [playback_area, mut overlay_area, deck_area, buttons_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(
+                if compact {
+                    CARD_COMPACT_CONTENT_HEIGHT
+                } else {
+                    10
+                },
+            ),
+            Constraint::Length(BUTTON_HEIGHT),
         ])
-        .flex(Flex::Center)
-        .areas(meta_area.inner(Margin::new(1, 0)));
-        let [_, deck_area] =
-            Layout::vertical([Constraint::Fill(1), Constraint::Length(10)]).areas(play_area);
+        .areas(play_area);
+        let button_areas = Layout::horizontal([Constraint::Length(BUTTON_WIDTH); BUTTONS.len()])
+            .flex(Flex::Center)
+            .spacing(2)
+            .areas::<{ BUTTONS.len() }>(buttons_area);
+        overlay_area = Layout::horizontal([Constraint::Percentage(50)])
+            .flex(Flex::Center)
+            .areas::<1>(overlay_area)[0];
+        let [hint_area, advisor_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(overlay_area);
 
         // Render containers
-        frame.render_widget(
-            Block::new().borders(Borders::LEFT | Borders::RIGHT),
-            meta_area,
-        );
-        frame.render_widget(
-            Block::bordered().border_type(BorderType::Rounded),
-            round_info_area,
-        );
-        frame.render_widget(
-            Block::bordered().border_type(BorderType::Rounded),
-            round_score_area,
-        );
-        frame.render_widget(
-            Block::bordered().border_type(BorderType::Rounded),
-            scoring_area,
-        );
+        if compact {
+            frame.render_widget(self.theme.block(), meta_area);
+        } else {
+            frame.render_widget(
+                Block::new()
+                    .borders(Borders::LEFT | Borders::RIGHT)
+                    .border_set(self.theme.border_set(border::PLAIN))
+                    .border_style(Style::new().fg(self.theme.border)),
+                meta_area,
+            );
+            frame.render_widget(self.theme.block(), round_info_area);
+            frame.render_widget(self.theme.block(), round_score_area);
+            frame.render_widget(self.theme.block(), scoring_area);
+        }
 
         // Render widgets
         frame.render_widget(
             RoundInfoWidget::new()
-                .blind_color(Color::from_str(self.run.round.blind.get_color()?)?)
-                .blind_text(self.run.round.blind.to_string())
-                .reward(self.run.round.blind.get_reward()?)
-                .target_score(
-                    self.run
-                        .round
-                        .blind
-                        .get_target_score(self.run.round.properties.ante)?,
-                ),
-            round_info_area.inner(Margin::new(1, 1)),
+                .blind_color(self.theme.blind_color(snapshot.blind))
+                .blind_text(blind_text)
+                .reward(snapshot.blind.get_reward()?)
+                .target_score(snapshot.target_score)
+                .compact(compact)
+                .theme(self.theme),
+            content_area(round_info_area),
         );
         frame.render_stateful_widget(
-            RoundScoreWidget::new(),
-            round_score_area.inner(Margin::new(1, 1)),
-            &mut self.run.round.score,
+            RoundScoreWidget::new().compact(compact).theme(self.theme),
+            content_area(round_score_area),
+            &mut snapshot.score.clone(),
         );
         frame.render_stateful_widget(
-            ScorerPreviewWidget::new(),
-            scoring_area.inner(Margin::new(1, 1)),
+            ScorerPreviewWidget::new()
+                .compact(compact)
+                .theme(self.theme),
+            content_area(scoring_area),
             &mut ScorerPreviewWidgetState {
-                chips,
+                chips: Score::from(chips),
                 level: NonZeroUsize::new(1)
                     .ok_or_eyre("Unable to create a non zero usize for level")?,
-                multiplier,
-                scoring_hand_text: scoring_hand_opt.map(|scoring_hand| scoring_hand.to_string()),
+                multiplier: Score::from(multiplier),
+                scoring_hand_text: snapshot
+                    .scoring_hand
+                    .map(|scoring_hand| scoring_hand.to_string()),
             },
         );
         frame.render_stateful_widget(
-            RunStatsWidget::new(),
+            RunStatsWidget::new().compact(compact).theme(self.theme),
             run_stats_area,
             &mut RunStatsWidgetState {
-                hands: self.run.round.hands_count,
-                discards: self.run.round.discards_count,
-                money: self.run.money,
-                ante: self.run.round.properties.ante,
-                round: self.run.round.properties.round_number,
+                hands: snapshot.hands_left,
+                discards: snapshot.discards_left,
+                money: snapshot.money,
+                ante: snapshot.ante,
+                round: snapshot.round_number,
             },
         );
         frame.render_stateful_widget(
-            CardListWidget::new(),
+            CardListWidget::new()
+                .face_down(face_down)
+                .compact(compact)
+                .theme(self.theme),
             deck_area,
             self.card_list_widget_state
                 .as_mut()
                 .ok_or_eyre("Card list widget state not initialized yet.")?,
         );
+        if self.show_hint && !face_down && snapshot.run_state == RunState::Running {
+            frame.render_widget(
+                PlayHintWidget::new()
+                    .theme(self.theme)
+                    .play(self.engine.best_play()?.as_ref()),
+                hint_area,
+            );
+        }
+        if self.show_advisor && !face_down && snapshot.run_state == RunState::Running {
+            let is_stale = self
+                .advisor
+                .as_ref()
+                .map(|advisor| advisor.is_stale(&self.engine))
+                .transpose()?
+                .unwrap_or(true);
+            if is_stale {
+                self.advisor = Some(DiscardAdvisor::spawn(&self.engine)?);
+            }
+            let visible_hand = snapshot.hand.iter().flatten().copied().collect::<Vec<_>>();
+            let estimation = self
+                .advisor
+                .as_ref()
+                .map(DiscardAdvisor::estimates)
+                .transpose()?
+                .flatten();
+            frame.render_widget(
+                DiscardAdvisorWidget::new()
+                    .theme(self.theme)
+                    .hand(&visible_hand)
+                    .estimates(
+                        estimation
+                            .as_ref()
+                            .map(|refined| refined.estimates.as_slice()),
+                    )
+                    .trials(estimation.as_ref().map(|refined| refined.trials)),
+                advisor_area,
+            );
+        }
+        self.button_areas.clear();
+        if self.playback.is_none() && snapshot.run_state == RunState::Running {
+            for ((key_action, label), button_area) in BUTTONS.into_iter().zip(button_areas) {
+                #[expect(
+                    clippy::wildcard_enum_match_arm,
+                    reason = "Intended: Only buttons of engine actions can be disabled."
+                )]
+                let disabled = match key_action {
+                    KeyAction::Play => !self.engine.is_legal(Action::Play)?,
+                    KeyAction::Discard => !self.engine.is_legal(Action::Discard)?,
+                    _ => false,
+                };
+                frame.render_widget(
+                    ButtonWidget::new()
+                        .theme(self.theme)
+                        .label(label)
+                        .disabled(disabled)
+                        .hovered(
+                            self.mouse_position
+                                .is_some_and(|position| button_area.contains(position)),
+                        ),
+                    button_area,
+                );
+                self.button_areas.push((key_action, button_area));
+            }
+        }
+        if let Some(player) = self.playback.as_ref() {
+            frame.render_widget(Paragraph::new(player.status()).centered(), playback_area);
+        }
 
-        match self.run.run_state {
+        match snapshot.run_state {
             RunState::Running => (),
             RunState::Finished(win) => {
                 if win {
                     frame.render_stateful_widget(
                         SplashScreenWidget::new()
+                            .theme(self.theme)
                             .splash("Congratulations!")
-                            .message("You won the game!"),
+                            .message(&win_message),
                         splash_state_area,
-                        &mut vec![("Money collected", &self.run.money.to_string())],
+                        &mut vec![("Money collected", &snapshot.money.to_string())],
                     );
                 } else {
                     frame.render_stateful_widget(
                         SplashScreenWidget::new()
+                            .theme(self.theme)
                             .splash("Game Over")
-                            .message("You lost the game!"),
+                            .message(&loss_message),
                         splash_state_area,
                         &mut vec![
-                            (
-                                "Last round reached",
-                                &self.run.round.properties.round_number.to_string(),
-                            ),
-                            (
-                                "Last ante reached",
-                                &self.run.round.properties.ante.to_string(),
-                            ),
+                            ("Last round reached", &snapshot.round_number.to_string()),
+                            ("Last ante reached", &snapshot.ante.to_string()),
                         ],
                     );
                 }
@@ -330,9 +762,8 @@ impl Game {
 
     /// Event handler for handling game-specific input interface events.
     ///
-    /// Returns a [`Result<bool>`] where the boolean value indicates whether to
-    /// continue the game loop.
-    fn evaluate_exit<S>(event: Event, send: S) -> Result<bool>
+    /// Returns whether to continue the game loop.
+    fn evaluate_exit<S>(event: Event, send: S) -> bool
     where
         S: FnOnce(Event),
     {
@@ -342,121 +773,171 @@ impl Game {
         )]
         match event {
             Event::Key(key_event) => match key_event.code {
-                KeyCode::Esc | KeyCode::Char('q') => {
+                KeyCode::Char('c' | 'C') if key_event.modifiers == KeyModifiers::CONTROL => {
                     send(Event::Exit);
                 }
-                KeyCode::Char('c' | 'C') => {
-                    if key_event.modifiers == KeyModifiers::CONTROL {
-                        send(Event::Exit);
-                    }
-                }
                 _ => (),
             },
-            Event::Resize(x_size, y_size) => {
-                if y_size < 40 || x_size < 150 {
-                    bail!(
-                        "Terminal size was less than required to render game. Need at least 150x40 character screen to render."
-                    );
-                }
-            }
-            Event::Exit => return Ok(false),
+            Event::Exit => return false,
             _ => (),
         }
 
-        Ok(true)
+        true
     }
 
-    /// Event handler for handling run-specific input interface events.
-    fn handle_run_events(&mut self, event: Event) -> Result<()> {
-        if event == Event::Tick {
-            if self.run.round.hands_count == 0
-                && self.run.round.score
-                    < self
-                        .run
-                        .round
-                        .blind
-                        .get_target_score(self.run.round.properties.ante)?
-            {
-                self.run.run_state = RunState::Finished(false);
+    /// Event handler for handling mouse movement over the run. Hovering a card
+    /// moves the cursor to it.
+    fn handle_mouse_events(&mut self, event: Event) {
+        let Event::Mouse(mouse_event) = event else {
+            return;
+        };
+        let position = Position::new(mouse_event.column, mouse_event.row);
+        self.mouse_position = Some(position);
+
+        if mouse_event.kind == MouseEventKind::Moved {
+            if let Some(state) = self.card_list_widget_state.as_mut() {
+                if let Some(idx) = state.card_at(position) {
+                    state.pos = Some(idx);
+                }
             }
+        }
+    }
 
-            if self.run.round.score
-                >= self
-                    .run
-                    .round
-                    .blind
-                    .get_target_score(self.run.round.properties.ante)?
-            {
-                self.run.run_state = RunState::Finished(true);
+    /// Event handler for handling round-specific input interface events.
+    fn handle_round_events(&mut self, event: Event) -> Result<()> {
+        match self.input_action(event) {
+            Some(KeyAction::Play) => {
+                self.apply_action(Action::Play)?;
+            }
+            Some(KeyAction::Discard) => {
+                self.apply_action(Action::Discard)?;
+            }
+            Some(KeyAction::Sort) => {
+                let sort_order = self.engine.run().round.sort_order.toggled();
+                self.apply_action(Action::Sort(sort_order))?;
+            }
+            Some(KeyAction::Hint) => {
+                self.show_hint = !self.show_hint;
+            }
+            Some(KeyAction::BestPlay) => {
+                self.select_best_play()?;
             }
+            Some(KeyAction::Advisor) => {
+                self.show_advisor = !self.show_advisor;
+            }
+            Some(KeyAction::Stats) => {
+                self.open_stats()?;
+            }
+            Some(KeyAction::Deck) => {
+                self.screens.push(Screen::Deck);
+            }
+            Some(KeyAction::RunInfo) => {
+                self.screens.push(Screen::RunInfo);
+            }
+            Some(KeyAction::Menu) => {
+                self.screens.push(Screen::Pause(Menu::new(PauseItem::ALL)));
+            }
+            _ => (),
         }
 
         Ok(())
     }
 
-    /// Event handler for handling round-specific input interface events.
-    fn handle_round_events(&mut self, event: Event) -> Result<()> {
-        #[expect(
-            clippy::wildcard_enum_match_arm,
-            reason = "Intended: Unused events may skip implementation as required."
-        )]
-        if let Event::Key(key_event) = event {
-            match key_event.code {
-                KeyCode::Enter => {
-                    if self.run.round.hands_count != 0 {
-                        let mut selected = self
-                            .run
-                            .round
-                            .hand
-                            .try_write()
-                            .or_else(|err| bail!("Could not attain read lock for hand: {err}."))?
-                            .drain_from_index_set(
-                                &self
-                                    .card_list_widget_state
-                                    .as_ref()
-                                    .ok_or_eyre("Card list widget state not initialized yet.")?
-                                    .selected,
-                            )?;
-
-                        if selected.is_empty() {
-                            return Ok(());
-                        }
-
-                        self.run.round.play_hand(&mut selected)?;
-                        self.card_list_widget_state
-                            .as_mut()
-                            .ok_or_eyre("Card list widget state not initialized yet.")?
-                            .set_cards(Arc::<RwLock<Vec<Card>>>::clone(&self.run.round.hand));
-                    }
+    /// Event handler for handling input interface events of the topmost
+    /// [`Screen`].
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "Intended: Screens are matched by reference to update their state in place."
+    )]
+    fn handle_screen_events(&mut self, event: Event) -> Result<()> {
+        let Some(key_action) = self.key_action(event) else {
+            return Ok(());
+        };
+        let Some(screen) = self.screens.last_mut() else {
+            return Ok(());
+        };
+
+        match (screen, key_action) {
+            (Screen::Title(_), KeyAction::Menu) => (),
+            (Screen::Stats, KeyAction::Play | KeyAction::Stats)
+            | (Screen::Deck, KeyAction::Play | KeyAction::Deck)
+            | (Screen::RunInfo, KeyAction::Play | KeyAction::RunInfo)
+            | (_, KeyAction::Menu) => {
+                self.close_screen();
+            }
+            (Screen::Title(menu), KeyAction::Select) => menu.move_prev(),
+            (Screen::Title(menu), KeyAction::Deselect) => menu.move_next(),
+            (Screen::Pause(menu), KeyAction::Select) => menu.move_prev(),
+            (Screen::Pause(menu), KeyAction::Deselect) => menu.move_next(),
+            (Screen::Settings(menu), KeyAction::Select) => menu.move_prev(),
+            (Screen::Settings(menu), KeyAction::Deselect) => menu.move_next(),
+            (Screen::Challenges(picker), KeyAction::Select) => picker.move_prev(),
+            (Screen::Challenges(picker), KeyAction::Deselect) => picker.move_next(),
+            (Screen::Title(menu), KeyAction::Play) => {
+                if let Some(item) = menu.picked() {
+                    self.pick_title_item(item)?;
                 }
-                KeyCode::Char('x') => {
-                    if self.run.round.discards_count != 0 {
-                        let mut selected = self
-                            .run
-                            .round
-                            .hand
-                            .try_write()
-                            .or_else(|err| bail!("Could not attain write lock for hand: {err}."))?
-                            .drain_from_index_set(
-                                &self
-                                    .card_list_widget_state
-                                    .as_ref()
-                                    .ok_or_eyre("Card list widget state not initialized yet.")?
-                                    .selected,
-                            )?;
-
-                        if selected.is_empty() {
-                            return Ok(());
-                        }
-
-                        self.run.round.discard_hand(&mut selected)?;
-                        self.card_list_widget_state
-                            .as_mut()
-                            .ok_or_eyre("Card list widget state not initialized yet.")?
-                            .set_cards(Arc::<RwLock<Vec<Card>>>::clone(&self.run.round.hand));
-                    }
+            }
+            (Screen::Pause(menu), KeyAction::Play) => {
+                if let Some(item) = menu.picked() {
+                    self.pick_pause_item(item)?;
+                }
+            }
+            (Screen::Settings(menu), KeyAction::Play) => match menu.picked() {
+                Some(SettingsItem::PlayHint) => self.show_hint = !self.show_hint,
+                Some(SettingsItem::DiscardAdvisor) => self.show_advisor = !self.show_advisor,
+                Some(SettingsItem::Back) => self.close_screen(),
+                None => (),
+            },
+            (Screen::Challenges(picker), KeyAction::Play) => {
+                let challenge = picker.picked()?.clone();
+                self.start_challenge(challenge)?;
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    /// Acts on the [`TitleItem`] picked on the title screen.
+    fn pick_title_item(&mut self, item: TitleItem) -> Result<()> {
+        match item {
+            TitleItem::NewRun => self.switch_run(Engine::new(random_seed())?, None)?,
+            TitleItem::Continue => {
+                if let Some((run, replay)) = load_saved_run()? {
+                    let resumed = Self::resume(run, replay)?;
+                    self.switch_run(resumed.engine, resumed.challenge)?;
+                    self.run_started = None;
                 }
-                _ => (),
+            }
+            TitleItem::Challenges => self.open_challenge_picker()?,
+            TitleItem::Stats => self.open_stats()?,
+            TitleItem::Settings => self
+                .screens
+                .push(Screen::Settings(Menu::new(SettingsItem::ALL))),
+            TitleItem::Quit => self.quit = true,
+        }
+
+        Ok(())
+    }
+
+    /// Acts on the [`PauseItem`] picked on the pause menu.
+    fn pick_pause_item(&mut self, item: PauseItem) -> Result<()> {
+        match item {
+            PauseItem::Resume => self.close_screen(),
+            PauseItem::Restart => {
+                let mut replay = self.engine.replay().clone();
+                replay.actions.clear();
+                let engine = Self::replay_engine(&replay, self.challenge.as_ref())?;
+                self.switch_run(engine, self.challenge.clone())?;
+            }
+            PauseItem::NewRun => self.switch_run(Engine::new(random_seed())?, None)?,
+            PauseItem::Settings => self
+                .screens
+                .push(Screen::Settings(Menu::new(SettingsItem::ALL))),
+            PauseItem::SaveAndQuit => {
+                self.autosave()?;
+                self.quit = true;
             }
         }
 
@@ -465,40 +946,620 @@ impl Game {
 
     /// Event handler for handling deck-specific input interface events.
     fn handle_deck_events(&mut self, event: Event) -> Result<()> {
-        #[expect(
-            clippy::wildcard_enum_match_arm,
-            reason = "Intended: Unused events may skip implementation as required."
-        )]
+        match self.input_action(event) {
+            Some(KeyAction::MoveRight) => {
+                if let Some(state) = self.card_list_widget_state.as_mut() {
+                    state.move_next()?;
+                }
+            }
+            Some(KeyAction::MoveLeft) => {
+                if let Some(state) = self.card_list_widget_state.as_mut() {
+                    state.move_prev()?;
+                }
+            }
+            Some(KeyAction::Select) => {
+                if let Some(pos) = self.card_list_state()?.pos {
+                    self.apply_action(Action::Select(pos))?;
+                }
+            }
+            Some(KeyAction::Deselect) => {
+                if let Some(pos) = self.card_list_state()?.pos {
+                    self.apply_action(Action::Deselect(pos))?;
+                }
+            }
+            Some(KeyAction::MoveCardLeft) => {
+                if let Some(pos) = self.card_list_state()?.pos {
+                    self.apply_action(Action::MoveLeft(pos))?;
+                }
+            }
+            Some(KeyAction::MoveCardRight) => {
+                if let Some(pos) = self.card_list_state()?.pos {
+                    self.apply_action(Action::MoveRight(pos))?;
+                }
+            }
+            Some(KeyAction::Toggle(position)) => {
+                let idx = position.saturating_sub(1);
+                if self.engine.selected().contains(&idx) {
+                    self.apply_action(Action::Deselect(idx))?;
+                } else {
+                    self.apply_action(Action::Select(idx))?;
+                }
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [`KeyAction`] bound to the key of a key event.
+    fn key_action(&self, event: Event) -> Option<KeyAction> {
         if let Event::Key(key_event) = event {
-            match key_event.code {
-                KeyCode::Right => {
-                    if let Some(state) = self.card_list_widget_state.as_mut() {
-                        state.move_next()?;
-                    }
+            self.keymap.action(key_event)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`KeyAction`] triggered by an input event on the run, ie, a
+    /// bound key, a click on a button or a click on a card which toggles its
+    /// selection.
+    fn input_action(&self, event: Event) -> Option<KeyAction> {
+        let Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            ..
+        }) = event
+        else {
+            return self.key_action(event);
+        };
+        let position = Position::new(column, row);
+
+        self.button_areas
+            .iter()
+            .find(|&&(_, button_area)| button_area.contains(position))
+            .map(|&(key_action, _)| key_action)
+            .or_else(|| {
+                self.card_list_widget_state
+                    .as_ref()?
+                    .card_at(position)
+                    .map(|idx| KeyAction::Toggle(idx.saturating_add(1)))
+            })
+    }
+
+    /// Applies an [`Action`] on the [`Engine`] and updates the cached widget
+    /// states.
+    ///
+    /// Actions that are not legal in the current state, eg, playing without any
+    /// selected cards, are ignored.
+    fn apply_action(&mut self, action: Action) -> Result<()> {
+        if !self.engine.is_legal(action)? {
+            return Ok(());
+        }
+
+        let selection = self.engine.snapshot()?;
+        let selected_hand = Scorer::get_scoring_hand(&self.engine.selected_cards()?)?.0;
+        let outcome = self.engine.apply(action)?;
+        self.record_stats(action, &selection, selected_hand)?;
+
+        let hand = Arc::<RwLock<Vec<Card>>>::clone(&self.engine.run().round.hand);
+        let selected = self.engine.selected().iter().copied().collect();
+        let state = self.card_list_state()?;
+        match outcome {
+            Outcome::SelectionChanged => {
+                if let Action::Select(idx) | Action::Deselect(idx) = action {
+                    state.pos = Some(idx);
                 }
-                KeyCode::Left => {
-                    if let Some(state) = self.card_list_widget_state.as_mut() {
-                        state.move_prev()?;
-                    }
+                state.selected = selected;
+            }
+            Outcome::HandReordered => {
+                match action {
+                    Action::MoveLeft(idx) => state.pos = Some(idx.saturating_sub(1)),
+                    Action::MoveRight(idx) => state.pos = Some(idx.saturating_add(1)),
+                    Action::Select(_)
+                    | Action::Deselect(_)
+                    | Action::Play
+                    | Action::Discard
+                    | Action::Sort(_) => (),
                 }
-                KeyCode::Up => {
-                    _ = self
-                        .card_list_widget_state
-                        .as_mut()
-                        .ok_or_eyre("Card list widget state not initialized yet.")?
-                        .select()?;
+                state.selected = selected;
+                self.autosave()?;
+            }
+            Outcome::HandPlayed(_) | Outcome::HandDiscarded => {
+                state.set_cards(hand);
+                self.autosave()?;
+            }
+            Outcome::RunFinished(_) => {
+                state.set_cards(hand);
+                if self.is_saved() {
+                    clear_saved_run()?;
+                    save_replay(self.engine.replay())?;
                 }
-                KeyCode::Down => {
-                    _ = self
-                        .card_list_widget_state
-                        .as_mut()
-                        .ok_or_eyre("Card list widget state not initialized yet.")?
-                        .deselect()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Selects the cards of the best play available in hand, deselecting any
+    /// other selected cards.
+    fn select_best_play(&mut self) -> Result<()> {
+        if self.engine.is_face_down() {
+            return Ok(());
+        }
+        let Some(play) = self.engine.best_play()? else {
+            return Ok(());
+        };
+
+        let deselected = self
+            .engine
+            .selected()
+            .iter()
+            .filter(|idx| !play.indices.contains(idx))
+            .copied()
+            .collect::<Vec<_>>();
+        for idx in deselected {
+            self.apply_action(Action::Deselect(idx))?;
+        }
+        for idx in play.indices {
+            self.apply_action(Action::Select(idx))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the cached card list widget state.
+    fn card_list_state(&mut self) -> Result<&mut CardListWidgetState> {
+        self.card_list_widget_state
+            .as_mut()
+            .ok_or_eyre("Card list widget state not initialized yet.")
+    }
+
+    /// Renders an overlay asking to enlarge the terminal in place of the game.
+    fn draw_enlarge_overlay(&self, frame: &mut Frame<'_>, area: Rect) {
+        let message = vec![
+            Line::from("Terminal too small".bold()),
+            Line::from(format!(
+                "Need at least {}x{}, got {}x{}.",
+                COMPACT_LAYOUT_MIN_SIZE.width,
+                COMPACT_LAYOUT_MIN_SIZE.height,
+                area.width,
+                area.height
+            )),
+            Line::from("Please enlarge your terminal to continue."),
+        ];
+        let [message_area] = Layout::vertical([Constraint::Length(4)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(message)
+                .style(self.theme.style())
+                .centered()
+                .wrap(Wrap { trim: true }),
+            message_area,
+        );
+    }
+
+    /// Renders the challenge picker in place of the run.
+    fn draw_challenge_picker(&self, frame: &mut Frame<'_>, area: Rect, picker: &ChallengePicker) {
+        frame.render_widget(Clear, area);
+        frame.render_widget(Block::new().style(self.theme.style()), area);
+
+        let mut picker_area = Layout::vertical([Constraint::Ratio(2, 3)])
+            .flex(Flex::Center)
+            .areas::<1>(area)[0];
+        picker_area = Layout::horizontal([Constraint::Ratio(1, 2)])
+            .flex(Flex::Center)
+            .areas::<1>(picker_area)[0];
+
+        frame.render_widget(
+            ChallengeListWidget::new()
+                .theme(self.theme)
+                .challenges(&picker.challenges)
+                .completed(&picker.completed)
+                .highlighted(picker.highlighted),
+            picker_area,
+        );
+    }
+
+    /// Renders a [`Screen`] overlay.
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "Intended: Screens are matched by reference to render their state."
+    )]
+    fn draw_screen(&self, frame: &mut Frame<'_>, area: Rect, screen: &Screen) -> Result<()> {
+        match screen {
+            Screen::Title(menu) => {
+                frame.render_widget(
+                    MenuWidget::new()
+                        .theme(self.theme)
+                        .splash(TITLE_SPLASH)
+                        .items(&menu.labels())
+                        .disabled(&menu.disabled)
+                        .highlighted(menu.highlighted),
+                    area,
+                );
+            }
+            Screen::Challenges(picker) => self.draw_challenge_picker(frame, area, picker),
+            Screen::Pause(menu) => {
+                self.draw_menu(frame, area, "Paused", &menu.labels(), menu);
+            }
+            Screen::Settings(menu) => {
+                let labels = menu
+                    .items
+                    .iter()
+                    .map(|&item| {
+                        let enabled = match item {
+                            SettingsItem::PlayHint => self.show_hint,
+                            SettingsItem::DiscardAdvisor => self.show_advisor,
+                            SettingsItem::Back => return item.to_string(),
+                        };
+                        format!("{item}: {}", if enabled { "On" } else { "Off" })
+                    })
+                    .collect::<Vec<_>>();
+                self.draw_menu(frame, area, "Settings", &labels, menu);
+            }
+            Screen::Stats => {
+                let mut stats_area = Layout::vertical([Constraint::Length(STATS_HEIGHT)])
+                    .flex(Flex::Center)
+                    .areas::<1>(area)[0];
+                stats_area = Layout::horizontal([Constraint::Length(STATS_WIDTH)])
+                    .flex(Flex::Center)
+                    .areas::<1>(stats_area)[0];
+
+                frame.render_widget(
+                    ProfileStatsWidget::new().theme(self.theme).stats(
+                        &self
+                            .profile
+                            .as_ref()
+                            .ok_or_eyre("Player profile not loaded yet.")?
+                            .stats,
+                    ),
+                    stats_area,
+                );
+            }
+            Screen::Deck => {
+                let mut deck_area = Layout::vertical([Constraint::Length(DECK_VIEW_HEIGHT)])
+                    .flex(Flex::Center)
+                    .areas::<1>(area)[0];
+                deck_area = Layout::horizontal([Constraint::Length(DECK_VIEW_WIDTH)])
+                    .flex(Flex::Center)
+                    .areas::<1>(deck_area)[0];
+
+                // Face down cards in hand are hidden from the snapshot and
+                // counted as unseen along with the draw pile instead.
+                let hand = self
+                    .engine
+                    .snapshot()?
+                    .hand
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                let remaining = self.engine.unseen_cards()?;
+                frame.render_widget(
+                    DeckViewWidget::new()
+                        .theme(self.theme)
+                        .face_down(self.engine.is_face_down())
+                        .hand(&hand)
+                        .history(&self.engine.run().round.history)
+                        .remaining(&remaining),
+                    deck_area,
+                );
+            }
+            Screen::RunInfo => {
+                let mut run_info_area = Layout::vertical([Constraint::Length(RUN_INFO_HEIGHT)])
+                    .flex(Flex::Center)
+                    .areas::<1>(area)[0];
+                run_info_area = Layout::horizontal([Constraint::Length(RUN_INFO_WIDTH)])
+                    .flex(Flex::Center)
+                    .areas::<1>(run_info_area)[0];
+
+                let run = self.engine.run();
+                let hands = run.hand_infos()?;
+                let blinds = run.blind_infos()?;
+                frame.render_widget(
+                    RunInfoWidget::new()
+                        .theme(self.theme)
+                        .hands(&hands)
+                        .blinds(&blinds)
+                        .current_blind(run.round.blind),
+                    run_info_area,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Renders a [`Menu`] overlay centered over the run.
+    fn draw_menu<Item>(
+        &self,
+        frame: &mut Frame<'_>,
+        area: Rect,
+        title: &str,
+        labels: &[String],
+        menu: &Menu<Item>,
+    ) {
+        let menu_height = u16::try_from(labels.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(MENU_CHROME_HEIGHT);
+        let mut menu_area = Layout::vertical([Constraint::Length(menu_height)])
+            .flex(Flex::Center)
+            .areas::<1>(area)[0];
+        menu_area = Layout::horizontal([Constraint::Length(MENU_WIDTH)])
+            .flex(Flex::Center)
+            .areas::<1>(menu_area)[0];
+
+        frame.render_widget(
+            MenuWidget::new()
+                .theme(self.theme)
+                .title(title)
+                .items(labels)
+                .disabled(&menu.disabled)
+                .highlighted(menu.highlighted),
+            menu_area,
+        );
+    }
+
+    /// Closes the topmost [`Screen`].
+    fn close_screen(&mut self) {
+        drop(self.screens.pop());
+    }
+
+    /// Returns the title screen, with [`TitleItem::Continue`] disabled if no
+    /// run is saved.
+    fn title_screen() -> Result<Screen> {
+        let mut menu = Menu::new(TitleItem::ALL);
+        if !has_saved_run()? {
+            menu = menu.disable(TitleItem::Continue);
+        }
+        Ok(Screen::Title(menu))
+    }
+
+    /// Opens the challenge picker.
+    fn open_challenge_picker(&mut self) -> Result<()> {
+        let completed = self.profile()?.completed_challenges.clone();
+        self.screens
+            .push(Screen::Challenges(ChallengePicker::new(completed)?));
+        Ok(())
+    }
+
+    /// Opens the lifetime stats of the player.
+    fn open_stats(&mut self) -> Result<()> {
+        _ = self.profile()?;
+        self.screens.push(Screen::Stats);
+        Ok(())
+    }
+
+    /// Returns an [`Engine`] that starts the run recorded by the [`Replay`],
+    /// played with the given [`Challenge`] if the run was a challenge.
+    fn replay_engine(replay: &Replay, challenge: Option<&Challenge>) -> Result<Engine> {
+        Ok(
+            match (replay.puzzle.as_ref(), challenge, replay.deck.clone()) {
+                (Some(puzzle), ..) => Engine::with_puzzle(puzzle)?,
+                (None, Some(run_challenge), _) => {
+                    Engine::with_challenge(run_challenge, replay.seed.clone())?
                 }
-                _ => (),
+                (None, None, Some(deck)) => Engine::with_deck(replay.seed.clone(), deck)?,
+                (None, None, None) => Engine::new(replay.seed.clone())?,
+            },
+        )
+    }
+
+    /// Replaces the current run with the run driven by the given [`Engine`]
+    /// and closes all screens.
+    fn switch_run(&mut self, engine: Engine, challenge: Option<Challenge>) -> Result<()> {
+        self.engine = engine;
+        self.challenge = challenge;
+        self.screens.clear();
+        self.run_started = Some(Instant::now());
+        self.advisor = None;
+        self.reset_card_list_state()?;
+        self.autosave()
+    }
+
+    /// Starts a new run of the given [`Challenge`] in place of the current run
+    /// and closes the challenge picker.
+    fn start_challenge(&mut self, picked: Challenge) -> Result<()> {
+        self.switch_run(
+            Engine::with_challenge(&picked, random_seed())?,
+            Some(picked),
+        )
+    }
+
+    /// Returns the player profile, loading it on first use.
+    fn profile(&mut self) -> Result<&mut Profile> {
+        if self.profile.is_none() {
+            self.profile = Some(load_profile()?);
+        }
+        self.profile
+            .as_mut()
+            .ok_or_eyre("Player profile not loaded yet.")
+    }
+
+    /// Records the applied [`Action`] in the lifetime stats of the player
+    /// profile, along with the completion of the run and its challenge.
+    ///
+    /// The [`Snapshot`] and the [`ScoringHand`] of the selected cards are
+    /// expected to be taken before the action was applied. The scoring hand is
+    /// passed separately as snapshots hide it while cards are face down. No-op
+    /// during playback and for puzzles.
+    fn record_stats(
+        &mut self,
+        action: Action,
+        before: &Snapshot,
+        selected_hand: Option<ScoringHand>,
+    ) -> Result<()> {
+        if matches!(
+            action,
+            Action::Select(_)
+                | Action::Deselect(_)
+                | Action::Sort(_)
+                | Action::MoveLeft(_)
+                | Action::MoveRight(_)
+        ) || self.playback.is_some()
+            || self.engine.replay().puzzle.is_some()
+        {
+            return Ok(());
+        }
+
+        let after = self.engine.snapshot()?;
+        let deck_name = self.deck_name();
+        let run_duration = self.run_started.map(|started| started.elapsed());
+        let money_gained = after
+            .money
+            .saturating_sub(self.engine.run().properties.initial_money());
+        let challenge_id = self
+            .challenge
+            .as_ref()
+            .map(|run_challenge| run_challenge.id.clone());
+        let stats = &mut self.profile()?.stats;
+        if action == Action::Discard {
+            stats.record_discard(before.selected.len());
+        } else if let Some(scoring_hand) = selected_hand {
+            stats.record_hand(
+                scoring_hand,
+                before.selected.len(),
+                &after.score.saturating_sub(&before.score),
+            );
+        } else {
+            // Played cards always make a scoring hand
+        }
+
+        if let RunState::Finished(win) = after.run_state {
+            stats.record_run(&deck_name, win, after.ante, money_gained, run_duration);
+            if let Some(id) = challenge_id.filter(|_| win) {
+                _ = self.profile()?.complete_challenge(&id);
             }
         }
 
+        save_profile(self.profile()?)
+    }
+
+    /// Returns the name of the deck the run is played with, as recorded in
+    /// the lifetime stats of the player.
+    fn deck_name(&self) -> String {
+        self.challenge.as_ref().map_or_else(
+            || {
+                if self.engine.replay().deck.is_some() {
+                    CUSTOM_DECK_NAME.to_owned()
+                } else {
+                    STANDARD_DECK_NAME.to_owned()
+                }
+            },
+            |run_challenge| format!("Challenge: {}", run_challenge.name),
+        )
+    }
+
+    /// Recreates the cached card list widget state for the hand of the run.
+    fn reset_card_list_state(&mut self) -> Result<()> {
+        self.card_list_widget_state = Some(
+            CardListWidgetState::from(Arc::<RwLock<Vec<Card>>>::clone(
+                &self.engine.run().round.hand,
+            ))
+            .selection_limit(Some(MAXIMUM_SELECTABLE_CARDS))?,
+        );
+
+        Ok(())
+    }
+
+    /// Returns `true` if the run is saved, ie, it is neither played back nor a
+    /// puzzle. Such runs must leave the saved run of the player untouched.
+    const fn is_saved(&self) -> bool {
+        self.playback.is_none() && self.engine.replay().puzzle.is_none()
+    }
+
+    /// Saves the run along with its [`Replay`]. No-op for runs that are not
+    /// saved, once the run is over and while the run is not shown, eg, on the
+    /// title screen.
+    fn autosave(&self) -> Result<()> {
+        if self.is_saved()
+            && self.engine.run().run_state == RunState::Running
+            && !self.screens.iter().any(Screen::hides_run)
+        {
+            save_run(self.engine.run())?;
+            save_replay(self.engine.replay())?;
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env::temp_dir, fs::remove_dir_all, process::id};
+
+    use balatro_tui_core::{card::parse_hand, challenge::ChallengeRule};
+
+    use super::*;
+    use crate::storage::{load_replay, load_run, use_test_data_dir, APP_DIR_NAME};
+
+    /// Points the data directory of the current test to a new temporary
+    /// directory of the named test.
+    fn isolate_data_dir(test_name: &str) {
+        let dir = temp_dir().join(format!("{APP_DIR_NAME}_{test_name}_{}", id()));
+        if dir.exists() {
+            remove_dir_all(&dir).unwrap();
+        }
+        use_test_data_dir(dir);
+    }
+
+    /// Applies the actions on the game, which must all be legal.
+    fn apply_actions(game: &mut Game, actions: &[Action]) {
+        game.reset_card_list_state().unwrap();
+        for &action in actions {
+            assert!(game.engine.is_legal(action).unwrap(), "{action:?}");
+            game.apply_action(action).unwrap();
+        }
+    }
+
+    #[test]
+    fn face_down_run_records_played_hands() {
+        isolate_data_dir("face_down_stats");
+        let blindfolded = Challenge {
+            id: "blindfolded".to_owned(),
+            max_hands: Some(1),
+            rules: vec![ChallengeRule::FaceDown],
+            ..Challenge::default()
+        };
+        let mut game =
+            Game::from_engine(Engine::with_challenge(&blindfolded, "SEED".to_owned()).unwrap());
+        game.challenge = Some(blindfolded);
+
+        apply_actions(&mut game, &[Action::Select(0), Action::Play]);
+        let stats = load_profile().unwrap().stats;
+
+        assert_eq!(stats.cards_played, 1);
+        assert_eq!(stats.hands_played.values().sum::<usize>(), 1);
+        assert!(!stats.best_hand_score.is_zero());
+        assert_eq!(stats.runs_played(), 1);
+    }
+
+    #[test]
+    fn puzzle_keeps_saved_run() {
+        isolate_data_dir("puzzle_save");
+        let saved = Engine::new("SAVED".to_owned()).unwrap();
+        save_run(saved.run()).unwrap();
+        save_replay(saved.replay()).unwrap();
+        let puzzle = Puzzle {
+            name: "Pair".to_owned(),
+            hand: parse_hand("AS AH 2C").unwrap(),
+            target_score: 10,
+            hands: 1,
+            ..Puzzle::default()
+        };
+        let mut game = Game::with_puzzle(&puzzle).unwrap();
+
+        apply_actions(&mut game, &[
+            Action::Select(0),
+            Action::Select(1),
+            Action::Play,
+        ]);
+
+        assert_eq!(game.engine.run().run_state, RunState::Finished(true));
+        assert_eq!(load_run().unwrap().unwrap().properties.seed, "SAVED");
+        assert_eq!(load_replay().unwrap().unwrap().seed, "SAVED");
+    }
+}
@@ -0,0 +1,397 @@
+//! This module provides [`KeyMap`], the mapping of keys to [`KeyAction`]
+//! performed by the player.
+//!
+//! Keys are bound by a [`KeyPreset`], and bindings can be overridden per action
+//! in `keys.toml` under the XDG config directory, ie,
+//! `$XDG_CONFIG_HOME/balatro_tui`, falling back to `$HOME/.config/balatro_tui`.
+//!
+//! ```toml
+//! # Preset to start from, either `default` or `hjkl`.
+//! preset = "hjkl"
+//! # Whether digit keys toggle the selection of the card at that position.
+//! digits = true
+//!
+//! [bindings]
+//! discard = ["d", "backspace"]
+//! hint = ["?"]
+//! ```
+//!
+//! Keys are written as single characters or as one of `enter`, `esc`, `space`,
+//! `tab`, `backspace`, `delete`, `left`, `right`, `up`, `down`, `home`, `end`,
+//! `pageup` and `pagedown`. A key bound to more than one action is rejected
+//! when the config is loaded.
+//!
+//! Menus reuse the bindings of the run: [`KeyAction::Select`] and
+//! [`KeyAction::Deselect`] move the highlight up and down, [`KeyAction::Play`]
+//! picks the highlighted item and [`KeyAction::Menu`] goes back.
+
+use std::collections::{BTreeMap, HashMap};
+
+use color_eyre::eyre::{bail, eyre, Result};
+use crossterm::event::{KeyCode, KeyEvent};
+use serde::{de::Error as DeError, Deserialize, Deserializer};
+
+use crate::storage::{config_dir, read_definition};
+
+/// File name of the keybindings config.
+const KEYS_FILE: &str = "keys.toml";
+
+/// Actions performed by the player with keys.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyAction {
+    /// Play the selected cards.
+    Play,
+    /// Discard the selected cards.
+    Discard,
+    /// Select the card under the cursor.
+    Select,
+    /// Deselect the card under the cursor.
+    Deselect,
+    /// Move the cursor to the previous card.
+    MoveLeft,
+    /// Move the cursor to the next card.
+    MoveRight,
+    /// Toggle sorting the cards in hand by rank or by suit.
+    Sort,
+    /// Move the card under the cursor one position to the left.
+    MoveCardLeft,
+    /// Move the card under the cursor one position to the right.
+    MoveCardRight,
+    /// Toggle the hint panel showing the best play in hand.
+    Hint,
+    /// Select the cards of the best play in hand.
+    BestPlay,
+    /// Toggle the discard advisor overlay.
+    Advisor,
+    /// Show the lifetime stats of the player.
+    Stats,
+    /// Show the composition of the deck.
+    Deck,
+    /// Show the scoring hands and the blinds of the run.
+    RunInfo,
+    /// Open the pause menu, or close the topmost menu.
+    Menu,
+    /// Toggle the selection of the card at the contained position, starting
+    /// from 1. Bound to digit keys.
+    #[serde(skip)]
+    Toggle(usize),
+}
+
+/// Presets of keybindings.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyPreset {
+    /// Arrow keys to move the cursor and select cards.
+    #[default]
+    Default,
+    /// Vim-style `h`/`l` to move the cursor and `k`/`j` to select and deselect
+    /// cards, in addition to arrow keys.
+    #[serde(alias = "vim")]
+    Hjkl,
+}
+
+impl KeyPreset {
+    /// Returns the keys bound to each action by the preset.
+    fn bindings(self) -> BTreeMap<KeyAction, Vec<KeyCode>> {
+        let mut bindings = BTreeMap::from([
+            (KeyAction::Play, vec![KeyCode::Enter]),
+            (KeyAction::Discard, vec![KeyCode::Char('x')]),
+            (KeyAction::Select, vec![KeyCode::Up]),
+            (KeyAction::Deselect, vec![KeyCode::Down]),
+            (KeyAction::MoveLeft, vec![KeyCode::Left]),
+            (KeyAction::MoveRight, vec![KeyCode::Right]),
+            (KeyAction::Sort, vec![KeyCode::Char('s')]),
+            (KeyAction::MoveCardLeft, vec![KeyCode::Char('<')]),
+            (KeyAction::MoveCardRight, vec![KeyCode::Char('>')]),
+            (KeyAction::Hint, vec![KeyCode::Char('h')]),
+            (KeyAction::BestPlay, vec![KeyCode::Char('b')]),
+            (KeyAction::Advisor, vec![KeyCode::Char('a')]),
+            (KeyAction::Stats, vec![KeyCode::Char('p')]),
+            (KeyAction::Deck, vec![KeyCode::Char('v')]),
+            (KeyAction::RunInfo, vec![KeyCode::Char('i')]),
+            (KeyAction::Menu, vec![KeyCode::Esc, KeyCode::Char('q')]),
+        ]);
+
+        if self == Self::Hjkl {
+            bindings.extend([
+                (KeyAction::Select, vec![KeyCode::Char('k'), KeyCode::Up]),
+                (KeyAction::Deselect, vec![KeyCode::Char('j'), KeyCode::Down]),
+                (KeyAction::MoveLeft, vec![KeyCode::Char('h'), KeyCode::Left]),
+                (KeyAction::MoveRight, vec![
+                    KeyCode::Char('l'),
+                    KeyCode::Right,
+                ]),
+                (KeyAction::Hint, vec![KeyCode::Char('?')]),
+            ]);
+        }
+
+        bindings
+    }
+}
+
+/// A key as written in the keybindings config.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Key(KeyCode);
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = String::deserialize(deserializer)?;
+        parse_key(&key).map(Self).ok_or_else(|| {
+            DeError::custom(format!(
+                "invalid key `{key}`, expected a character or a named key"
+            ))
+        })
+    }
+}
+
+/// Keybindings config as written in `keys.toml`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct KeyConfig {
+    /// Preset to start from.
+    preset: KeyPreset,
+    /// Whether digit keys toggle the selection of the card at that position.
+    digits: bool,
+    /// Keys bound to actions, replacing the keys bound by the preset.
+    bindings: BTreeMap<KeyAction, Vec<Key>>,
+}
+
+impl Default for KeyConfig {
+    fn default() -> Self {
+        Self {
+            preset: KeyPreset::default(),
+            digits: true,
+            bindings: BTreeMap::new(),
+        }
+    }
+}
+
+/// Mapping of keys to [`KeyAction`].
+#[derive(Clone, Debug)]
+pub struct KeyMap {
+    /// Action bound to each key.
+    actions: HashMap<KeyCode, KeyAction>,
+    /// Whether digit keys toggle the selection of the card at that position.
+    digits: bool,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            actions: invert(KeyPreset::default().bindings()),
+            digits: true,
+        }
+    }
+}
+
+impl KeyMap {
+    /// Create a [`KeyMap`] from the keys bound to each action.
+    ///
+    /// # Errors
+    ///
+    /// Errors if a key is bound to more than one action, or if a digit key is
+    /// bound to an action while digit keys toggle cards.
+    fn new(bindings: &BTreeMap<KeyAction, Vec<KeyCode>>, digits: bool) -> Result<Self> {
+        let mut actions = HashMap::new();
+        for (&action, keys) in bindings {
+            for &key in keys {
+                if let Some(bound_action) = actions.insert(key, action) {
+                    bail!(
+                        "Key `{}` is bound to both `{bound_action:?}` and `{action:?}`.",
+                        key_name(key)
+                    );
+                }
+                if digits && digit(key).is_some() {
+                    bail!(
+                        "Key `{}` is bound to `{action:?}` but digit keys toggle cards. Set \
+                         `digits = false` to bind digit keys.",
+                        key_name(key)
+                    );
+                }
+            }
+        }
+
+        Ok(Self { actions, digits })
+    }
+
+    /// Create a [`KeyMap`] from the content of a keybindings config.
+    fn from_config(config: KeyConfig) -> Result<Self> {
+        let mut bindings = config.preset.bindings();
+        bindings.extend(
+            config
+                .bindings
+                .into_iter()
+                .map(|(action, keys)| (action, keys.into_iter().map(|Key(key)| key).collect())),
+        );
+        Self::new(&bindings, config.digits)
+    }
+
+    /// Returns the [`KeyAction`] bound to the key of the event, if any.
+    #[must_use = "Bound key action must be used."]
+    pub fn action(&self, key_event: KeyEvent) -> Option<KeyAction> {
+        self.actions.get(&key_event.code).copied().or_else(|| {
+            digit(key_event.code)
+                .filter(|_| self.digits)
+                .map(KeyAction::Toggle)
+        })
+    }
+}
+
+/// Loads the keybindings config from the XDG config directory. The default
+/// keybindings are returned if there is no config.
+pub fn load() -> Result<KeyMap> {
+    let path = config_dir()?.join(KEYS_FILE);
+    if !path.exists() {
+        return Ok(KeyMap::default());
+    }
+
+    KeyMap::from_config(read_definition(&path)?)
+        .map_err(|error| eyre!("Keybindings in {} are invalid: {error}", path.display()))
+}
+
+/// Returns the action bound to each key.
+fn invert(bindings: BTreeMap<KeyAction, Vec<KeyCode>>) -> HashMap<KeyCode, KeyAction> {
+    bindings
+        .into_iter()
+        .flat_map(|(action, keys)| keys.into_iter().map(move |key| (key, action)))
+        .collect()
+}
+
+/// Returns the card position toggled by a digit key, where `0` is the tenth
+/// card.
+fn digit(key: KeyCode) -> Option<usize> {
+    if let KeyCode::Char(character) = key {
+        character
+            .to_digit(10)
+            .and_then(|position| usize::try_from(position).ok())
+            .map(|position| if position == 0 { 10 } else { position })
+    } else {
+        None
+    }
+}
+
+/// Parses a key written in the keybindings config.
+fn parse_key(key: &str) -> Option<KeyCode> {
+    let mut characters = key.chars();
+    if let (Some(character), None) = (characters.next(), characters.next()) {
+        return Some(KeyCode::Char(character));
+    }
+
+    Some(match key.to_lowercase().as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "delete" => KeyCode::Delete,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        _ => return None,
+    })
+}
+
+/// Returns the name of a key as written in the keybindings config.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "Intended: Other keys are named by their debug representation."
+)]
+fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "space".to_owned(),
+        KeyCode::Char(character) => character.to_string(),
+        other => format!("{other:?}").to_lowercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn keymap(config: &str) -> Result<KeyMap> {
+        KeyMap::from_config(toml::from_str(config)?)
+    }
+
+    #[test]
+    fn presets_have_no_conflicts() {
+        for preset in [KeyPreset::Default, KeyPreset::Hjkl] {
+            drop(KeyMap::new(&preset.bindings(), true).unwrap());
+        }
+    }
+
+    #[test]
+    fn hjkl_preset_binds_vim_keys() {
+        let keys = keymap(r#"preset = "hjkl""#).unwrap();
+
+        assert_eq!(
+            keys.action(key(KeyCode::Char('h'))),
+            Some(KeyAction::MoveLeft)
+        );
+        assert_eq!(
+            keys.action(key(KeyCode::Char('k'))),
+            Some(KeyAction::Select)
+        );
+        assert_eq!(keys.action(key(KeyCode::Up)), Some(KeyAction::Select));
+        assert_eq!(keys.action(key(KeyCode::Char('?'))), Some(KeyAction::Hint));
+    }
+
+    #[test]
+    fn digits_toggle_cards() {
+        let keys = KeyMap::default();
+
+        assert_eq!(
+            keys.action(key(KeyCode::Char('3'))),
+            Some(KeyAction::Toggle(3))
+        );
+        assert_eq!(
+            keys.action(key(KeyCode::Char('0'))),
+            Some(KeyAction::Toggle(10))
+        );
+        assert_eq!(
+            keymap("digits = false")
+                .unwrap()
+                .action(key(KeyCode::Char('3'))),
+            None
+        );
+    }
+
+    #[test]
+    fn bindings_override_preset() {
+        let keys = keymap("[bindings]\ndiscard = [\"d\", \"backspace\"]").unwrap();
+
+        assert_eq!(
+            keys.action(key(KeyCode::Char('d'))),
+            Some(KeyAction::Discard)
+        );
+        assert_eq!(
+            keys.action(key(KeyCode::Backspace)),
+            Some(KeyAction::Discard)
+        );
+        assert_eq!(keys.action(key(KeyCode::Char('x'))), None);
+    }
+
+    #[test]
+    fn conflicts_are_rejected() {
+        let error = keymap("[bindings]\ndiscard = [\"h\"]").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Key `h` is bound to both `Discard` and `Hint`."
+        );
+        drop(keymap("[bindings]\nplay = [\"1\"]").unwrap_err());
+        drop(keymap("digits = false\n[bindings]\nplay = [\"1\"]").unwrap());
+        drop(keymap("[bindings]\nplay = [\"ctrl+enter\"]").unwrap_err());
+    }
+}
@@ -4,18 +4,78 @@
 //!
 //! All rights are reserved by `LocalThunk` for the original game.
 
-use color_eyre::{eyre::Context, Result};
+use std::io::{stdin, stdout};
+
+use balatro_tui_core::rng::random_seed;
+use clap::Parser;
+use cli::{Cli, Command};
+use color_eyre::{
+    eyre::{Context, OptionExt},
+    Result,
+};
 use game::Game;
+use protocol::Protocol;
 
+pub mod advisor;
+pub mod challenge;
+pub mod cli;
+pub mod deck_file;
 pub mod event;
 pub mod game;
-pub mod iter_index_ext;
+pub mod keymap;
+pub mod menu;
+pub mod playback;
+pub mod protocol;
+pub mod puzzle;
+pub mod simulate;
+pub mod storage;
+pub mod theme;
 pub mod tui;
 
 #[tokio::main]
+#[expect(
+    clippy::unwrap_in_result,
+    reason = "False positive: Tokio's main macro expands to an `expect` call on runtime creation."
+)]
 async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    // Run subcommands
+    if let Some(Command::Simulate(args)) = cli.command {
+        return simulate::run(&args, stdout().lock());
+    }
+
+    // Play without terminal interface
+    if cli.protocol == Some(Protocol::Jsonl) {
+        return protocol::serve(stdin().lock(), stdout().lock(), cli.seed)
+            .wrap_err("Error encountered while serving the protocol.");
+    }
+
     // Start Game
-    let mut game = Game::new()?;
+    let mut game = if let Some(replay_file) = cli.replay {
+        Game::playback(
+            storage::read_file(&replay_file)?
+                .ok_or_eyre(format!("Replay file {} not found.", replay_file.display()))?,
+        )?
+    } else if cli.resume {
+        let (run, replay) =
+            storage::load_saved_run()?.ok_or_eyre("No saved run found to continue.")?;
+        Game::resume(run, replay)?
+    } else if let Some(puzzle_file) = cli.puzzle {
+        Game::with_puzzle(&puzzle::load(&puzzle_file)?)?
+    } else if cli.challenges {
+        Game::challenges()?
+    } else if let Some(deck_file) = cli.deck_file {
+        Game::with_deck(
+            cli.seed.unwrap_or_else(random_seed),
+            deck_file::load(&deck_file)?,
+        )?
+    } else if let Some(seed) = cli.seed {
+        Game::with_seed(seed)?
+    } else {
+        Game::title()?
+    };
+    game.set_ascii(cli.ascii);
     game.start()
         .await
         .wrap_err("Error encountered while running the game.")?;
@@ -0,0 +1,211 @@
+//! This module provides [`Menu`] to pick an item from a list of items, along
+//! with the items of the title screen, the pause menu and the settings menu.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Items of the title screen.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TitleItem {
+    /// Start a new run with the standard deck.
+    NewRun,
+    /// Continue the saved run.
+    Continue,
+    /// Pick a challenge to play.
+    Challenges,
+    /// Show the lifetime stats of the player.
+    Stats,
+    /// Show the settings menu.
+    Settings,
+    /// Quit the game.
+    Quit,
+}
+
+impl TitleItem {
+    /// Items of the title screen in order.
+    pub const ALL: [Self; 6] = [
+        Self::NewRun,
+        Self::Continue,
+        Self::Challenges,
+        Self::Stats,
+        Self::Settings,
+        Self::Quit,
+    ];
+}
+
+impl Display for TitleItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match *self {
+            Self::NewRun => "New Run",
+            Self::Continue => "Continue",
+            Self::Challenges => "Challenges",
+            Self::Stats => "Stats",
+            Self::Settings => "Settings",
+            Self::Quit => "Quit",
+        })
+    }
+}
+
+/// Items of the pause menu.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseItem {
+    /// Close the pause menu.
+    Resume,
+    /// Restart the run with the same seed.
+    Restart,
+    /// Start a new run with the standard deck.
+    NewRun,
+    /// Show the settings menu.
+    Settings,
+    /// Save the run and quit the game.
+    SaveAndQuit,
+}
+
+impl PauseItem {
+    /// Items of the pause menu in order.
+    pub const ALL: [Self; 5] = [
+        Self::Resume,
+        Self::Restart,
+        Self::NewRun,
+        Self::Settings,
+        Self::SaveAndQuit,
+    ];
+}
+
+impl Display for PauseItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match *self {
+            Self::Resume => "Resume",
+            Self::Restart => "Restart with same seed",
+            Self::NewRun => "New Run",
+            Self::Settings => "Settings",
+            Self::SaveAndQuit => "Save & Quit",
+        })
+    }
+}
+
+/// Items of the settings menu.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SettingsItem {
+    /// Toggle the hint panel showing the best play in hand.
+    PlayHint,
+    /// Toggle the discard advisor overlay.
+    DiscardAdvisor,
+    /// Close the settings menu.
+    Back,
+}
+
+impl SettingsItem {
+    /// Items of the settings menu in order.
+    pub const ALL: [Self; 3] = [Self::PlayHint, Self::DiscardAdvisor, Self::Back];
+}
+
+impl Display for SettingsItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match *self {
+            Self::PlayHint => "Play hint",
+            Self::DiscardAdvisor => "Discard advisor",
+            Self::Back => "Back",
+        })
+    }
+}
+
+/// Menu to pick an item from a list of items, skipping disabled items.
+#[derive(Clone, Debug)]
+pub struct Menu<Item> {
+    /// Items of the menu.
+    pub items: Vec<Item>,
+    /// Indices of the items that cannot be picked.
+    pub disabled: Vec<usize>,
+    /// Index of the highlighted item.
+    pub highlighted: usize,
+}
+
+impl<Item: Copy + PartialEq> Menu<Item> {
+    /// Create a new [`Menu`] over the given items with the first item
+    /// highlighted.
+    pub fn new<Items: IntoIterator<Item = Item>>(items: Items) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            disabled: vec![],
+            highlighted: 0,
+        }
+    }
+
+    /// Disable the given item and return the [`Menu`] instance. The highlight
+    /// is moved to the next enabled item if the item was highlighted.
+    #[must_use = "Menu builder returned instance must be used."]
+    pub fn disable(mut self, item: Item) -> Self {
+        if let Some(idx) = self.items.iter().position(|&menu_item| menu_item == item) {
+            self.disabled.push(idx);
+            if self.highlighted == idx {
+                self.move_next();
+            }
+        }
+        self
+    }
+
+    /// Highlight the next enabled item, cycling back to the first one.
+    pub fn move_next(&mut self) {
+        for _ in 0..self.items.len() {
+            self.highlighted = self
+                .highlighted
+                .saturating_add(1)
+                .checked_rem(self.items.len())
+                .unwrap_or(0);
+            if !self.disabled.contains(&self.highlighted) {
+                break;
+            }
+        }
+    }
+
+    /// Highlight the previous enabled item, cycling to the last one.
+    pub fn move_prev(&mut self) {
+        for _ in 0..self.items.len() {
+            self.highlighted = self
+                .highlighted
+                .checked_sub(1)
+                .unwrap_or_else(|| self.items.len().saturating_sub(1));
+            if !self.disabled.contains(&self.highlighted) {
+                break;
+            }
+        }
+    }
+
+    /// Returns the highlighted item, if it is enabled.
+    #[must_use = "Picked item must be used."]
+    pub fn picked(&self) -> Option<Item> {
+        if self.disabled.contains(&self.highlighted) {
+            return None;
+        }
+        self.items.get(self.highlighted).copied()
+    }
+}
+
+impl<Item: Display> Menu<Item> {
+    /// Returns the labels of the items of the menu.
+    pub fn labels(&self) -> Vec<String> {
+        self.items.iter().map(ToString::to_string).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn menu_skips_disabled_items() {
+        let mut menu = Menu::new(TitleItem::ALL)
+            .disable(TitleItem::NewRun)
+            .disable(TitleItem::Continue);
+
+        assert_eq!(menu.picked(), Some(TitleItem::Challenges));
+
+        menu.move_prev();
+
+        assert_eq!(menu.picked(), Some(TitleItem::Quit));
+
+        menu.move_next();
+
+        assert_eq!(menu.picked(), Some(TitleItem::Challenges));
+    }
+}
@@ -0,0 +1,240 @@
+//! This module provides [`ReplayPlayer`] that feeds the actions of a
+//! [`Replay`] into the game loop in place of user input.
+//!
+//! Playback is driven by [`Event::Tick`] and can be paused, stepped through one
+//! action at a time and sped up or slowed down using key events.
+
+use balatro_tui_core::{action::Action, replay::Replay};
+use crossterm::event::KeyCode;
+
+use crate::event::Event;
+
+/// Available playback speeds as the number of ticks to wait between two
+/// actions, from slowest to fastest.
+const PLAYBACK_SPEEDS: [usize; 5] = [16, 8, 4, 2, 1];
+
+/// Index into [`PLAYBACK_SPEEDS`] used when playback starts.
+const DEFAULT_PLAYBACK_SPEED: usize = 2;
+
+/// Plays back the actions of a [`Replay`] in order.
+#[derive(Clone, Debug)]
+pub struct ReplayPlayer {
+    /// Actions to be played back.
+    actions: Vec<Action>,
+    /// Index of the next action to be played back.
+    next_action: usize,
+    /// Whether playback is paused. Paused playback can still be stepped
+    /// through.
+    paused: bool,
+    /// Index into [`PLAYBACK_SPEEDS`] for the current playback speed.
+    speed: usize,
+    /// Ticks elapsed since the last played back action.
+    elapsed_ticks: usize,
+}
+
+impl ReplayPlayer {
+    /// Create a new [`ReplayPlayer`] that plays back the actions of the given
+    /// [`Replay`].
+    #[must_use = "Created replay player must be used."]
+    #[inline]
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            actions: replay.actions,
+            next_action: 0,
+            paused: false,
+            speed: DEFAULT_PLAYBACK_SPEED,
+            elapsed_ticks: 0,
+        }
+    }
+
+    /// Returns `true` if all actions have been played back.
+    #[must_use]
+    #[inline]
+    pub const fn is_finished(&self) -> bool {
+        self.next_action >= self.actions.len()
+    }
+
+    /// Handles playback controls and ticks. Returns the next [`Action`] to be
+    /// applied to the game, if it is due.
+    pub fn handle_event(&mut self, event: Event) -> Option<Action> {
+        #[expect(
+            clippy::wildcard_enum_match_arm,
+            reason = "Intended: Unused events may skip implementation as required."
+        )]
+        match event {
+            Event::Tick if !self.paused => {
+                self.elapsed_ticks = self.elapsed_ticks.saturating_add(1);
+                if self.elapsed_ticks >= self.ticks_per_action() {
+                    return self.step();
+                }
+            }
+            Event::Key(key_event) => match key_event.code {
+                KeyCode::Char(' ') => self.paused = !self.paused,
+                KeyCode::Char('n') | KeyCode::Right => return self.step(),
+                KeyCode::Char('+' | '=') => {
+                    self.speed = self
+                        .speed
+                        .saturating_add(1)
+                        .min(PLAYBACK_SPEEDS.len().saturating_sub(1));
+                }
+                KeyCode::Char('-') => self.speed = self.speed.saturating_sub(1),
+                _ => (),
+            },
+            _ => (),
+        }
+
+        None
+    }
+
+    /// Returns a single line description of the playback progress and
+    /// controls.
+    #[must_use]
+    pub fn status(&self) -> String {
+        format!(
+            "Replay {}/{} | {} | Speed {}/{} | Space: pause, n: step, +/-: speed",
+            self.next_action,
+            self.actions.len(),
+            if self.is_finished() {
+                "Finished"
+            } else if self.paused {
+                "Paused"
+            } else {
+                "Playing"
+            },
+            self.speed.saturating_add(1),
+            PLAYBACK_SPEEDS.len(),
+        )
+    }
+
+    /// Returns the next action and advances the playback.
+    fn step(&mut self) -> Option<Action> {
+        self.elapsed_ticks = 0;
+        let action = self.actions.get(self.next_action).copied()?;
+        self.next_action = self.next_action.saturating_add(1);
+        Some(action)
+    }
+
+    /// Returns the number of ticks to wait between two actions at the current
+    /// playback speed.
+    fn ticks_per_action(&self) -> usize {
+        PLAYBACK_SPEEDS.get(self.speed).copied().unwrap_or(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use balatro_tui_core::{engine::Engine, run::RunState};
+    use crossterm::event::{KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    fn key(character: char) -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char(character), KeyModifiers::NONE))
+    }
+
+    fn player(actions: &[Action]) -> ReplayPlayer {
+        ReplayPlayer::new(Replay {
+            actions: actions.to_vec(),
+            ..Replay::new("SEED".to_owned())
+        })
+    }
+
+    /// Returns the number of ticks after which the player plays back its next
+    /// action, giving up after the slowest speed.
+    fn ticks_until_action(player: &mut ReplayPlayer) -> Option<usize> {
+        (1..=PLAYBACK_SPEEDS[0]).find(|_| player.handle_event(Event::Tick).is_some())
+    }
+
+    #[test]
+    fn ticks_play_back_actions_in_order() {
+        let mut replay_player = player(&[Action::Select(0), Action::Play]);
+
+        let actions = (0..PLAYBACK_SPEEDS[DEFAULT_PLAYBACK_SPEED].saturating_mul(2))
+            .filter_map(|_| replay_player.handle_event(Event::Tick))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actions, vec![Action::Select(0), Action::Play]);
+        assert!(replay_player.is_finished());
+        assert_eq!(replay_player.handle_event(Event::Tick), None);
+        assert_eq!(replay_player.handle_event(key('n')), None);
+    }
+
+    #[test]
+    fn paused_playback_only_steps() {
+        let mut replay_player = player(&[Action::Select(0), Action::Play]);
+
+        assert_eq!(replay_player.handle_event(key(' ')), None);
+        assert_eq!(ticks_until_action(&mut replay_player), None);
+        assert!(replay_player.status().contains("Paused"));
+        assert_eq!(
+            replay_player.handle_event(key('n')),
+            Some(Action::Select(0))
+        );
+
+        assert_eq!(replay_player.handle_event(key(' ')), None);
+        assert_eq!(
+            ticks_until_action(&mut replay_player),
+            Some(PLAYBACK_SPEEDS[DEFAULT_PLAYBACK_SPEED])
+        );
+        assert!(replay_player.status().contains("Finished"));
+    }
+
+    #[test]
+    fn speed_changes_are_bounded() {
+        let mut replay_player = player(&[Action::Play; 4]);
+
+        for _ in 0..PLAYBACK_SPEEDS.len() {
+            assert_eq!(replay_player.handle_event(key('+')), None);
+        }
+        assert_eq!(ticks_until_action(&mut replay_player), Some(1));
+        assert!(replay_player
+            .status()
+            .contains(&format!("Speed {0}/{0}", PLAYBACK_SPEEDS.len())));
+
+        assert_eq!(replay_player.handle_event(key('-')), None);
+        assert_eq!(ticks_until_action(&mut replay_player), Some(2));
+
+        for _ in 0..PLAYBACK_SPEEDS.len() {
+            assert_eq!(replay_player.handle_event(key('-')), None);
+        }
+        assert_eq!(
+            ticks_until_action(&mut replay_player),
+            Some(PLAYBACK_SPEEDS[0])
+        );
+        assert!(replay_player.status().contains("Speed 1/"));
+    }
+
+    #[test]
+    fn playback_reaches_recorded_snapshot() {
+        let mut recorded = Engine::new("PLAYBACK".to_owned()).unwrap();
+        let script = [
+            Action::Select(0),
+            Action::Select(1),
+            Action::Discard,
+            Action::Deselect(1),
+            Action::Play,
+        ];
+        for &action in script.iter().cycle().take(60) {
+            if recorded.run().run_state != RunState::Running {
+                break;
+            }
+            if recorded.is_legal(action).unwrap() {
+                drop(recorded.apply(action).unwrap());
+            }
+        }
+
+        let mut played_back = Engine::new(recorded.replay().seed.clone()).unwrap();
+        let mut replay_player = ReplayPlayer::new(recorded.replay().clone());
+        while !replay_player.is_finished() {
+            if let Some(action) = replay_player.handle_event(Event::Tick) {
+                drop(played_back.apply(action).unwrap());
+            }
+        }
+
+        assert!(!recorded.replay().actions.is_empty());
+        assert_eq!(
+            played_back.snapshot().unwrap(),
+            recorded.snapshot().unwrap()
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! This module provides a JSON-lines protocol to play the game over standard
+//! input and output without a terminal interface.
+//!
+//! Every line read from the input is a [`Request`] and every line written to
+//! the output is a [`Response`]. The current state is written once when the
+//! game starts and after every request.
+//!
+//! ```text
+//! > {"type":"action","action":{"Select":0}}
+//! < {"type":"state","outcome":"SelectionChanged","state":{...},"legal_actions":[...]}
+//! > {"type":"action","action":"Play"}
+//! < {"type":"state","outcome":{"HandPlayed":"56"},"state":{...},"legal_actions":[...]}
+//! > {"type":"reset","seed":"ABCDEF"}
+//! < {"type":"state","outcome":null,"state":{...},"legal_actions":[...]}
+//! ```
+//!
+//! Malformed requests and illegal actions are answered with an error response
+//! and leave the game untouched.
+
+use std::io::{BufRead, Write};
+
+use balatro_tui_core::{
+    action::Action,
+    engine::{Engine, Outcome, Snapshot},
+    rng::random_seed,
+};
+use clap::ValueEnum;
+use color_eyre::eyre::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Protocols available for playing the game without a terminal interface.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, ValueEnum)]
+pub enum Protocol {
+    /// Newline-delimited JSON over standard input and output.
+    Jsonl,
+}
+
+/// Message accepted by the protocol.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Request {
+    /// Apply an action on the current game.
+    Action {
+        /// Action to be applied.
+        action: Action,
+    },
+    /// Start a new game, optionally with the given seed.
+    Reset {
+        /// Seed of the new game. A random seed is used if not provided.
+        #[serde(default)]
+        seed: Option<String>,
+    },
+}
+
+/// Message emitted by the protocol.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Response {
+    /// Current state of the game.
+    State {
+        /// Outcome of the last applied action, if any.
+        outcome: Option<Outcome>,
+        /// Observable state of the game.
+        state: Box<Snapshot>,
+        /// Actions that can be applied in the current state.
+        legal_actions: Vec<Action>,
+    },
+    /// The last request could not be served.
+    Error {
+        /// Description of the failure.
+        message: String,
+    },
+}
+
+/// Serves the JSON-lines protocol over the given input and output until the
+/// input is exhausted.
+pub fn serve<R: BufRead, W: Write>(input: R, mut output: W, seed: Option<String>) -> Result<()> {
+    let mut engine = Engine::new(seed.unwrap_or_else(random_seed))?;
+    send(&mut output, &state_response(&engine, None))?;
+
+    for line_result in input.lines() {
+        let line = line_result.wrap_err("Unable to read request from input.")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(Request::Action { action }) => match engine.apply(action) {
+                Ok(outcome) => state_response(&engine, Some(outcome)),
+                Err(err) => Response::Error {
+                    message: err.to_string(),
+                },
+            },
+            Ok(Request::Reset { seed: reset_seed }) => {
+                match Engine::new(reset_seed.unwrap_or_else(random_seed)) {
+                    Ok(new_engine) => {
+                        engine = new_engine;
+                        state_response(&engine, None)
+                    }
+                    Err(err) => Response::Error {
+                        message: err.to_string(),
+                    },
+                }
+            }
+            Err(err) => Response::Error {
+                message: format!("Malformed request: {err}"),
+            },
+        };
+
+        send(&mut output, &response)?;
+    }
+
+    Ok(())
+}
+
+/// Creates a [`Response::State`] for the current state of the engine.
+pub(crate) fn state_response(engine: &Engine, outcome: Option<Outcome>) -> Response {
+    match engine
+        .snapshot()
+        .and_then(|state| Ok((state, engine.legal_actions()?)))
+    {
+        Ok((state, legal_actions)) => Response::State {
+            outcome,
+            state: Box::new(state),
+            legal_actions,
+        },
+        Err(err) => Response::Error {
+            message: err.to_string(),
+        },
+    }
+}
+
+/// Writes a single response line and flushes the output.
+fn send<W: Write>(output: &mut W, response: &Response) -> Result<()> {
+    serde_json::to_writer(&mut *output, response)?;
+    writeln!(output)?;
+    output
+        .flush()
+        .wrap_err("Unable to write response to output.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn responses(input: &str) -> Vec<Response> {
+        let mut output = vec![];
+        serve(input.as_bytes(), &mut output, Some("SEED".to_owned())).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn serves_actions_and_errors() {
+        let responses = responses(concat!(
+            "{\"type\":\"action\",\"action\":{\"Select\":0}}\n",
+            "\n",
+            "{\"type\":\"action\",\"action\":\"Play\"}\n",
+            "{\"type\":\"action\",\"action\":{\"Deselect\":0}}\n",
+            "not json\n",
+            "{\"type\":\"reset\",\"seed\":\"SEED\"}\n",
+        ));
+
+        assert_eq!(responses.len(), 6);
+        assert!(matches!(&responses[0], Response::State {
+            outcome: None,
+            ..
+        }));
+        assert!(matches!(&responses[1], Response::State {
+            outcome: Some(Outcome::SelectionChanged),
+            ..
+        }));
+        assert!(matches!(&responses[2], Response::State {
+            outcome: Some(Outcome::HandPlayed(_)),
+            ..
+        }));
+        assert!(matches!(&responses[3], Response::Error { .. }));
+        assert!(matches!(&responses[4], Response::Error { .. }));
+        assert_eq!(responses[5], responses[0]);
+    }
+}
@@ -0,0 +1,88 @@
+//! This module loads puzzles from puzzle files.
+//!
+//! A puzzle file is a [`Puzzle`] written in TOML or JSON, as decided by the
+//! file extension. Cards in hand and the draw order are written as whitespace
+//! separated card notation.
+//!
+//! ```toml
+//! name = "Pocket Aces"
+//! description = "Beat the blind with a single pair."
+//! hand = "AS AH 7C 3D 2S"
+//! draw_order = "KS 9H"
+//! target_score = 50
+//! hands = 1
+//! discards = 1
+//! solution = [{ Play = [0, 1] }]
+//! ```
+//!
+//! A puzzle pack is bundled in the `puzzles` directory of the crate. Every
+//! bundled puzzle carries its solution, which is verified by the tests.
+
+use std::path::Path;
+
+use balatro_tui_core::puzzle::Puzzle;
+use color_eyre::eyre::Result;
+
+use crate::storage::read_definition;
+
+/// Loads a puzzle from a puzzle file.
+#[inline]
+pub fn load(path: &Path) -> Result<Puzzle> {
+    read_definition(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use balatro_tui_core::{
+        engine::{Engine, Outcome},
+        strategy::{apply_move, Move},
+    };
+
+    use super::*;
+    use crate::storage::parse_definition;
+
+    /// Bundled puzzles along with the final score of their solutions.
+    const PUZZLE_PACK: [(&str, &str); 4] = [
+        (include_str!("../puzzles/flush_out.toml"), "256"),
+        (include_str!("../puzzles/full_house.toml"), "304"),
+        (include_str!("../puzzles/two_step.toml"), "282"),
+        (include_str!("../puzzles/straight_shooter.toml"), "195"),
+    ];
+
+    #[test]
+    fn bundled_puzzles_are_solved() {
+        for (content, score) in PUZZLE_PACK {
+            let puzzle = parse_definition::<Puzzle>(content, "toml").unwrap();
+            let mut engine = Engine::with_puzzle(&puzzle).unwrap();
+
+            let mut outcome = Outcome::SelectionChanged;
+            for next_move in puzzle.solution.clone() {
+                outcome = apply_move(&mut engine, next_move).unwrap();
+            }
+
+            assert_eq!(outcome, Outcome::RunFinished(true), "{}", puzzle.name);
+            assert_eq!(
+                engine.snapshot().unwrap().score.to_string(),
+                score,
+                "{}",
+                puzzle.name
+            );
+        }
+    }
+
+    #[test]
+    fn bundled_puzzles_are_not_trivial() {
+        for (content, _) in PUZZLE_PACK {
+            let puzzle = parse_definition::<Puzzle>(content, "toml").unwrap();
+            let mut engine = Engine::with_puzzle(&puzzle).unwrap();
+            let best_play = engine.best_play().unwrap().unwrap();
+
+            assert_ne!(
+                apply_move(&mut engine, Move::Play(best_play.indices)).unwrap(),
+                Outcome::RunFinished(true),
+                "{} must not be solved by the best play in hand",
+                puzzle.name
+            );
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! This module provides the `simulate` subcommand that plays many headless
+//! games with a [`Strategy`] and reports statistics about them.
+//!
+//! Games are played in parallel across all cores. Game `n` is seeded with the
+//! decimal representation of `n`, so that runs over the same seed range are
+//! reproducible. The statistics include the win rate, the average ante
+//! reached, the distribution of hand types played and a histogram of the
+//! final score relative to the target score of the blind.
+//!
+//! Besides the built-in strategies, games can be played by an external
+//! program with [`StrategyKind::External`]. The program is started once per
+//! game and speaks JSON lines over its standard input and output: for every
+//! move, it reads the state of the game as a `state` message of the
+//! [`crate::protocol`] and answers with a [`Move`], eg, `{"Play":[0,1]}` or
+//! `{"Discard":[4]}`. Strategies written in Rust can instead implement the
+//! [`Strategy`] trait of the core library.
+
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, BufReader, Write},
+    ops::Range,
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+use balatro_tui_core::{
+    engine::Engine,
+    error::CoreError,
+    rng::seeded_rng,
+    run::RunState,
+    scorer::{Scorer, ScoringHand},
+    strategy::{apply_move, FlushStrategy, GreedyStrategy, Move, RandomStrategy, Strategy},
+};
+use clap::{Args, ValueEnum};
+use color_eyre::eyre::{eyre, Context, Result};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::protocol::state_response;
+
+/// Number of buckets of the final score histogram. Every bucket spans a tenth
+/// of the target score, with the last bucket holding scores that beat it.
+const HISTOGRAM_BUCKETS: usize = 11;
+
+/// Built-in strategies available for simulation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, ValueEnum)]
+pub enum StrategyKind {
+    /// Plays or discards a random selection of cards.
+    Random,
+    /// Always plays the highest scoring play in hand.
+    Greedy,
+    /// Discards off-suit cards to draw into a flush.
+    DiscardForFlush,
+    /// Asks the program given by `--strategy-command` for every move.
+    External,
+}
+
+impl StrategyKind {
+    /// Creates a new instance of the strategy for the game with the given seed.
+    /// External strategies start a new instance of the given command.
+    fn build(self, seed: &str, command: Option<&str>) -> Result<Box<dyn Strategy>, CoreError> {
+        Ok(match self {
+            Self::Random => Box::new(RandomStrategy::new(seeded_rng(&format!("{seed}-strategy")))),
+            Self::Greedy => Box::new(GreedyStrategy),
+            Self::DiscardForFlush => Box::new(FlushStrategy),
+            Self::External => Box::new(ExternalStrategy::spawn(command.ok_or_else(|| {
+                CoreError::StrategyError("No strategy command given".to_owned())
+            })?)?),
+        })
+    }
+}
+
+/// [`Strategy`] backed by an external program speaking JSON lines over its
+/// standard input and output. The program is killed once the strategy is
+/// dropped.
+#[derive(Debug)]
+struct ExternalStrategy {
+    /// Running program.
+    child: Child,
+    /// Standard input of the program, where states are written.
+    input: ChildStdin,
+    /// Standard output of the program, where moves are read from.
+    output: BufReader<ChildStdout>,
+}
+
+impl ExternalStrategy {
+    /// Starts the given command, split on whitespace into the program and its
+    /// arguments.
+    fn spawn(command: &str) -> Result<Self, CoreError> {
+        let mut words = command.split_whitespace();
+        let program = words
+            .next()
+            .ok_or_else(|| CoreError::StrategyError("Strategy command is empty".to_owned()))?;
+        let mut child = Command::new(program)
+            .args(words)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|err| CoreError::StrategyError(format!("Unable to start {program}: {err}")))?;
+        let (Some(input), Some(output)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(CoreError::StrategyError(
+                "Strategy command has no standard input or output".to_owned(),
+            ));
+        };
+
+        Ok(Self {
+            child,
+            input,
+            output: BufReader::new(output),
+        })
+    }
+}
+
+impl Strategy for ExternalStrategy {
+    fn next_move(&mut self, engine: &Engine) -> Result<Move, CoreError> {
+        let to_strategy_error = |err: io::Error| CoreError::StrategyError(err.to_string());
+
+        serde_json::to_writer(&mut self.input, &state_response(engine, None))
+            .map_err(|err| CoreError::StrategyError(err.to_string()))?;
+        writeln!(self.input).map_err(to_strategy_error)?;
+        self.input.flush().map_err(to_strategy_error)?;
+
+        let mut line = String::new();
+        if self
+            .output
+            .read_line(&mut line)
+            .map_err(to_strategy_error)?
+            == 0
+        {
+            return Err(CoreError::StrategyError(
+                "Strategy command exited before the game was over".to_owned(),
+            ));
+        }
+        serde_json::from_str(&line)
+            .map_err(|err| CoreError::StrategyError(format!("Malformed move {line:?}: {err}")))
+    }
+}
+
+impl Drop for ExternalStrategy {
+    fn drop(&mut self) {
+        drop(self.child.kill());
+        drop(self.child.wait());
+    }
+}
+
+/// Formats in which simulation statistics can be printed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned plain text table.
+    #[default]
+    Table,
+    /// Comma separated values with a header row.
+    Csv,
+}
+
+/// Arguments of the `simulate` subcommand.
+#[derive(Clone, Debug, Args)]
+pub struct SimulateArgs {
+    /// Number of games to play, seeded from `0` onwards.
+    #[arg(long, default_value_t = 1000)]
+    pub games: u64,
+    /// Strategy used to play the games.
+    #[arg(long, value_enum, default_value_t = StrategyKind::Greedy)]
+    pub strategy: StrategyKind,
+    /// Command of the external program deciding the moves, split on
+    /// whitespace into the program and its arguments. Required by the
+    /// `external` strategy.
+    #[arg(long, value_name = "COMMAND", required_if_eq("strategy", "external"))]
+    pub strategy_command: Option<String>,
+    /// Range of seeds of the games to play, eg, `1000..2000`.
+    #[arg(long, value_name = "START..END", value_parser = parse_seed_range, conflicts_with = "games")]
+    pub seed_range: Option<Range<u64>>,
+    /// Format in which statistics are printed.
+    #[arg(long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+}
+
+/// Parses a seed range of the form `START..END`.
+fn parse_seed_range(value: &str) -> Result<Range<u64>> {
+    let (start, end) = value
+        .split_once("..")
+        .ok_or_else(|| eyre!("Expected a range of the form START..END"))?;
+    Ok(start.trim().parse()?..end.trim().parse()?)
+}
+
+/// Result of a single simulated game.
+#[derive(Clone, Debug, Default)]
+struct GameResult {
+    /// Whether the run was won.
+    won: bool,
+    /// Ante reached by the run.
+    ante: usize,
+    /// Hand types played during the run, in order.
+    hand_types: Vec<ScoringHand>,
+    /// Index of the final score histogram bucket of the run.
+    histogram_bucket: usize,
+}
+
+/// Aggregated statistics over simulated games.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Stats {
+    /// Number of games played.
+    games: u64,
+    /// Number of games won.
+    wins: u64,
+    /// Sum of antes reached over all games.
+    antes: u64,
+    /// Number of times every hand type was played.
+    hand_types: BTreeMap<ScoringHand, u64>,
+    /// Number of games whose final score falls in every bucket.
+    histogram: [u64; HISTOGRAM_BUCKETS],
+}
+
+impl Stats {
+    /// Adds the result of a game to the statistics.
+    fn record(mut self, result: GameResult) -> Self {
+        self.games = self.games.saturating_add(1);
+        self.wins = self.wins.saturating_add(u64::from(result.won));
+        self.antes = self.antes.saturating_add(result.ante as u64);
+        for hand_type in result.hand_types {
+            let count = self.hand_types.entry(hand_type).or_default();
+            *count = count.saturating_add(1);
+        }
+        if let Some(count) = self.histogram.get_mut(result.histogram_bucket) {
+            *count = count.saturating_add(1);
+        }
+        self
+    }
+
+    /// Merges the statistics of two disjoint sets of games.
+    fn merge(mut self, other: Self) -> Self {
+        self.games = self.games.saturating_add(other.games);
+        self.wins = self.wins.saturating_add(other.wins);
+        self.antes = self.antes.saturating_add(other.antes);
+        for (hand_type, other_count) in other.hand_types {
+            let count = self.hand_types.entry(hand_type).or_default();
+            *count = count.saturating_add(other_count);
+        }
+        for (count, other_count) in self.histogram.iter_mut().zip(other.histogram) {
+            *count = count.saturating_add(other_count);
+        }
+        self
+    }
+
+    /// Returns the statistics as `[metric, key, value]` rows.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "Intended: Ratios are only printed with limited precision."
+    )]
+    fn rows(&self) -> Vec<[String; 3]> {
+        let games = self.games.max(1) as f64;
+        let mut rows = vec![
+            ["games".to_owned(), String::new(), self.games.to_string()],
+            ["wins".to_owned(), String::new(), self.wins.to_string()],
+            [
+                "win_rate".to_owned(),
+                String::new(),
+                format!("{:.4}", self.wins as f64 / games),
+            ],
+            [
+                "average_ante".to_owned(),
+                String::new(),
+                format!("{:.2}", self.antes as f64 / games),
+            ],
+        ];
+        rows.extend(self.hand_types.iter().map(|(hand_type, &count)| {
+            [
+                "hand_type".to_owned(),
+                hand_type.to_string(),
+                count.to_string(),
+            ]
+        }));
+        rows.extend(self.histogram.iter().enumerate().map(|(bucket, count)| {
+            let key = if bucket.saturating_add(1) == HISTOGRAM_BUCKETS {
+                ">=100%".to_owned()
+            } else {
+                format!(
+                    "{}-{}%",
+                    bucket.saturating_mul(10),
+                    bucket.saturating_add(1).saturating_mul(10)
+                )
+            };
+            ["score_histogram".to_owned(), key, count.to_string()]
+        }));
+        rows
+    }
+}
+
+/// Plays a single game with a new instance of the strategy.
+fn simulate_game(
+    seed: &str,
+    kind: StrategyKind,
+    command: Option<&str>,
+) -> Result<GameResult, CoreError> {
+    let mut engine = Engine::new(seed.to_owned())?;
+    let mut strategy = kind.build(seed, command)?;
+    let mut result = GameResult::default();
+
+    let won = loop {
+        if let RunState::Finished(won) = engine.run().run_state {
+            break won;
+        }
+
+        let next_move = match strategy.next_move(&engine)? {
+            Move::Play(indices) => {
+                let hand = engine.run().round.hand.try_read()?;
+                let cards = indices
+                    .iter()
+                    .filter_map(|&idx| hand.get(idx).copied())
+                    .collect::<Vec<_>>();
+                drop(hand);
+                if let (Some(hand_type), _) = Scorer::get_scoring_hand(&cards)? {
+                    result.hand_types.push(hand_type);
+                }
+                Move::Play(indices)
+            }
+            discard @ Move::Discard(_) => discard,
+        };
+        drop(apply_move(&mut engine, next_move)?);
+    };
+
+    let snapshot = engine.snapshot()?;
+    let ratio = snapshot.score.to_f64() / snapshot.target_score.to_f64();
+    result.won = won;
+    result.ante = snapshot.ante.get();
+    result.histogram_bucket = histogram_bucket(ratio);
+    Ok(result)
+}
+
+/// Returns the histogram bucket of a final score to target score ratio.
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "Intended: Ratio is clamped to the bucket range before conversion."
+)]
+fn histogram_bucket(ratio: f64) -> usize {
+    let last_bucket = HISTOGRAM_BUCKETS.saturating_sub(1);
+    if ratio >= 1.0 {
+        last_bucket
+    } else {
+        ((ratio * 10.0).floor().max(0.0) as usize).min(last_bucket)
+    }
+}
+
+/// Runs the simulation and writes the statistics to the output.
+pub fn run<W: Write>(args: &SimulateArgs, mut output: W) -> Result<()> {
+    let seeds = args.seed_range.clone().unwrap_or(0..args.games);
+
+    let stats = seeds
+        .into_par_iter()
+        .map(|seed| {
+            simulate_game(
+                &seed.to_string(),
+                args.strategy,
+                args.strategy_command.as_deref(),
+            )
+        })
+        .try_fold(Stats::default, |stats, result| {
+            result.map(|game| stats.record(game))
+        })
+        .try_reduce(Stats::default, |first, second| Ok(first.merge(second)))
+        .wrap_err("Error encountered while simulating games.")?;
+
+    let rows = stats.rows();
+    match args.format {
+        OutputFormat::Table => {
+            let widths = [0, 1, 2].map(|column| {
+                rows.iter()
+                    .map(|row| row.get(column).map_or(0, String::len))
+                    .max()
+                    .unwrap_or(0)
+            });
+            for row in &rows {
+                let [metric, key, value] = row.each_ref();
+                writeln!(
+                    output,
+                    "{metric:<metric_width$}  {key:<key_width$}  {value:>value_width$}",
+                    metric_width = widths[0],
+                    key_width = widths[1],
+                    value_width = widths[2],
+                )?;
+            }
+        }
+        OutputFormat::Csv => {
+            writeln!(output, "metric,key,value")?;
+            for row in &rows {
+                writeln!(output, "{}", row.join(","))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulate_prints_csv() {
+        let args = SimulateArgs {
+            games: 0,
+            strategy: StrategyKind::DiscardForFlush,
+            strategy_command: None,
+            seed_range: Some(10..14),
+            format: OutputFormat::Csv,
+        };
+        let mut output = vec![];
+
+        run(&args, &mut output).unwrap();
+        let text = String::from_utf8(output).unwrap();
+        let mut lines = text.lines();
+
+        assert_eq!(lines.next(), Some("metric,key,value"));
+        assert_eq!(lines.next(), Some("games,,4"));
+        assert!(text.contains("score_histogram,>=100%,"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn external_strategy_plays_moves_of_command() {
+        use std::{env::temp_dir, fs, process};
+
+        let script = temp_dir().join(format!("balatro_tui_strategy_{}.sh", process::id()));
+        fs::write(
+            &script,
+            "while read -r state; do echo '{\"Play\":[0]}'; done\n",
+        )
+        .unwrap();
+        let args = SimulateArgs {
+            games: 2,
+            strategy: StrategyKind::External,
+            strategy_command: Some(format!("sh {}", script.display())),
+            seed_range: None,
+            format: OutputFormat::Csv,
+        };
+        let mut output = vec![];
+
+        let result = run(&args, &mut output);
+        fs::remove_file(&script).unwrap();
+        result.unwrap();
+        let text = String::from_utf8(output).unwrap();
+
+        assert!(text.contains("games,,2"));
+        assert!(text.contains("hand_type,High Card,"));
+    }
+
+    #[test]
+    fn seed_range_is_parsed() {
+        assert_eq!(parse_seed_range("5..10").unwrap(), 5..10);
+        assert!(parse_seed_range("5")
+            .unwrap_err()
+            .to_string()
+            .contains("START..END"));
+    }
+}
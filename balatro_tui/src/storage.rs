@@ -0,0 +1,249 @@
+//! This module provides persistent on-disk storage for the game.
+//!
+//! Files are stored as JSON under the XDG data directory, ie,
+//! `$XDG_DATA_HOME/balatro_tui`, falling back to
+//! `$HOME/.local/share/balatro_tui`. Files are written to a temporary file
+//! first and then renamed over the target, so that a crash while saving never
+//! leaves a corrupted file behind.
+//!
+//! This module also reads definition files written by players, eg, deck files
+//! and puzzles, which can be written in TOML or JSON, and locates the config
+//! directory of the game under the XDG config directory, ie,
+//! `$XDG_CONFIG_HOME/balatro_tui`, falling back to `$HOME/.config/balatro_tui`.
+
+#[cfg(test)]
+use std::cell::RefCell;
+use std::{
+    env::var_os,
+    fs::{create_dir_all, read_to_string, remove_file, rename, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use balatro_tui_core::{profile::Profile, replay::Replay, run::Run};
+use color_eyre::eyre::{bail, Context, OptionExt, Result};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Extension appended to the name of a file while it is being written.
+const TEMP_EXTENSION: &str = "tmp";
+
+/// Name of the directory holding game files inside XDG base directories.
+pub(crate) const APP_DIR_NAME: &str = "balatro_tui";
+
+/// File name of the auto-saved run.
+const SAVED_RUN_FILE: &str = "run.json";
+
+/// File name of the replay of the saved or last finished run.
+const REPLAY_FILE: &str = "replay.json";
+
+/// File name of the player profile.
+const PROFILE_FILE: &str = "profile.json";
+
+#[cfg(test)]
+thread_local! {
+    /// Data directory used in place of the XDG data directory by tests running
+    /// on the current thread, so that they never touch the files of the player.
+    static TEST_DATA_DIR: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Uses the given directory as the data directory for the rest of the current
+/// test.
+#[cfg(test)]
+pub(crate) fn use_test_data_dir(dir: PathBuf) {
+    TEST_DATA_DIR.set(Some(dir));
+}
+
+/// Returns the data directory for the game, creating it if it doesn't exist.
+pub(crate) fn data_dir() -> Result<PathBuf> {
+    #[cfg(test)]
+    if let Some(test_data_dir) = TEST_DATA_DIR.with_borrow(Clone::clone) {
+        create_dir_all(&test_data_dir).wrap_err("Unable to create game data directory.")?;
+        return Ok(test_data_dir);
+    }
+
+    let data_dir = var_os("XDG_DATA_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share")))
+        .ok_or_eyre("Unable to determine home directory for storing game data.")?
+        .join(APP_DIR_NAME);
+    create_dir_all(&data_dir).wrap_err("Unable to create game data directory.")?;
+    Ok(data_dir)
+}
+
+/// Returns the config directory for the game. The directory may not exist.
+pub(crate) fn config_dir() -> Result<PathBuf> {
+    Ok(var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .ok_or_eyre("Unable to determine home directory for reading game config.")?
+        .join(APP_DIR_NAME))
+}
+
+/// Reads and deserializes a JSON file from the data directory. Returns [`None`]
+/// if the file doesn't exist.
+#[inline]
+pub(crate) fn read_data<T: DeserializeOwned>(file_name: &str) -> Result<Option<T>> {
+    read_file(&data_dir()?.join(file_name))
+}
+
+/// Reads and deserializes a JSON file from the given path. Returns [`None`] if
+/// the file doesn't exist.
+pub(crate) fn read_file<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = read_to_string(path)
+        .wrap_err_with(|| format!("Unable to read data file {}.", path.display()))?;
+    Ok(Some(serde_json::from_str(&content).wrap_err_with(
+        || format!("Data file {} is corrupted.", path.display()),
+    )?))
+}
+
+/// Parses the content of a definition file, as TOML or JSON as decided by the
+/// extension of the file.
+pub(crate) fn parse_definition<T: DeserializeOwned>(content: &str, extension: &str) -> Result<T> {
+    Ok(match extension {
+        "toml" => toml::from_str(content)?,
+        "json" => serde_json::from_str(content)?,
+        _ => bail!("Unsupported file extension `{extension}`, expected `toml` or `json`."),
+    })
+}
+
+/// Reads and parses a definition file from the given path. See
+/// [`parse_definition()`].
+pub(crate) fn read_definition<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = read_to_string(path)
+        .wrap_err_with(|| format!("Unable to read file {}.", path.display()))?;
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+    parse_definition(&content, extension)
+        .wrap_err_with(|| format!("File {} is invalid.", path.display()))
+}
+
+/// Serializes and writes a JSON file into the data directory.
+pub(crate) fn write_data<T: Serialize>(file_name: &str, data: &T) -> Result<()> {
+    let path = data_dir()?.join(file_name);
+    write_atomically(&path, &serde_json::to_string(data)?)
+        .wrap_err_with(|| format!("Unable to write data file {}.", path.display()))
+}
+
+/// Writes the content into a temporary file next to the given path and renames
+/// it over the path once it is flushed to the disk. The file at the path is
+/// thus either left as is or fully replaced.
+fn write_atomically(path: &Path, content: &str) -> Result<()> {
+    let temp_path = path.with_extension(TEMP_EXTENSION);
+    let mut temp_file = File::create(&temp_path)?;
+    temp_file.write_all(content.as_bytes())?;
+    temp_file.sync_all()?;
+    drop(temp_file);
+    rename(&temp_path, path)?;
+
+    Ok(())
+}
+
+/// Removes a file from the data directory. No-op if the file doesn't exist.
+pub(crate) fn remove_data(file_name: &str) -> Result<()> {
+    let path = data_dir()?.join(file_name);
+    if path.exists() {
+        remove_file(&path)
+            .wrap_err_with(|| format!("Unable to remove data file {}.", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Saves a run so that it can be continued later.
+#[inline]
+pub(crate) fn save_run(run: &Run) -> Result<()> {
+    write_data(SAVED_RUN_FILE, run)
+}
+
+/// Loads the saved run, if any.
+#[inline]
+pub(crate) fn load_run() -> Result<Option<Run>> {
+    read_data(SAVED_RUN_FILE)
+}
+
+/// Returns `true` if a run is saved.
+#[inline]
+pub(crate) fn has_saved_run() -> Result<bool> {
+    Ok(data_dir()?.join(SAVED_RUN_FILE).exists())
+}
+
+/// Loads the saved run along with its [`Replay`], if any. A new replay is
+/// returned if the saved replay is not of the saved run.
+pub(crate) fn load_saved_run() -> Result<Option<(Run, Replay)>> {
+    let Some(run) = load_run()? else {
+        return Ok(None);
+    };
+    let replay = load_replay()?
+        .filter(|replay| replay.seed == run.properties.seed)
+        .unwrap_or_else(|| Replay::new(run.properties.seed.clone()));
+    Ok(Some((run, replay)))
+}
+
+/// Removes the saved run.
+#[inline]
+pub(crate) fn clear_saved_run() -> Result<()> {
+    remove_data(SAVED_RUN_FILE)
+}
+
+/// Saves the replay of the current run. The replay is kept after the run is
+/// over so that it can be shared.
+#[inline]
+pub(crate) fn save_replay(replay: &Replay) -> Result<()> {
+    write_data(REPLAY_FILE, replay)
+}
+
+/// Loads the replay of the saved or last finished run, if any.
+#[inline]
+pub(crate) fn load_replay() -> Result<Option<Replay>> {
+    read_data(REPLAY_FILE)
+}
+
+/// Saves the player profile.
+#[inline]
+pub(crate) fn save_profile(profile: &Profile) -> Result<()> {
+    write_data(PROFILE_FILE, profile)
+}
+
+/// Loads the player profile. A new profile is returned if none is saved.
+///
+/// Profiles saved in an older format are migrated to the current format.
+#[inline]
+pub(crate) fn load_profile() -> Result<Profile> {
+    read_data::<Profile>(PROFILE_FILE)?
+        .map(Profile::migrate)
+        .transpose()
+        .wrap_err("Unable to load the player profile.")
+        .map(Option::unwrap_or_default)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env::temp_dir, fs::remove_dir_all, process::id};
+
+    use super::*;
+
+    #[test]
+    fn writes_atomically_over_existing_file() {
+        let dir = temp_dir().join(format!("{APP_DIR_NAME}_storage_{}", id()));
+        create_dir_all(&dir).unwrap();
+        let path = dir.join(SAVED_RUN_FILE);
+
+        write_atomically(&path, "old").unwrap();
+        write_atomically(&path, "new").unwrap();
+
+        assert_eq!(read_to_string(&path).unwrap(), "new");
+        assert!(
+            !path.with_extension(TEMP_EXTENSION).exists(),
+            "Temporary file must be renamed over the target"
+        );
+
+        remove_dir_all(dir).unwrap();
+    }
+}
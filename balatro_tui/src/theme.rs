@@ -0,0 +1,251 @@
+//! This module provides loading of the [`Theme`] used to render the game.
+//!
+//! Themes start from a [`ThemePreset`], and colors can be overridden per
+//! element in `theme.toml` under the XDG config directory, ie,
+//! `$XDG_CONFIG_HOME/balatro_tui`, falling back to `$HOME/.config/balatro_tui`.
+//!
+//! ```toml
+//! # Preset to start from, one of `classic`, `four_color`, `high_contrast` or
+//! # `colorblind`.
+//! preset = "four_color"
+//!
+//! [colors]
+//! heart = "magenta"
+//! background = "#1e1e2e"
+//! ```
+//!
+//! Colors are written as color names, eg, `red` or `light-blue`, as hex
+//! values, eg, `#ff8800`, or as indexed colors, eg, `208`. The elements that
+//! can be colored are the suits `club`, `diamond`, `heart` and `spade`,
+//! `chips`, `multiplier`, `money`, the blinds `small_blind`, `big_blind` and
+//! `boss_blind`, `highlight`, `disabled`, `border` and `background`.
+//!
+//! ASCII mode replaces suit, chip and box drawing glyphs with ASCII
+//! characters. It is used when the locale, ie, the first set variable of
+//! `LC_ALL`, `LC_CTYPE` and `LANG`, is not UTF-8, unless `ascii` is set in the
+//! theme config. The `--ascii` flag always enables it.
+
+use std::{env::var, str::FromStr};
+
+use balatro_tui_widgets::Theme;
+use color_eyre::eyre::Result;
+use ratatui::style::Color;
+use serde::{de::Error as DeError, Deserialize, Deserializer};
+
+use crate::storage::{config_dir, read_definition};
+
+/// File name of the theme config.
+const THEME_FILE: &str = "theme.toml";
+
+/// Locale variables in the order of precedence.
+const LOCALE_VARIABLES: [&str; 3] = ["LC_ALL", "LC_CTYPE", "LANG"];
+
+/// Built-in themes.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemePreset {
+    /// See [`Theme::CLASSIC`].
+    #[default]
+    Classic,
+    /// See [`Theme::FOUR_COLOR`].
+    #[serde(alias = "four_colour")]
+    FourColor,
+    /// See [`Theme::HIGH_CONTRAST`].
+    HighContrast,
+    /// See [`Theme::COLORBLIND`].
+    #[serde(alias = "colourblind")]
+    Colorblind,
+}
+
+impl ThemePreset {
+    /// Returns the [`Theme`] of the preset.
+    #[must_use = "Preset theme must be used."]
+    pub const fn theme(self) -> Theme {
+        match self {
+            Self::Classic => Theme::CLASSIC,
+            Self::FourColor => Theme::FOUR_COLOR,
+            Self::HighContrast => Theme::HIGH_CONTRAST,
+            Self::Colorblind => Theme::COLORBLIND,
+        }
+    }
+}
+
+/// A color as written in the theme config.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct ThemeColor(Color);
+
+impl<'de> Deserialize<'de> for ThemeColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let color = String::deserialize(deserializer)?;
+        Color::from_str(&color).map(Self).map_err(|_parse_error| {
+            DeError::custom(format!(
+                "invalid color `{color}`, expected a color name, a hex value or a color index"
+            ))
+        })
+    }
+}
+
+/// Colors overridden in the theme config. Colors that are not set are taken
+/// from the preset.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ThemeColors {
+    /// See [`Theme::club`].
+    club: Option<ThemeColor>,
+    /// See [`Theme::diamond`].
+    diamond: Option<ThemeColor>,
+    /// See [`Theme::heart`].
+    heart: Option<ThemeColor>,
+    /// See [`Theme::spade`].
+    spade: Option<ThemeColor>,
+    /// See [`Theme::chips`].
+    chips: Option<ThemeColor>,
+    /// See [`Theme::multiplier`].
+    multiplier: Option<ThemeColor>,
+    /// See [`Theme::money`].
+    money: Option<ThemeColor>,
+    /// See [`Theme::small_blind`].
+    small_blind: Option<ThemeColor>,
+    /// See [`Theme::big_blind`].
+    big_blind: Option<ThemeColor>,
+    /// See [`Theme::boss_blind`].
+    boss_blind: Option<ThemeColor>,
+    /// See [`Theme::highlight`].
+    highlight: Option<ThemeColor>,
+    /// See [`Theme::disabled`].
+    disabled: Option<ThemeColor>,
+    /// See [`Theme::border`].
+    border: Option<ThemeColor>,
+    /// See [`Theme::background`].
+    background: Option<ThemeColor>,
+}
+
+/// Theme config as written in `theme.toml`.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ThemeConfig {
+    /// Preset to start from.
+    preset: ThemePreset,
+    /// Colors replacing the colors of the preset.
+    colors: ThemeColors,
+    /// Whether to render using ASCII characters only. Detected from the
+    /// locale if not set.
+    ascii: Option<bool>,
+}
+
+impl ThemeConfig {
+    /// Returns the [`Theme`] of the preset with the overridden colors. ASCII
+    /// mode is taken from the config, or is `ascii_locale` if not set.
+    fn theme(self, ascii_locale: bool) -> Theme {
+        let preset = self.preset.theme();
+        let colors = self.colors;
+        let pick = |color: Option<ThemeColor>, fallback: Color| {
+            color.map_or(fallback, |ThemeColor(picked)| picked)
+        };
+
+        Theme {
+            club: pick(colors.club, preset.club),
+            diamond: pick(colors.diamond, preset.diamond),
+            heart: pick(colors.heart, preset.heart),
+            spade: pick(colors.spade, preset.spade),
+            chips: pick(colors.chips, preset.chips),
+            multiplier: pick(colors.multiplier, preset.multiplier),
+            money: pick(colors.money, preset.money),
+            small_blind: pick(colors.small_blind, preset.small_blind),
+            big_blind: pick(colors.big_blind, preset.big_blind),
+            boss_blind: pick(colors.boss_blind, preset.boss_blind),
+            highlight: pick(colors.highlight, preset.highlight),
+            disabled: pick(colors.disabled, preset.disabled),
+            border: pick(colors.border, preset.border),
+            background: pick(colors.background, preset.background),
+            ascii: self.ascii.unwrap_or(ascii_locale),
+        }
+    }
+}
+
+/// Loads the theme config from the XDG config directory. The classic theme is
+/// returned if there is no config. ASCII mode is always used if `ascii` is
+/// set.
+pub fn load(ascii: bool) -> Result<Theme> {
+    let path = config_dir()?.join(THEME_FILE);
+    let config = if path.exists() {
+        read_definition::<ThemeConfig>(&path)?
+    } else {
+        ThemeConfig::default()
+    };
+
+    let mut theme = config.theme(is_ascii_locale(&locale()));
+    theme.ascii |= ascii;
+    Ok(theme)
+}
+
+/// Returns the locale of the terminal, ie, the first set locale variable.
+fn locale() -> String {
+    LOCALE_VARIABLES
+        .iter()
+        .filter_map(|name| var(name).ok())
+        .find(|locale| !locale.is_empty())
+        .unwrap_or_default()
+}
+
+/// Returns whether the locale lacks Unicode support. Unset locales default to
+/// `C`, which is ASCII-only, except on Windows where locale variables are not
+/// used.
+fn is_ascii_locale(locale: &str) -> bool {
+    if locale.is_empty() && cfg!(windows) {
+        return false;
+    }
+
+    let lowercase_locale = locale.to_ascii_lowercase();
+    !lowercase_locale.contains("utf-8") && !lowercase_locale.contains("utf8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn theme(config: &str) -> Result<Theme> {
+        Ok(toml::from_str::<ThemeConfig>(config)?.theme(false))
+    }
+
+    #[test]
+    fn colors_override_preset() {
+        let overridden = theme(
+            r##"
+            preset = "four_color"
+
+            [colors]
+            heart = "magenta"
+            background = "#1e1e2e"
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(overridden.heart, Color::Magenta);
+        assert_eq!(overridden.background, Color::Rgb(30, 30, 46));
+        assert_eq!(overridden.club, Theme::FOUR_COLOR.club);
+    }
+
+    #[test]
+    fn ascii_mode_follows_locale_unless_configured() {
+        assert!(is_ascii_locale("C"));
+        assert!(is_ascii_locale("en_US.ISO-8859-1"));
+        assert!(!is_ascii_locale("en_US.UTF-8"));
+        assert!(!is_ascii_locale("C.utf8"));
+
+        assert!(!theme("").unwrap().ascii);
+        assert!(theme("ascii = true").unwrap().ascii);
+        assert!(
+            !toml::from_str::<ThemeConfig>("ascii = false")
+                .unwrap()
+                .theme(true)
+                .ascii
+        );
+    }
+
+    #[test]
+    fn invalid_colors_are_rejected() {
+        drop(theme("[colors]\nheart = \"not a color\"").unwrap_err());
+        drop(theme("[colors]\nfelt = \"green\"").unwrap_err());
+    }
+}
@@ -0,0 +1,50 @@
+//! Benchmarks for evaluating and scoring played cards.
+
+#![expect(
+    missing_docs,
+    reason = "Intended: Criterion macros generate undocumented benchmark entrypoints."
+)]
+
+use balatro_tui_core::{
+    card::parse_hand, evaluator::HandEvaluation, scorer::Scorer, solver::best_play,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmarks evaluation and scoring of a single played hand.
+fn bench_scoring(criterion: &mut Criterion) {
+    let hands = [
+        (
+            "high_card",
+            parse_hand("2H 7S 9D JC KH").unwrap_or_default(),
+        ),
+        (
+            "full_house",
+            parse_hand("QH QS QD 4C 4H").unwrap_or_default(),
+        ),
+        (
+            "straight_flush",
+            parse_hand("5S 6S 7S 8S 9S").unwrap_or_default(),
+        ),
+    ];
+
+    for (name, cards) in hands {
+        _ = criterion.bench_function(&format!("evaluate/{name}"), |bencher| {
+            bencher.iter(|| HandEvaluation::evaluate(black_box(&cards)));
+        });
+        _ = criterion.bench_function(&format!("score_cards/{name}"), |bencher| {
+            bencher.iter(|| Scorer::score_cards(black_box(&cards)));
+        });
+    }
+}
+
+/// Benchmarks searching the best play of a full hand.
+fn bench_best_play(criterion: &mut Criterion) {
+    let hand = parse_hand("2H 7H 2S 9H KC JH KS 4H QD QC").unwrap_or_default();
+
+    _ = criterion.bench_function("best_play/ten_cards", |bencher| {
+        bencher.iter(|| best_play(black_box(&hand)));
+    });
+}
+
+criterion_group!(benches, bench_scoring, bench_best_play);
+criterion_main!(benches);
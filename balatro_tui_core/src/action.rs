@@ -0,0 +1,31 @@
+//! This module provides [`Action`], the set of inputs a player can make during
+//! a run.
+//!
+//! Given the same [`super::run::RunProperties::seed`], applying the same
+//! sequence of actions always results in the same run. This is what makes a
+//! [`super::replay::Replay`] reproducible.
+
+use serde::{Deserialize, Serialize};
+
+use super::card::SortOrder;
+
+/// An input made by the player during a run.
+///
+/// Card indices refer to the position of a card in the current hand.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Action {
+    /// Select the card at the given index in hand.
+    Select(usize),
+    /// Deselect the card at the given index in hand.
+    Deselect(usize),
+    /// Play the selected cards.
+    Play,
+    /// Discard the selected cards.
+    Discard,
+    /// Sort the hand in the given order, which is kept for the following deals.
+    Sort(SortOrder),
+    /// Move the card at the given index in hand one position to the left.
+    MoveLeft(usize),
+    /// Move the card at the given index in hand one position to the right.
+    MoveRight(usize),
+}
@@ -6,6 +6,7 @@
 
 use std::num::NonZeroUsize;
 
+use serde::{Deserialize, Serialize};
 use strum::{
     Display as EnumDisplay, EnumCount, EnumIter, EnumProperty, EnumString, IntoStaticStr,
     VariantArray,
@@ -14,6 +15,7 @@ use strum::{
 use crate::{
     enum_property_ext::EnumPropertyExt,
     error::{ArithmeticError, ScorerError, StrumError},
+    score::Score,
 };
 
 /// Blind type can be either small blind, big blind or boss blind.
@@ -27,6 +29,7 @@ use crate::{
     Copy,
     Debug,
     Default,
+    Deserialize,
     EnumDisplay,
     EnumCount,
     EnumProperty,
@@ -36,6 +39,7 @@ use crate::{
     Ord,
     PartialEq,
     PartialOrd,
+    Serialize,
 )]
 #[repr(usize)]
 pub enum Blind {
@@ -66,13 +70,18 @@ pub enum Blind {
 /// Bosses are different blinds that can be randomly show up during a run as
 /// boss blind. Each boss has a unique associated power that plays out during
 /// the boss blind round.
+///
+/// The power is described by the `description` property that can be fetched
+/// using [`Bosses::get_description()`].
 #[derive(
     Clone,
     Copy,
     Debug,
+    Deserialize,
     EnumDisplay,
     EnumCount,
     EnumIter,
+    EnumProperty,
     EnumString,
     Eq,
     Hash,
@@ -80,69 +89,105 @@ pub enum Blind {
     Ord,
     PartialEq,
     PartialOrd,
+    Serialize,
     VariantArray,
 )]
 #[strum(prefix = "The ")]
 pub enum Bosses {
     /// Discards 2 random cards from your hand, after each hand played
+    #[strum(props(
+        description = "Discards 2 random cards from your hand, after each hand played"
+    ))]
     Hook,
     /// Playing your most played hand this run sets money to $0
+    #[strum(props(description = "Playing your most played hand this run sets money to $0"))]
     Ox,
     /// First hand is drawn face down
+    #[strum(props(description = "First hand is drawn face down"))]
     House,
     /// Extra large blind (2x base target)
+    #[strum(props(description = "Extra large blind (2x base target)"))]
     Wall,
     /// 1 in 7 cards get drawn face-down throughout the round
+    #[strum(props(description = "1 in 7 cards get drawn face down throughout the round"))]
     Wheel,
     ///  Decreases the level of Hand you play by 1 (hand levels can go to Level
     /// 1, and are permanently reduced before scoring)
+    #[strum(props(description = "Decreases the level of the hand you play by 1"))]
     Arm,
     /// All Club cards are debuffed
+    #[strum(props(description = "All Club cards are debuffed"))]
     Club,
     /// Cards are drawn face down after each hand played
+    #[strum(props(description = "Cards are drawn face down after each hand played"))]
     Fish,
     /// Must play 5 cards (they do not need to be scoring)
+    #[strum(props(description = "Must play 5 cards (they do not need to be scoring)"))]
     Psychic,
     /// All Spade cards are debuffed
+    #[strum(props(description = "All Spade cards are debuffed"))]
     Goad,
     /// Start with 0 discards
+    #[strum(props(description = "Start with 0 discards"))]
     Water,
     /// All Diamond cards are debuffed
+    #[strum(props(description = "All Diamond cards are debuffed"))]
     Window,
     /// -1 Hand Size
+    #[strum(props(description = "-1 hand size"))]
     Manacle,
     /// Every hand played this round must be of a different type and not
     /// previously played this round
+    #[strum(props(description = "No repeat hand types this round"))]
     Eye,
     /// Only one hand type can be played this round
+    #[strum(props(description = "Only one hand type can be played this round"))]
     Mouth,
     /// All face cards are debuffed
+    #[strum(props(description = "All face cards are debuffed"))]
     Plant,
     /// After playing a hand or discarding cards, you always draw 3 cards (hand
     /// size is ignored)
+    #[strum(props(description = "After playing a hand or discarding cards, always draw 3 cards"))]
     Serpent,
     /// Cards played previously this Ante (during Small and Big Blinds) are
     /// debuffed
+    #[strum(props(description = "Cards played previously this ante are debuffed"))]
     Pillar,
     /// Play only 1 hand (0.5x base target)
+    #[strum(props(description = "Play only 1 hand (0.5x base target)"))]
     Needle,
     /// All Heart cards are debuffed
+    #[strum(props(description = "All Heart cards are debuffed"))]
     Head,
     /// Lose $1 per card played
+    #[strum(props(description = "Lose $1 per card played"))]
     Tooth,
     /// The base Chips and Multiplier for playing a poker hand are halved this
     /// round
+    #[strum(props(description = "Base chips and multiplier are halved"))]
     Flint,
     /// All face cards are drawn face down
+    #[strum(props(description = "All face cards are drawn face down"))]
     Mark,
 }
 
+impl Bosses {
+    /// Returns the description of the power of the boss.
+    #[inline]
+    pub fn get_description(&self) -> Result<&str, StrumError> {
+        self.get_property("description")
+    }
+}
+
+/// Base amounts for each ante that are scaled by the blind multipliers to
+/// compute the target score.
 const BLIND_BASE_AMOUNTS: [usize; 8] = [3, 8, 20, 50, 110, 200, 350, 500];
 
 impl Blind {
     /// Returns the target score required to cross the round with this blind.
     #[inline]
-    pub fn get_target_score(&self, ante: NonZeroUsize) -> Result<usize, ScorerError> {
+    pub fn get_target_score(&self, ante: NonZeroUsize) -> Result<Score, ScorerError> {
         if ante.get() >= BLIND_BASE_AMOUNTS.len() {
             return Err(ScorerError::AnteExceeded(ante.get()));
         }
@@ -163,21 +208,15 @@ impl Blind {
             2
         };
 
-        Ok(chips_multiplier
-            .checked_mul(blind_multiple)
-            .ok_or(ArithmeticError::Overflow("multiplication"))?
-            .checked_mul(boss_blind_multiplier)
-            .ok_or(ArithmeticError::Overflow("multiplication"))?
-            .checked_mul(
-                *BLIND_BASE_AMOUNTS
-                    .get(
-                        ante.get()
-                            .checked_sub(1)
-                            .ok_or(ArithmeticError::Overflow("subtraction"))?,
-                    )
-                    .ok_or_else(|| ScorerError::AnteExceeded(ante.get()))?,
+        let base_amount = *BLIND_BASE_AMOUNTS
+            .get(
+                ante.get()
+                    .checked_sub(1)
+                    .ok_or(ArithmeticError::Overflow("subtraction"))?,
             )
-            .ok_or(ArithmeticError::Overflow("multiplication"))?)
+            .ok_or_else(|| ScorerError::AnteExceeded(ante.get()))?;
+
+        Ok(Score::from(chips_multiplier) * blind_multiple * boss_blind_multiplier * base_amount)
     }
 
     /// Returns color used to represent the blind.
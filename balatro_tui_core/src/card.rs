@@ -14,6 +14,7 @@ use std::{
 };
 
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 use strum::{Display as EnumDisplay, EnumCount, EnumIter, EnumProperty, EnumString, IntoStaticStr};
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -62,6 +63,7 @@ use crate::{
     Clone,
     Copy,
     Debug,
+    Deserialize,
     EnumDisplay,
     EnumCount,
     EnumIter,
@@ -73,29 +75,53 @@ use crate::{
     Ord,
     PartialEq,
     PartialOrd,
+    Serialize,
 )]
 pub enum Suit {
     /// Club suit (♣/C)
-    #[strum(serialize = "\u{2663}", serialize = "C", props(display = "\u{2663}"))]
+    #[strum(
+        serialize = "\u{2663}",
+        serialize = "C",
+        props(display = "\u{2663}", ascii = "C")
+    )]
     Club,
     /// Diamond suit (♦/D)
-    #[strum(serialize = "\u{2666}", serialize = "D", props(display = "\u{2666}"))]
+    #[strum(
+        serialize = "\u{2666}",
+        serialize = "D",
+        props(display = "\u{2666}", ascii = "D")
+    )]
     Diamond,
     /// Heart suit (♥/H)
-    #[strum(serialize = "\u{2665}", serialize = "H", props(display = "\u{2665}"))]
+    #[strum(
+        serialize = "\u{2665}",
+        serialize = "H",
+        props(display = "\u{2665}", ascii = "H")
+    )]
     Heart,
     /// Spade suit (♠/S)
-    #[strum(serialize = "\u{2660}", serialize = "S", props(display = "\u{2660}"))]
+    #[strum(
+        serialize = "\u{2660}",
+        serialize = "S",
+        props(display = "\u{2660}", ascii = "S")
+    )]
     Spade,
 }
 
 impl Suit {
-    /// Returns deterministic display value for the rank.
+    /// Returns deterministic display value for the suit.
     #[inline]
     pub fn get_display(&self) -> String {
         self.get_str("display")
             .map_or_else(|| self.to_string(), Into::into)
     }
+
+    /// Returns the first letter notation of the suit.
+    #[inline]
+    pub fn get_ascii(&self) -> String {
+        self.get_str("ascii")
+            .map_or_else(|| self.to_string(), Into::into)
+    }
 }
 
 /// Represents the rank of the card.
@@ -160,6 +186,7 @@ impl Suit {
     Clone,
     Copy,
     Debug,
+    Deserialize,
     EnumDisplay,
     EnumCount,
     EnumIter,
@@ -169,6 +196,7 @@ impl Suit {
     Hash,
     IntoStaticStr,
     PartialEq,
+    Serialize,
 )]
 pub enum Rank {
     /// Ace rank (A)
@@ -290,7 +318,25 @@ impl Ord for Rank {
 ///     suit: Suit::Diamond,
 /// });
 /// ```
-#[derive(Clone, Copy, Debug, Ord, PartialOrd, PartialEq, Eq, Hash)]
+///
+/// The canonical notation of a card is its rank followed by its suit. It is
+/// written by the [`Display`] implementation with unicode suits, or with first
+/// letter suits using the alternate flag. Both forms parse back into the same
+/// card.
+///
+/// ```
+/// # use balatro_tui_core::card::{Card, Rank, Suit};
+/// let card = Card {
+///     rank: Rank::Ten,
+///     suit: Suit::Spade,
+/// };
+///
+/// assert_eq!(card.to_string(), "10\u{2660}");
+/// assert_eq!(format!("{card:#}"), "10S");
+/// assert_eq!(card.to_string().parse::<Card>().unwrap(), card);
+/// assert_eq!(format!("{card:#}").parse::<Card>().unwrap(), card);
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Ord, PartialOrd, PartialEq, Eq, Hash, Serialize)]
 pub struct Card {
     /// Rank of the card
     pub rank: Rank,
@@ -301,7 +347,11 @@ pub struct Card {
 impl Display for Card {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{}{}", self.suit, self.rank)
+        if f.alternate() {
+            write!(f, "{}{}", self.rank.get_display(), self.suit.get_ascii())
+        } else {
+            write!(f, "{}{}", self.rank.get_display(), self.suit.get_display())
+        }
     }
 }
 
@@ -320,6 +370,66 @@ impl FromStr for Card {
     }
 }
 
+/// Parses a hand of cards written in card notation, separated by whitespace or
+/// commas.
+///
+/// ```
+/// # use balatro_tui_core::card::{parse_hand, Card, Rank, Suit};
+/// let hand = parse_hand("AS KS QS JS 10S").unwrap();
+///
+/// assert_eq!(hand.len(), 5);
+/// assert_eq!(hand[4], Card {
+///     rank: Rank::Ten,
+///     suit: Suit::Spade,
+/// });
+/// assert_eq!(parse_hand("A\u{2660}, 2H").unwrap().len(), 2);
+/// ```
+#[inline]
+pub fn parse_hand(hand: &str) -> Result<Vec<Card>, StrumError> {
+    hand.split(|separator: char| separator.is_whitespace() || separator == ',')
+        .filter(|card| !card.is_empty())
+        .map(Card::from_str)
+        .collect()
+}
+
+/// Order in which cards in hand are sorted.
+#[derive(Clone, Copy, Debug, Default, Deserialize, EnumDisplay, Eq, Hash, PartialEq, Serialize)]
+pub enum SortOrder {
+    /// Sort by descending order of [`Rank`] first and then by [`Suit`], as per
+    /// [`Sortable::sort_by_rank()`].
+    #[default]
+    Rank,
+    /// Sort by [`Suit`] first and then by descending order of [`Rank`], as per
+    /// [`Sortable::sort_by_suit()`].
+    Suit,
+}
+
+impl SortOrder {
+    /// Returns the other sort order.
+    #[must_use = "Toggled sort order must be used."]
+    #[inline]
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Rank => Self::Suit,
+            Self::Suit => Self::Rank,
+        }
+    }
+
+    /// Compares two cards as per the sort order.
+    #[must_use = "Card ordering must be used."]
+    #[inline]
+    pub fn compare(self, first: &Card, second: &Card) -> Ordering {
+        match self {
+            Self::Rank => {
+                (Reverse(first.rank), first.suit).cmp(&(Reverse(second.rank), second.suit))
+            }
+            Self::Suit => {
+                (first.suit, Reverse(first.rank)).cmp(&(second.suit, Reverse(second.rank)))
+            }
+        }
+    }
+}
+
 /// Trait that defines sorting methods for cards. This trait is implemented over
 /// a slice of cards and thus methods can be used over [`\[Card;N\]`],
 /// [`&\[Card\]`] and [`Vec<Card>`]
@@ -330,6 +440,8 @@ pub trait Sortable {
     /// In-place sorts the cards by descending order of [`Rank`] first and then
     /// by [`Suit`].
     fn sort_by_rank(&mut self);
+    /// In-place sorts the cards as per the given [`SortOrder`].
+    fn sort_by_order(&mut self, sort_order: SortOrder);
     /// Creates a new sorted [`Vec<Card>`] using the rules from
     /// [`Sortable::sort_by_suit()`].
     #[must_use = "Sorted cards must be used."]
@@ -361,6 +473,11 @@ impl Sortable for [Card] {
         self.sort_by_key(|card| (Reverse(card.rank), card.suit));
     }
 
+    #[inline]
+    fn sort_by_order(&mut self, sort_order: SortOrder) {
+        self.sort_by(|first, second| sort_order.compare(first, second));
+    }
+
     #[inline]
     fn sorted_by_suit(&self) -> Vec<Card> {
         let mut cards = self.to_vec();
@@ -377,7 +494,6 @@ impl Sortable for [Card] {
 
     #[expect(
         clippy::unwrap_used,
-        clippy::unwrap_in_result,
         reason = "Refactor: Cannot propagate error out of `HashMap::and_modify`"
     )]
     fn grouped_by_suit(&self) -> Result<Vec<(Suit, usize)>, ArithmeticError> {
@@ -403,7 +519,6 @@ impl Sortable for [Card] {
 
     #[expect(
         clippy::unwrap_used,
-        clippy::unwrap_in_result,
         reason = "Refactor: Cannot propagate error out of `HashMap::and_modify`"
     )]
     fn grouped_by_rank(&self) -> Result<Vec<(Rank, usize)>, ArithmeticError> {
@@ -429,9 +544,25 @@ impl Sortable for [Card] {
 }
 
 #[cfg(test)]
+#[expect(
+    clippy::non_ascii_literal,
+    reason = "Intended: Tests verify parsing of unicode suit symbols."
+)]
 mod tests {
+    use proptest::{collection::vec, prelude::*, sample::select};
+    use strum::IntoEnumIterator;
+
     use super::*;
 
+    /// Strategy generating any card of a standard deck.
+    fn any_card() -> impl Strategy<Value = Card> {
+        (
+            select(Rank::iter().collect::<Vec<_>>()),
+            select(Suit::iter().collect::<Vec<_>>()),
+        )
+            .prop_map(|(rank, suit)| Card { rank, suit })
+    }
+
     #[test]
     fn suit_from_unicode() {
         let parsed_suits = ["♣", "♦", "♥", "♠"].map(|suit| Suit::from_str(suit).unwrap());
@@ -695,4 +826,46 @@ mod tests {
 
         assert_eq!(unsorted_ranks, sorted_ranks);
     }
+
+    #[test]
+    fn card_display_is_rank_then_suit() {
+        let card = Card {
+            rank: Rank::Ace,
+            suit: Suit::Spade,
+        };
+
+        assert_eq!(card.to_string(), "A♠");
+        assert_eq!(format!("{card:#}"), "AS");
+    }
+
+    #[test]
+    fn parse_hand_rejects_invalid_cards() {
+        assert!(matches!(
+            parse_hand("AS 1X"),
+            Err(StrumError::FromStringError(_))
+        ));
+        assert!(matches!(
+            parse_hand("AS ♠"),
+            Err(StrumError::FromStringError(_))
+        ));
+        assert_eq!(parse_hand(" , ").unwrap(), vec![]);
+    }
+
+    proptest! {
+        #[test]
+        fn card_notation_round_trips(card in any_card()) {
+            prop_assert_eq!(card.to_string().parse::<Card>().unwrap(), card);
+            prop_assert_eq!(format!("{card:#}").parse::<Card>().unwrap(), card);
+        }
+
+        #[test]
+        fn hand_notation_round_trips(hand in vec(any_card(), 0..10), ascii: bool, separator in select(vec![" ", ", ", ",", "\t"])) {
+            let notation = hand
+                .iter()
+                .map(|card| if ascii { format!("{card:#}") } else { card.to_string() })
+                .join(separator);
+
+            prop_assert_eq!(parse_hand(&notation).unwrap(), hand);
+        }
+    }
 }
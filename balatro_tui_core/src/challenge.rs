@@ -0,0 +1,114 @@
+//! This module provides [`Challenge`], a predefined rule set for a run.
+//!
+//! Challenges are plain data, so that they can be defined in data files. A
+//! challenge can override the [`RunProperties`] of a standard run, replace the
+//! standard deck and apply special [`ChallengeRule`]s. A run of a challenge is
+//! created using [`super::engine::Engine::with_challenge()`], which carries the
+//! rules in the [`RunProperties`] so that the engine enforces them.
+//!
+//! Jokers, consumables and the shop are not implemented yet, so challenges
+//! cannot define them. Money is not earned during a run yet either, so
+//! [`ChallengeRule::MoneyCap`] only caps the starting money of the run.
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    deck::{Deck, DeckConstExt, DeckDefinition},
+    engine::{
+        STANDARD_HAND_SIZE, STANDARD_MAX_DISCARDS, STANDARD_MAX_HANDS, STANDARD_STARTING_MONEY,
+    },
+    run::RunProperties,
+};
+
+/// Special rule applied to the run of a [`Challenge`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum ChallengeRule {
+    /// Cards in hand are shown face down until they are played. The
+    /// [`super::engine::Snapshot`] of a running game hides them.
+    FaceDown,
+    /// Money held during the run never exceeds the contained amount.
+    MoneyCap(usize),
+}
+
+/// A predefined rule set for a run.
+///
+/// Properties that are not set default to the ones of a standard run.
+///
+/// ```
+/// # use balatro_tui_core::challenge::{Challenge, ChallengeRule};
+/// let challenge: Challenge = serde_json::from_str(
+///     r#"{
+///         "id": "one_shot",
+///         "name": "One Shot",
+///         "description": "A single hand to beat the blind.",
+///         "max_hands": 1,
+///         "rules": ["FaceDown", {"MoneyCap": 5}]
+///     }"#,
+/// )
+/// .unwrap();
+/// let properties = challenge.properties("SEED".to_owned());
+///
+/// assert_eq!(properties.max_hands, 1);
+/// assert_eq!(properties.money_cap(), Some(5));
+/// assert!(challenge.has_rule(ChallengeRule::FaceDown));
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Challenge {
+    /// Unique identifier of the challenge, used to record its completion.
+    pub id: String,
+    /// Name of the challenge.
+    pub name: String,
+    /// Description of the challenge shown to the player.
+    pub description: String,
+    /// Starting deck of the run. The standard deck is used if not set.
+    #[serde(default)]
+    pub deck: Option<DeckDefinition>,
+    /// Overrides [`RunProperties::hand_size`].
+    #[serde(default)]
+    pub hand_size: Option<usize>,
+    /// Overrides [`RunProperties::max_hands`].
+    #[serde(default)]
+    pub max_hands: Option<usize>,
+    /// Overrides [`RunProperties::max_discards`].
+    #[serde(default)]
+    pub max_discards: Option<usize>,
+    /// Overrides [`RunProperties::starting_money`].
+    #[serde(default)]
+    pub starting_money: Option<usize>,
+    /// Special rules applied to the run.
+    #[serde(default)]
+    pub rules: Vec<ChallengeRule>,
+}
+
+impl Challenge {
+    /// Returns the properties of a run of the challenge seeded with the given
+    /// seed.
+    #[must_use = "Created run properties must be used."]
+    #[inline]
+    pub fn properties(&self, seed: String) -> RunProperties {
+        RunProperties {
+            hand_size: self.hand_size.unwrap_or(STANDARD_HAND_SIZE),
+            max_discards: self.max_discards.unwrap_or(STANDARD_MAX_DISCARDS),
+            max_hands: self.max_hands.unwrap_or(STANDARD_MAX_HANDS),
+            seed,
+            starting_money: self.starting_money.unwrap_or(STANDARD_STARTING_MONEY),
+            rules: self.rules.clone(),
+        }
+    }
+
+    /// Returns the starting deck of a run of the challenge.
+    #[must_use = "Created deck must be used."]
+    #[inline]
+    pub fn deck(&self) -> Deck {
+        self.deck
+            .as_ref()
+            .map_or_else(Deck::standard, DeckDefinition::build)
+    }
+
+    /// Returns `true` if the challenge applies the given rule.
+    #[must_use]
+    #[inline]
+    pub fn has_rule(&self, rule: ChallengeRule) -> bool {
+        self.rules.contains(&rule)
+    }
+}
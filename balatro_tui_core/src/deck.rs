@@ -3,20 +3,25 @@
 //! This module also provides deck management methods and card tracking for UI
 //! states. To utilize methods described on [`Deck`],
 //! [`DeckConstExt`] and [`DeckExt`] traits must be brought into scope.
+//!
+//! Custom decks can be described using [`DeckDefinition`], eg, when loaded
+//! from a deck file.
+
+use std::{iter::repeat_n, sync::LazyLock};
 
 use itertools::Itertools;
-use once_cell::sync::Lazy;
-use rand::{seq::SliceRandom, thread_rng};
+use rand::{seq::SliceRandom, Rng};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 use strum::IntoEnumIterator;
 
 use super::card::{Card, Rank, Suit};
-use crate::error::{ArithmeticError, CoreError};
+use crate::error::{ArithmeticError, CoreError, StrumError};
 
 /// Lazy initializer for default deck.
 ///
 /// More decks can be added using lazy initialization with use of
 /// [`super::card::SuitIter`] and [`super::card::RankIter`].
-pub static DEFAULT_DECK: Lazy<Deck> = Lazy::new(|| {
+pub static DEFAULT_DECK: LazyLock<Deck> = LazyLock::new(|| {
     Rank::iter()
         .cartesian_product(Suit::iter())
         .map(|(rank, suit)| Card { rank, suit })
@@ -40,12 +45,22 @@ pub trait DeckConstExt {
 
 /// Extension methods for [`Deck`], directly implemented on top of
 /// [`Vec<Card>`].
+///
+/// Randomized operations accept the [`Rng`] to draw from, so that a run seeded
+/// with [`super::rng::seeded_rng()`] stays deterministic.
 pub trait DeckExt {
-    /// In-place shuffle a deck based on thread/seed rng.
-    fn shuffle(&mut self);
+    /// In-place shuffle a deck using the provided rng.
+    fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R);
     /// Draw random cards from the deck and return new deck.
     #[must_use = "Drawn cards must be used."]
-    fn draw_random(&mut self, draw_size: usize) -> Result<Deck, CoreError>;
+    fn draw_random<R: Rng + ?Sized>(
+        &mut self,
+        draw_size: usize,
+        rng: &mut R,
+    ) -> Result<Deck, CoreError>;
+    /// Draw cards from the front of the deck in order and return new deck.
+    #[must_use = "Drawn cards must be used."]
+    fn draw_ordered(&mut self, draw_size: usize) -> Result<Deck, CoreError>;
 }
 
 impl DeckConstExt for Deck {
@@ -57,15 +72,19 @@ impl DeckConstExt for Deck {
 
 impl DeckExt for Deck {
     #[inline]
-    fn shuffle(&mut self) {
-        self.as_mut_slice().shuffle(&mut thread_rng());
+    fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.as_mut_slice().shuffle(rng);
     }
 
-    fn draw_random(&mut self, draw_size: usize) -> Result<Deck, CoreError> {
+    fn draw_random<R: Rng + ?Sized>(
+        &mut self,
+        draw_size: usize,
+        rng: &mut R,
+    ) -> Result<Deck, CoreError> {
         if draw_size > self.len() {
             return Err(CoreError::HandsExhaustedError);
         }
-        self.shuffle();
+        self.shuffle(rng);
 
         let drain_size = self
             .len()
@@ -75,4 +94,125 @@ impl DeckExt for Deck {
 
         Ok(drawn_cards)
     }
+
+    fn draw_ordered(&mut self, draw_size: usize) -> Result<Deck, CoreError> {
+        if draw_size > self.len() {
+            return Err(CoreError::HandsExhaustedError);
+        }
+
+        Ok(self.drain(..draw_size).collect())
+    }
+}
+
+/// Definition of a custom deck, listing its cards along with their counts.
+///
+/// Cards are written in card notation, see [`Card`]. An invalid card fails
+/// deserialization with the underlying [`crate::error::StrumError`] in the
+/// error message, so that deserializers can report where it occurred.
+///
+/// ```
+/// # use balatro_tui_core::deck::DeckDefinition;
+/// let definition: DeckDefinition = serde_json::from_str(
+///     r#"{"name": "Aces", "cards": [{"card": "AS", "count": 3}, {"card": "AH"}]}"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(definition.build().len(), 4);
+///
+/// let error =
+///     serde_json::from_str::<DeckDefinition>(r#"{"cards": [{"card": "AX"}]}"#).unwrap_err();
+///
+/// assert_eq!(error.line(), 1);
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct DeckDefinition {
+    /// Name of the deck.
+    #[serde(default)]
+    pub name: String,
+    /// Cards in the deck.
+    pub cards: Vec<DeckEntry>,
+}
+
+/// A card of a [`DeckDefinition`] along with the number of its copies.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct DeckEntry {
+    /// The card, in card notation.
+    #[serde(
+        deserialize_with = "deserialize_card_notation",
+        serialize_with = "serialize_card_notation"
+    )]
+    pub card: Card,
+    /// Number of copies of the card in the deck.
+    #[serde(default = "default_card_count")]
+    pub count: usize,
+}
+
+impl DeckDefinition {
+    /// Create a new [`Deck`] containing the defined cards.
+    #[must_use = "Created deck must be used."]
+    pub fn build(&self) -> Deck {
+        self.cards
+            .iter()
+            .flat_map(|entry| repeat_n(entry.card, entry.count))
+            .collect()
+    }
+}
+
+/// Default number of copies of a card in a [`DeckDefinition`].
+const fn default_card_count() -> usize {
+    1
+}
+
+/// Deserializes a [`Card`] from card notation.
+fn deserialize_card_notation<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Card, D::Error> {
+    let notation = String::deserialize(deserializer)?;
+    notation
+        .parse()
+        .map_err(|err: StrumError| D::Error::custom(format!("invalid card `{notation}`: {err}")))
+}
+
+/// Serializes a [`Card`] into ASCII card notation.
+#[expect(
+    clippy::trivially_copy_pass_by_ref,
+    reason = "Intended: Serde passes serialized fields by reference."
+)]
+fn serialize_card_notation<S: Serializer>(card: &Card, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{card:#}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn definition_round_trip() {
+        let definition = DeckDefinition {
+            name: "Tens".to_owned(),
+            cards: vec![DeckEntry {
+                card: "10H".parse().unwrap(),
+                count: 2,
+            }],
+        };
+
+        let serialized = serde_json::to_string(&definition).unwrap();
+
+        assert_eq!(
+            serialized,
+            r#"{"name":"Tens","cards":[{"card":"10H","count":2}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<DeckDefinition>(&serialized).unwrap(),
+            definition
+        );
+    }
+
+    #[test]
+    fn invalid_card_reports_location() {
+        let error =
+            serde_json::from_str::<DeckDefinition>("{\n\"cards\": [\n{\"card\": \"1X\"}\n]}")
+                .unwrap_err();
+
+        assert_eq!(error.line(), 3);
+        assert!(error.to_string().contains("invalid card `1X`"));
+    }
 }
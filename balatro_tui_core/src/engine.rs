@@ -0,0 +1,783 @@
+//! This module provides [`Engine`], a headless game engine that drives a
+//! [`Run`] using [`Action`] inputs.
+//!
+//! The engine owns all flow control of a game, ie, card selection, ordering of
+//! the hand, playing and discarding hands and deciding when the run is over. It
+//! has no dependency on any rendering or input interface, so that user
+//! interfaces, bots, tests and tooling can all be built on top of it.
+//!
+//! The engine is driven by querying [`Engine::legal_actions()`] and feeding one
+//! of them to [`Engine::apply()`]. The state of the game can be observed using
+//! [`Engine::snapshot()`].
+//!
+//! ```
+//! # use balatro_tui_core::{action::Action, engine::{Engine, Outcome}};
+//! let mut engine = Engine::new("ABCDEF".to_owned()).unwrap();
+//!
+//! engine.apply(Action::Select(0)).unwrap();
+//! let outcome = engine.apply(Action::Play).unwrap();
+//!
+//! assert!(matches!(outcome, Outcome::HandPlayed(_)));
+//! assert_eq!(engine.snapshot().unwrap().hands_left, 2);
+//! ```
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroUsize,
+    sync::{Arc, RwLock},
+};
+
+use itertools::{Either, Itertools};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    action::Action,
+    blind::Blind,
+    card::{Card, SortOrder, Sortable},
+    challenge::{Challenge, ChallengeRule},
+    deck::{Deck, DeckConstExt, DEFAULT_DECK},
+    puzzle::Puzzle,
+    replay::Replay,
+    rng::seeded_rng,
+    round::{Round, RoundProperties},
+    run::{Run, RunProperties, RunState},
+    score::Score,
+    scorer::{Scorer, ScoringHand},
+    solver::{advise_discards, best_play, BestPlay, DiscardEstimate, Position},
+};
+use crate::error::CoreError;
+
+/// Maximum selectable cards to form a hand.
+///
+/// As per standard rules this is set to `5`.
+pub const MAXIMUM_SELECTABLE_CARDS: usize = 5;
+
+/// Number of cards in hand for a standard run.
+pub const STANDARD_HAND_SIZE: usize = 10;
+
+/// Number of hands per round for a standard run.
+pub const STANDARD_MAX_HANDS: usize = 3;
+
+/// Number of discards per round for a standard run.
+pub const STANDARD_MAX_DISCARDS: usize = 3;
+
+/// Money available at the start of a standard run.
+pub const STANDARD_STARTING_MONEY: usize = 10;
+
+/// Result of successfully applying an [`Action`] on the [`Engine`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Outcome {
+    /// The card selection changed.
+    SelectionChanged,
+    /// The selected cards were played, scoring the contained score.
+    HandPlayed(Score),
+    /// The selected cards were discarded and replaced with new cards.
+    HandDiscarded,
+    /// The cards in hand were reordered. The selection follows the cards.
+    HandReordered,
+    /// The action ended the run. The contained value is `true` if the run was
+    /// won.
+    RunFinished(bool),
+}
+
+/// Observable state of the game at a point in time.
+///
+/// Snapshots are plain data and can be serialized to be consumed outside of
+/// the engine.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Snapshot {
+    /// Seed of the run.
+    pub seed: String,
+    /// Operational state of the run.
+    pub run_state: RunState,
+    /// Current ante.
+    pub ante: NonZeroUsize,
+    /// Current round number.
+    pub round_number: NonZeroUsize,
+    /// Blind faced in the current round.
+    pub blind: Blind,
+    /// Score required to beat the current blind.
+    pub target_score: Score,
+    /// Score accumulated in the current round.
+    pub score: Score,
+    /// Money held by the user.
+    pub money: usize,
+    /// Hands left to be played in the current round.
+    pub hands_left: usize,
+    /// Discards left in the current round.
+    pub discards_left: usize,
+    /// Cards in hand, or [`None`] for cards that are face down, as per the
+    /// [`ChallengeRule::FaceDown`] rule.
+    pub hand: Vec<Option<Card>>,
+    /// Indices of selected cards in [`Snapshot::hand`], in ascending order.
+    pub selected: Vec<usize>,
+    /// Hand that would be scored if the selected cards were played. Hidden
+    /// while cards in hand are face down.
+    pub scoring_hand: Option<ScoringHand>,
+    /// Number of cards left to be drawn from the deck.
+    pub deck_remaining: usize,
+}
+
+/// Headless game engine that owns a [`Run`] and the card selection.
+///
+/// Every applied [`Action`] is recorded into a [`Replay`].
+#[derive(Clone, Debug)]
+pub struct Engine {
+    /// The run driven by the engine.
+    run: Run,
+    /// Indices of selected cards in hand.
+    selected: BTreeSet<usize>,
+    /// Recording of every applied [`Action`].
+    replay: Replay,
+}
+
+impl Engine {
+    /// Create an engine with a new standard run seeded with the given seed.
+    #[inline]
+    pub fn new(seed: String) -> Result<Self, CoreError> {
+        Self::with_deck(seed, Deck::standard())
+    }
+
+    /// Create an engine with a new standard run seeded with the given seed,
+    /// that is played with the given deck instead of the standard deck.
+    #[inline]
+    pub fn with_deck(seed: String, cards: Deck) -> Result<Self, CoreError> {
+        Self::with_properties(
+            RunProperties {
+                hand_size: STANDARD_HAND_SIZE,
+                max_discards: STANDARD_MAX_DISCARDS,
+                max_hands: STANDARD_MAX_HANDS,
+                seed,
+                starting_money: STANDARD_STARTING_MONEY,
+                rules: vec![],
+            },
+            cards,
+        )
+    }
+
+    /// Create an engine with a new run of the given [`Challenge`] seeded with
+    /// the given seed.
+    #[inline]
+    pub fn with_challenge(challenge: &Challenge, seed: String) -> Result<Self, CoreError> {
+        let mut engine = Self::with_properties(challenge.properties(seed), challenge.deck())?;
+        engine.replay.challenge = Some(challenge.id.clone());
+        Ok(engine)
+    }
+
+    /// Create an engine with a new run of the given [`Puzzle`].
+    ///
+    /// The run starts with the hand of the puzzle, draws cards in the draw
+    /// order of the puzzle and must reach the target score of the puzzle.
+    pub fn with_puzzle(puzzle: &Puzzle) -> Result<Self, CoreError> {
+        if puzzle.hand.is_empty() {
+            return Err(CoreError::InvalidPuzzleError("no cards in hand"));
+        }
+        if puzzle.hands == 0 {
+            return Err(CoreError::InvalidPuzzleError("no hands to play"));
+        }
+
+        let mut run = Self::new_run(
+            RunProperties {
+                hand_size: puzzle.hand.len(),
+                max_discards: puzzle.discards,
+                max_hands: puzzle.hands,
+                seed: format!("puzzle-{}", puzzle.name),
+                starting_money: 0,
+                rules: vec![],
+            },
+            puzzle.draw_order.clone(),
+        );
+        run.round.target_score = Some(Score::from(puzzle.target_score));
+        run.round.ordered_draw = true;
+        *run.round.hand.try_write()? = puzzle.hand.sorted_by_rank();
+
+        let mut replay = Replay::new(run.properties.seed.clone());
+        replay.puzzle = Some(puzzle.clone());
+
+        Ok(Self::resume(run, replay))
+    }
+
+    /// Create an engine with a new run using the given properties and deck,
+    /// and start the run.
+    pub fn with_properties(properties: RunProperties, cards: Deck) -> Result<Self, CoreError> {
+        let mut replay = Replay::new(properties.seed.clone());
+        if cards != *DEFAULT_DECK {
+            replay.deck = Some(cards.clone());
+        }
+        let mut run = Self::new_run(properties, cards);
+
+        run.start()?;
+
+        Ok(Self::resume(run, replay))
+    }
+
+    /// Creates a new run with the given properties and deck, which is yet to be
+    /// started.
+    fn new_run(properties: RunProperties, cards: Deck) -> Run {
+        let deck = Arc::new(RwLock::new(cards));
+        let rng = Arc::new(RwLock::new(seeded_rng(&properties.seed)));
+        Run {
+            deck: Arc::clone(&deck),
+            rng: Arc::clone(&rng),
+            run_state: RunState::Running,
+            money: properties.initial_money(),
+            round: Round {
+                blind: Blind::Small,
+                deck,
+                rng,
+                discards_count: properties.max_discards,
+                hand: Arc::new(RwLock::new(vec![])),
+                hands_count: properties.max_hands,
+                history: vec![],
+                properties: RoundProperties {
+                    hand_size: properties.hand_size,
+                    ante: NonZeroUsize::MIN,
+                    round_number: NonZeroUsize::MIN,
+                },
+                score: Score::zero(),
+                target_score: None,
+                ordered_draw: false,
+                sort_order: SortOrder::default(),
+            },
+            properties,
+            upcoming_round_number: NonZeroUsize::MIN,
+            hands_played: BTreeMap::new(),
+        }
+    }
+
+    /// Create an engine that continues an already started [`Run`], eg, one
+    /// loaded from a save, along with the [`Replay`] recorded so far.
+    #[must_use = "Created engine must be used."]
+    #[inline]
+    pub const fn resume(run: Run, replay: Replay) -> Self {
+        Self {
+            run,
+            selected: BTreeSet::new(),
+            replay,
+        }
+    }
+
+    /// Returns the run driven by the engine.
+    #[must_use]
+    #[inline]
+    pub const fn run(&self) -> &Run {
+        &self.run
+    }
+
+    /// Returns the [`Replay`] of all actions applied so far.
+    #[must_use]
+    #[inline]
+    pub const fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    /// Returns the indices of selected cards in hand.
+    #[must_use]
+    #[inline]
+    pub const fn selected(&self) -> &BTreeSet<usize> {
+        &self.selected
+    }
+
+    /// Returns the selected cards in hand.
+    pub fn selected_cards(&self) -> Result<Vec<Card>, CoreError> {
+        let hand = self.run.round.hand.try_read()?;
+        Ok(self
+            .selected
+            .iter()
+            .filter_map(|&idx| hand.get(idx).copied())
+            .collect())
+    }
+
+    /// Returns the cards left to be drawn from the deck.
+    pub fn remaining_cards(&self) -> Result<Vec<Card>, CoreError> {
+        Ok(self.run.deck.try_read()?.clone())
+    }
+
+    /// Returns the cards that the player has not seen, ie, the cards left to be
+    /// drawn from the deck along with the cards in hand while they are face
+    /// down.
+    pub fn unseen_cards(&self) -> Result<Vec<Card>, CoreError> {
+        let mut cards = self.remaining_cards()?;
+        if self.is_face_down() {
+            cards.extend(self.run.round.hand.try_read()?.iter().copied());
+        }
+        Ok(cards)
+    }
+
+    /// Returns `true` if cards in hand are face down, as per the
+    /// [`ChallengeRule::FaceDown`] rule. Cards are revealed once the run is
+    /// over.
+    #[must_use]
+    pub fn is_face_down(&self) -> bool {
+        self.run.run_state == RunState::Running
+            && self.run.properties.has_rule(ChallengeRule::FaceDown)
+    }
+
+    /// Returns the highest scoring play available in hand. See
+    /// [`best_play()`].
+    pub fn best_play(&self) -> Result<Option<BestPlay>, CoreError> {
+        Ok(best_play(&self.run.round.hand.try_read()?)?)
+    }
+
+    /// Returns the estimated probability of beating the blind after every
+    /// candidate discard, using the given number of trials. See
+    /// [`advise_discards()`].
+    pub fn advise_discards<R: Rng>(
+        &self,
+        trials: usize,
+        rng: &mut R,
+    ) -> Result<Vec<DiscardEstimate>, CoreError> {
+        let round = &self.run.round;
+        let target_score = round.get_target_score()?;
+        let hand = round.hand.try_read()?;
+        let deck = self.run.deck.try_read()?;
+
+        Ok(advise_discards(
+            Position {
+                hand: &hand,
+                deck: &deck,
+                hands_left: round.hands_count,
+                discards_left: round.discards_count,
+                score: &round.score,
+                target_score: &target_score,
+            },
+            trials,
+            rng,
+        )?)
+    }
+
+    /// Returns `true` if the action can be applied in the current state.
+    pub fn is_legal(&self, action: Action) -> Result<bool, CoreError> {
+        if self.run.run_state != RunState::Running {
+            return Ok(false);
+        }
+
+        Ok(match action {
+            Action::Select(idx) => {
+                idx < self.run.round.hand.try_read()?.len()
+                    && !self.selected.contains(&idx)
+                    && self.selected.len() < MAXIMUM_SELECTABLE_CARDS
+            }
+            Action::Deselect(idx) => self.selected.contains(&idx),
+            Action::Play => self.run.round.hands_count != 0 && !self.selected.is_empty(),
+            Action::Discard => self.run.round.discards_count != 0 && !self.selected.is_empty(),
+            Action::Sort(_) => true,
+            Action::MoveLeft(idx) => idx != 0 && idx < self.run.round.hand.try_read()?.len(),
+            Action::MoveRight(idx) => idx.saturating_add(1) < self.run.round.hand.try_read()?.len(),
+        })
+    }
+
+    /// Returns all actions that can be applied in the current state.
+    ///
+    /// Actions reordering the hand, ie, [`Action::Sort`], [`Action::MoveLeft`]
+    /// and [`Action::MoveRight`], are not listed as they do not change what can
+    /// be played.
+    pub fn legal_actions(&self) -> Result<Vec<Action>, CoreError> {
+        let hand_size = self.run.round.hand.try_read()?.len();
+
+        (0..hand_size)
+            .flat_map(|idx| [Action::Select(idx), Action::Deselect(idx)])
+            .chain([Action::Play, Action::Discard])
+            .filter_map(|action| match self.is_legal(action) {
+                Ok(true) => Some(Ok(action)),
+                Ok(false) => None,
+                Err(err) => Some(Err(err)),
+            })
+            .collect()
+    }
+
+    /// Applies an action and returns its [`Outcome`].
+    ///
+    /// Returns [`CoreError::IllegalActionError`] if the action is not legal in
+    /// the current state. See [`Engine::legal_actions()`].
+    pub fn apply(&mut self, action: Action) -> Result<Outcome, CoreError> {
+        if !self.is_legal(action)? {
+            return Err(CoreError::IllegalActionError(action));
+        }
+
+        let outcome = match action {
+            Action::Select(idx) => {
+                _ = self.selected.insert(idx);
+                Outcome::SelectionChanged
+            }
+            Action::Deselect(idx) => {
+                _ = self.selected.remove(&idx);
+                Outcome::SelectionChanged
+            }
+            Action::Play => {
+                let mut played_cards = self.take_selected()?;
+                let scoring_hand = Scorer::get_scoring_hand(&played_cards)?.0;
+                let last_score = self.run.round.score.clone();
+                self.run.round.play_hand(&mut played_cards)?;
+                if let Some(played_hand) = scoring_hand {
+                    self.run.record_hand_played(played_hand);
+                }
+                Outcome::HandPlayed(self.run.round.score.saturating_sub(&last_score))
+            }
+            Action::Discard => {
+                let mut discarded_cards = self.take_selected()?;
+                self.run.round.discard_hand(&mut discarded_cards)?;
+                Outcome::HandDiscarded
+            }
+            Action::Sort(sort_order) => {
+                let previous_indices = self.run.round.sort_hand(sort_order)?;
+                self.selected = previous_indices
+                    .iter()
+                    .positions(|idx| self.selected.contains(idx))
+                    .collect();
+                Outcome::HandReordered
+            }
+            Action::MoveLeft(idx) => {
+                self.swap_cards(idx, idx.saturating_sub(1))?;
+                Outcome::HandReordered
+            }
+            Action::MoveRight(idx) => {
+                self.swap_cards(idx, idx.saturating_add(1))?;
+                Outcome::HandReordered
+            }
+        };
+
+        self.replay.record(action);
+
+        self.update_run_state()?;
+
+        Ok(match self.run.run_state {
+            RunState::Running => outcome,
+            RunState::Finished(win) => Outcome::RunFinished(win),
+        })
+    }
+
+    /// Returns the observable state of the game. Cards in hand are hidden while
+    /// they are face down.
+    pub fn snapshot(&self) -> Result<Snapshot, CoreError> {
+        let round = &self.run.round;
+        let face_down = self.is_face_down();
+
+        Ok(Snapshot {
+            seed: self.run.properties.seed.clone(),
+            run_state: self.run.run_state,
+            ante: round.properties.ante,
+            round_number: round.properties.round_number,
+            blind: round.blind,
+            target_score: round.get_target_score()?,
+            score: round.score.clone(),
+            money: self.run.money,
+            hands_left: round.hands_count,
+            discards_left: round.discards_count,
+            hand: round
+                .hand
+                .try_read()?
+                .iter()
+                .map(|&card| (!face_down).then_some(card))
+                .collect(),
+            selected: self.selected.iter().copied().collect(),
+            scoring_hand: Scorer::get_scoring_hand(&self.selected_cards()?)?
+                .0
+                .filter(|_| !face_down),
+            deck_remaining: self.run.deck.try_read()?.len(),
+        })
+    }
+
+    /// Swaps the cards at the given indices in hand, along with their
+    /// selection.
+    fn swap_cards(&mut self, first: usize, second: usize) -> Result<(), CoreError> {
+        self.run.round.swap_cards(first, second)?;
+        if self.selected.contains(&first) != self.selected.contains(&second) {
+            for idx in [first, second] {
+                if !self.selected.remove(&idx) {
+                    _ = self.selected.insert(idx);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the selected cards from hand and returns them. Clears the
+    /// selection.
+    fn take_selected(&mut self) -> Result<Vec<Card>, CoreError> {
+        let mut hand = self.run.round.hand.try_write()?;
+        let (selected, leftover): (Vec<Card>, Vec<Card>) =
+            hand.iter().enumerate().partition_map(|(idx, &card)| {
+                if self.selected.contains(&idx) {
+                    Either::Left(card)
+                } else {
+                    Either::Right(card)
+                }
+            });
+        *hand = leftover;
+        drop(hand);
+        self.selected.clear();
+
+        Ok(selected)
+    }
+
+    /// Finishes the run if the blind is beaten or if no hands or cards are
+    /// left to play.
+    fn update_run_state(&mut self) -> Result<(), CoreError> {
+        let round = &self.run.round;
+        let target_score = round.get_target_score()?;
+
+        if round.score >= target_score {
+            self.run.run_state = RunState::Finished(true);
+        } else if round.hands_count == 0 || round.hand.try_read()?.is_empty() {
+            self.run.run_state = RunState::Finished(false);
+        } else {
+            // Round continues
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::parse_hand;
+
+    #[test]
+    fn legal_actions_follow_selection() {
+        let mut engine = Engine::new("SEED".to_owned()).unwrap();
+
+        assert_eq!(
+            engine.legal_actions().unwrap(),
+            (0..STANDARD_HAND_SIZE)
+                .map(Action::Select)
+                .collect::<Vec<_>>()
+        );
+
+        for idx in 0..MAXIMUM_SELECTABLE_CARDS {
+            assert_eq!(
+                engine.apply(Action::Select(idx)).unwrap(),
+                Outcome::SelectionChanged
+            );
+        }
+
+        let legal_actions = engine.legal_actions().unwrap();
+
+        assert!(
+            !legal_actions.contains(&Action::Select(MAXIMUM_SELECTABLE_CARDS)),
+            "Selection must be limited"
+        );
+        assert!(legal_actions.contains(&Action::Deselect(0)));
+        assert!(legal_actions.contains(&Action::Play));
+        assert!(legal_actions.contains(&Action::Discard));
+    }
+
+    #[test]
+    fn illegal_action_is_rejected() {
+        let mut engine = Engine::new("SEED".to_owned()).unwrap();
+
+        assert!(matches!(
+            engine.apply(Action::Play),
+            Err(CoreError::IllegalActionError(Action::Play))
+        ));
+        assert!(matches!(
+            engine.apply(Action::Select(STANDARD_HAND_SIZE)),
+            Err(CoreError::IllegalActionError(_))
+        ));
+        assert!(engine.replay().actions.is_empty());
+    }
+
+    #[test]
+    fn play_and_discard_update_snapshot() {
+        let mut engine = Engine::new("SEED".to_owned()).unwrap();
+        let initial = engine.snapshot().unwrap();
+
+        assert_eq!(
+            engine.apply(Action::Select(3)).unwrap(),
+            Outcome::SelectionChanged
+        );
+        assert_eq!(
+            engine.apply(Action::Select(1)).unwrap(),
+            Outcome::SelectionChanged
+        );
+        assert_eq!(engine.snapshot().unwrap().selected, vec![1, 3]);
+
+        assert_eq!(
+            engine.apply(Action::Discard).unwrap(),
+            Outcome::HandDiscarded
+        );
+        let discarded = engine.snapshot().unwrap();
+        assert_eq!(discarded.discards_left, STANDARD_MAX_DISCARDS - 1);
+        assert_eq!(discarded.hand.len(), STANDARD_HAND_SIZE);
+        assert_eq!(discarded.deck_remaining, initial.deck_remaining - 2);
+        assert!(discarded.selected.is_empty());
+
+        assert_eq!(
+            engine.apply(Action::Select(0)).unwrap(),
+            Outcome::SelectionChanged
+        );
+        let Outcome::HandPlayed(score) = engine.apply(Action::Play).unwrap() else {
+            panic!("Playing a single card must not finish the run");
+        };
+        let played = engine.snapshot().unwrap();
+        assert_eq!(played.hands_left, STANDARD_MAX_HANDS - 1);
+        assert_eq!(played.score, score);
+    }
+
+    #[test]
+    fn run_finishes_when_hands_exhausted() {
+        let mut engine = Engine::new("SEED".to_owned()).unwrap();
+
+        let mut outcome = Outcome::SelectionChanged;
+        for _ in 0..STANDARD_MAX_HANDS {
+            assert_eq!(
+                engine
+                    .apply(Action::Select(STANDARD_HAND_SIZE - 1))
+                    .unwrap(),
+                Outcome::SelectionChanged
+            );
+            outcome = engine.apply(Action::Play).unwrap();
+        }
+
+        assert_eq!(outcome, Outcome::RunFinished(false));
+        assert!(engine.legal_actions().unwrap().is_empty());
+    }
+
+    #[test]
+    fn run_finishes_when_custom_deck_runs_out() {
+        let deck = parse_hand("2S 3S 4S 5S 7S 2H 3H 4H 5H 7H").unwrap();
+        let mut engine = Engine::with_deck("SEED".to_owned(), deck.clone()).unwrap();
+
+        assert_eq!(engine.replay().deck, Some(deck));
+
+        let mut outcome = Outcome::SelectionChanged;
+        for _ in 0..2_usize {
+            for idx in 0..MAXIMUM_SELECTABLE_CARDS {
+                assert_eq!(
+                    engine.apply(Action::Select(idx)).unwrap(),
+                    Outcome::SelectionChanged
+                );
+            }
+            outcome = engine.apply(Action::Play).unwrap();
+        }
+
+        assert_eq!(outcome, Outcome::RunFinished(false));
+        assert_eq!(engine.snapshot().unwrap().hands_left, 1);
+    }
+
+    #[test]
+    fn challenge_overrides_run() {
+        let challenge = Challenge {
+            id: "short".to_owned(),
+            hand_size: Some(5),
+            max_hands: Some(1),
+            ..Challenge::default()
+        };
+        let engine = Engine::with_challenge(&challenge, "SEED".to_owned()).unwrap();
+        let snapshot = engine.snapshot().unwrap();
+
+        assert_eq!(snapshot.hand.len(), 5);
+        assert_eq!(snapshot.hands_left, 1);
+        assert_eq!(snapshot.discards_left, STANDARD_MAX_DISCARDS);
+        assert_eq!(engine.replay().challenge.as_deref(), Some("short"));
+        assert_eq!(engine.replay().deck, None);
+    }
+
+    #[test]
+    fn face_down_challenge_hides_hand_until_run_is_over() {
+        let challenge = Challenge {
+            id: "blind".to_owned(),
+            max_hands: Some(1),
+            rules: vec![ChallengeRule::FaceDown, ChallengeRule::MoneyCap(4)],
+            ..Challenge::default()
+        };
+        let mut engine = Engine::with_challenge(&challenge, "SEED".to_owned()).unwrap();
+        drop(engine.apply(Action::Select(0)).unwrap());
+        let hand = engine.run().round.hand.try_read().unwrap().clone();
+        let snapshot = engine.snapshot().unwrap();
+
+        assert!(engine.is_face_down());
+        assert_eq!(snapshot.hand, vec![None; STANDARD_HAND_SIZE]);
+        assert_eq!(snapshot.selected, vec![0]);
+        assert_eq!(snapshot.scoring_hand, None);
+        assert_eq!(snapshot.money, 4);
+        assert_eq!(
+            engine.unseen_cards().unwrap().len(),
+            snapshot.deck_remaining + STANDARD_HAND_SIZE
+        );
+
+        assert!(matches!(
+            engine.apply(Action::Play).unwrap(),
+            Outcome::RunFinished(_)
+        ));
+        let finished = engine.snapshot().unwrap();
+
+        assert!(!engine.is_face_down());
+        assert!(finished.hand.iter().all(Option::is_some));
+        assert_eq!(finished.hand.first(), Some(&hand.get(1).copied()));
+        assert_eq!(
+            engine.unseen_cards().unwrap().len(),
+            finished.deck_remaining
+        );
+    }
+
+    #[test]
+    fn reordering_keeps_selection_and_sort_order() {
+        let mut engine = Engine::new("SEED".to_owned()).unwrap();
+        let selected_cards =
+            |current_engine: &Engine| current_engine.selected_cards().unwrap().sorted_by_rank();
+        let is_sorted_by_suit = |current_engine: &Engine| {
+            current_engine
+                .run()
+                .round
+                .hand
+                .try_read()
+                .unwrap()
+                .is_sorted_by(|first, second| SortOrder::Suit.compare(first, second).is_le())
+        };
+
+        drop(engine.apply(Action::Select(0)).unwrap());
+        drop(engine.apply(Action::Select(3)).unwrap());
+        let cards = selected_cards(&engine);
+
+        assert_eq!(
+            engine.apply(Action::Sort(SortOrder::Suit)).unwrap(),
+            Outcome::HandReordered
+        );
+        assert_eq!(selected_cards(&engine), cards);
+        assert!(is_sorted_by_suit(&engine));
+
+        assert!(!engine.is_legal(Action::MoveLeft(0)).unwrap());
+        assert!(!engine
+            .is_legal(Action::MoveRight(STANDARD_HAND_SIZE - 1))
+            .unwrap());
+        let moved_card = engine.snapshot().unwrap().hand.first().copied();
+        assert_eq!(
+            engine.apply(Action::MoveRight(0)).unwrap(),
+            Outcome::HandReordered
+        );
+        assert_eq!(engine.snapshot().unwrap().hand.get(1).copied(), moved_card);
+        assert_eq!(selected_cards(&engine), cards);
+
+        drop(engine.apply(Action::Discard).unwrap());
+
+        assert!(
+            is_sorted_by_suit(&engine),
+            "Sort order must persist across deals"
+        );
+    }
+
+    #[test]
+    fn same_seed_and_actions_are_deterministic() {
+        let actions = [
+            Action::Select(0),
+            Action::Select(4),
+            Action::Discard,
+            Action::Select(2),
+            Action::Select(5),
+            Action::Play,
+        ];
+        let mut first = Engine::new("SEED".to_owned()).unwrap();
+        let mut second = Engine::new("SEED".to_owned()).unwrap();
+
+        for action in actions {
+            assert_eq!(first.apply(action).unwrap(), second.apply(action).unwrap());
+        }
+
+        assert_eq!(first.snapshot().unwrap(), second.snapshot().unwrap());
+        assert_eq!(first.replay().actions, actions);
+    }
+}
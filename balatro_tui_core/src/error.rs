@@ -2,12 +2,14 @@
 
 use std::{
     num::ParseIntError,
-    sync::{RwLockWriteGuard, TryLockError},
+    sync::{RwLockReadGuard, RwLockWriteGuard, TryLockError},
 };
 
 use strum::ParseError;
 use thiserror::Error;
 
+use crate::action::Action;
+
 /// Defines errors relating to arithmetic operation failures.
 #[derive(Clone, Copy, Debug, Error)]
 pub enum ArithmeticError {
@@ -84,6 +86,36 @@ pub enum CoreError {
     #[error("Attempted to play hand but no hands remaining")]
     HandsExhaustedError,
 
+    /// Signifies that a card was accessed at an index outside of the hand.
+    /// Error message provides the attempted index.
+    #[error("Card index {0} is out of bounds of the hand")]
+    CardIndexError(usize),
+
+    /// Signifies that a [`crate::puzzle::Puzzle`] cannot be played, eg, as it
+    /// has no cards in hand.
+    #[error("Puzzle cannot be played: {0}")]
+    InvalidPuzzleError(&'static str),
+
+    /// Signifies that a [`crate::profile::Profile`] was written in a newer
+    /// format than the one supported, and cannot be migrated.
+    #[error("Profile version {0} is newer than the supported version")]
+    UnsupportedProfileVersionError(u32),
+
+    /// Signifies that an [`Action`] was applied when it was not legal in the
+    /// current state of the game.
+    #[error("Attempted an action that is not legal in the current state: {0:?}")]
+    IllegalActionError(Action),
+
+    /// Signifies that a [`crate::strategy::Strategy`] could not decide a move,
+    /// eg, as the external program backing it has exited.
+    #[error("Strategy failed to decide a move: {0}")]
+    StrategyError(String),
+
+    /// Signifies inability to acquire read lock on shared state, eg, `deck` or
+    /// `hand`.
+    #[error("Could not acquire read lock on shared state: {0:?}")]
+    ReadLockError(String),
+
     /// Provides conversion from [`ArithmeticError`] to [`ScorerError`].
     #[error("Arithmetic error occurred in core")]
     ArithmeticError(#[from] ArithmeticError),
@@ -103,3 +135,10 @@ impl<'guard, T> From<TryLockError<RwLockWriteGuard<'guard, T>>> for CoreError {
         Self::DeckLockError(format!("{source:?}"))
     }
 }
+
+impl<'guard, T> From<TryLockError<RwLockReadGuard<'guard, T>>> for CoreError {
+    #[inline]
+    fn from(source: TryLockError<RwLockReadGuard<'guard, T>>) -> Self {
+        Self::ReadLockError(format!("{source:?}"))
+    }
+}
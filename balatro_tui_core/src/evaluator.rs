@@ -0,0 +1,533 @@
+//! This module provides [`HandEvaluation`], an allocation-free evaluator for
+//! played cards used by [`super::scorer::Scorer`].
+//!
+//! Played cards are reduced to a [`Rank`] presence bitmask along with per
+//! [`Rank`] and per [`Suit`] counts, all held on the stack. Straights are
+//! looked up from a table precomputed at compile time from the rank bitmask,
+//! and the chips and multipliers of [`ScoringHand`] and [`Rank`] are read once
+//! from their properties into lookup tables.
+//!
+//! ```
+//! # use balatro_tui_core::{card::{Card, Rank}, evaluator::HandEvaluation, score::Score, scorer::ScoringHand};
+//! let cards = ["KH", "KS", "4D", "4C", "9H"]
+//!     .iter()
+//!     .map(|card| card.parse::<Card>())
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//!
+//! let evaluation = HandEvaluation::evaluate(&cards);
+//!
+//! assert_eq!(evaluation.scoring_hand, Some(ScoringHand::TwoPair));
+//! assert_eq!(evaluation.scored_ranks().collect::<Vec<_>>(), vec![
+//!     Rank::King,
+//!     Rank::King,
+//!     Rank::Four,
+//!     Rank::Four
+//! ]);
+//! assert_eq!(
+//!     evaluation.score().unwrap(),
+//!     Score::from((20 + 10 + 10 + 4 + 4) * 2_usize)
+//! );
+//! ```
+
+use std::{iter::repeat_n, sync::LazyLock};
+
+use strum::{EnumCount, IntoEnumIterator};
+
+use super::{
+    card::{Card, Rank, Suit},
+    score::Score,
+    scorer::{Scorer, ScoringHand},
+};
+use crate::error::{ScorerError, StrumError};
+
+/// Bit masks for scoring a straight.
+///
+/// 0th mask represents a high ace straight, ie, A-K-Q-J-10
+/// 1st mask represents a low ace straight, ie, A-2-3-4-5
+///
+/// <div class="warning">Straight scoring operation relies on consistency of
+/// this constant and thus must not be changed</div>
+const STRAIGHT_BIT_MASKS: [u16; 10] = [
+    0b0001_1110_0000_0001,
+    0b0000_0000_0001_1111,
+    0b0000_0000_0011_1110,
+    0b0000_0000_0111_1100,
+    0b0000_0000_1111_1000,
+    0b0000_0001_1111_0000,
+    0b0000_0011_1110_0000,
+    0b0000_0111_1100_0000,
+    0b0000_1111_1000_0000,
+    0b0001_1111_0000_0000,
+];
+
+/// Kind of straight made by a set of ranks.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum StraightKind {
+    /// Ranks do not make a straight.
+    None,
+    /// Ranks make a straight that is not a high ace straight.
+    Straight,
+    /// Ranks make a high ace straight, ie, A-K-Q-J-10.
+    HighAce,
+}
+
+/// Kind of straight for every [`Rank`] presence bitmask.
+const STRAIGHT_TABLE: [StraightKind; 1 << Rank::COUNT] = straight_table();
+
+/// Builds [`STRAIGHT_TABLE`] from [`STRAIGHT_BIT_MASKS`].
+#[expect(
+    clippy::indexing_slicing,
+    reason = "Intended: Straight masks only span the ranks and always index within the table."
+)]
+const fn straight_table() -> [StraightKind; 1 << Rank::COUNT] {
+    let mut table = [StraightKind::None; 1 << Rank::COUNT];
+    let mut idx = 0;
+    while idx < STRAIGHT_BIT_MASKS.len() {
+        table[STRAIGHT_BIT_MASKS[idx] as usize] = if idx == 0 {
+            StraightKind::HighAce
+        } else {
+            StraightKind::Straight
+        };
+        idx = idx.saturating_add(1);
+    }
+    table
+}
+
+/// Base chips and multiplier of every [`ScoringHand`], indexed by ordinal.
+static HAND_VALUES: LazyLock<Result<[(usize, usize); ScoringHand::COUNT], StrumError>> =
+    LazyLock::new(|| {
+        let mut values = [(0, 0); ScoringHand::COUNT];
+        for (value, scoring_hand) in values.iter_mut().zip(ScoringHand::iter()) {
+            *value = Scorer::get_chips_and_multiplier(scoring_hand)?;
+        }
+        Ok(values)
+    });
+
+/// Chips scored by every [`Rank`], indexed by ordinal.
+static RANK_SCORES: LazyLock<Result<[usize; Rank::COUNT], StrumError>> = LazyLock::new(|| {
+    let mut scores = [0; Rank::COUNT];
+    for (score, rank) in scores.iter_mut().zip(Rank::iter()) {
+        *score = rank.get_score()?;
+    }
+    Ok(scores)
+});
+
+/// Groups of ranks scored by a [`ScoringHand`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum ScoredGroups {
+    /// All played cards are scored.
+    All,
+    /// Only the cards of the rank with the most cards are scored.
+    First,
+    /// Only the cards of the two ranks with the most cards are scored.
+    FirstAndSecond,
+}
+
+/// Result of evaluating a set of played cards.
+///
+/// Evaluation never allocates and does not depend on the order of the cards.
+/// When ranks tie for a scoring group, eg, for [`ScoringHand::HighCard`], the
+/// highest [`Rank`] is scored, with [`Rank::Ace`] counted as a high ace.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HandEvaluation {
+    /// Scoring hand made by the cards, or [`None`] if no cards were played.
+    pub scoring_hand: Option<ScoringHand>,
+    /// Number of scored cards of every [`Rank`], indexed by ordinal.
+    pub scored_counts: [u8; Rank::COUNT],
+}
+
+impl HandEvaluation {
+    /// Evaluates the played cards.
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "Intended: Count tables hold one entry for every rank and suit ordinal."
+    )]
+    pub fn evaluate(cards: &[Card]) -> Self {
+        let mut rank_counts = [0_u8; Rank::COUNT];
+        let mut suit_counts = [0_u8; Suit::COUNT];
+        let mut rank_mask = 0_u16;
+
+        for card in cards {
+            rank_counts[card.rank as usize] = rank_counts[card.rank as usize].saturating_add(1);
+            suit_counts[card.suit as usize] = suit_counts[card.suit as usize].saturating_add(1);
+            rank_mask |= 1 << (card.rank as usize);
+        }
+
+        let is_flush = suit_counts.iter().copied().max() == Some(5);
+        let straight = STRAIGHT_TABLE[usize::from(rank_mask)];
+        let (first, first_count) = top_rank_group(&rank_counts, None);
+        let (second, second_count) = top_rank_group(&rank_counts, Some(first));
+
+        let (scoring_hand, scored_groups) = if first_count == 0 {
+            return Self {
+                scoring_hand: None,
+                scored_counts: [0; Rank::COUNT],
+            };
+        } else if is_flush && first_count == 5 {
+            (ScoringHand::FlushFive, ScoredGroups::First)
+        } else if is_flush && first_count == 3 && second_count == 2 {
+            (ScoringHand::FlushHouse, ScoredGroups::FirstAndSecond)
+        } else if first_count == 5 {
+            (ScoringHand::FiveOfAKind, ScoredGroups::First)
+        } else if is_flush && straight == StraightKind::HighAce {
+            (ScoringHand::RoyalFlush, ScoredGroups::All)
+        } else if is_flush && straight == StraightKind::Straight {
+            (ScoringHand::StraightFlush, ScoredGroups::All)
+        } else if first_count == 4 {
+            (ScoringHand::FourOfAKind, ScoredGroups::First)
+        } else if first_count == 3 && second_count == 2 {
+            (ScoringHand::FullHouse, ScoredGroups::FirstAndSecond)
+        } else if is_flush {
+            (ScoringHand::Flush, ScoredGroups::All)
+        } else if straight != StraightKind::None {
+            (ScoringHand::Straight, ScoredGroups::All)
+        } else if first_count == 3 {
+            (ScoringHand::ThreeOfAKind, ScoredGroups::First)
+        } else if first_count == 2 && second_count == 2 {
+            (ScoringHand::TwoPair, ScoredGroups::FirstAndSecond)
+        } else if first_count == 2 {
+            (ScoringHand::Pair, ScoredGroups::First)
+        } else {
+            (ScoringHand::HighCard, ScoredGroups::First)
+        };
+
+        let mut scored_counts = [0_u8; Rank::COUNT];
+        match scored_groups {
+            ScoredGroups::All => scored_counts = rank_counts,
+            ScoredGroups::First => scored_counts[first] = first_count,
+            ScoredGroups::FirstAndSecond => {
+                scored_counts[first] = first_count;
+                scored_counts[second] = second_count;
+            }
+        }
+
+        Self {
+            scoring_hand: Some(scoring_hand),
+            scored_counts,
+        }
+    }
+
+    /// Returns the scored ranks in descending order of [`Rank`] ordinal.
+    pub fn scored_ranks(&self) -> impl Iterator<Item = Rank> + '_ {
+        Rank::iter()
+            .zip(self.scored_counts)
+            .rev()
+            .flat_map(|(rank, count)| repeat_n(rank, usize::from(count)))
+    }
+
+    /// Returns the score of the played cards, ie, the sum of base chips of
+    /// the scoring hand and chips of the scored ranks, times the multiplier of
+    /// the scoring hand.
+    ///
+    /// Returns [`ScorerError::EmptyHandScoredError`] if no cards were played.
+    pub fn score(&self) -> Result<Score, ScorerError> {
+        let (base_chips, multiplier) = self.base_chips_and_multiplier()?;
+        let rank_scores = RANK_SCORES.as_ref().map_err(Clone::clone)?;
+
+        let chips = rank_scores
+            .iter()
+            .zip(self.scored_counts)
+            .fold(Score::from(base_chips), |acc, (&score, count)| {
+                acc + Score::from(score) * usize::from(count)
+            });
+
+        Ok(chips * multiplier)
+    }
+
+    /// Returns the score of the played cards like [`Self::score()`], computed
+    /// with saturating [`usize`] arithmetic to avoid allocating.
+    ///
+    /// Base values of scoring hands and ranks are small enough for the score
+    /// of any playable hand to be exact, so this is only meant for ranking
+    /// plays against each other, eg, in [`crate::solver::best_play()`].
+    pub(crate) fn saturating_score(&self) -> Result<usize, ScorerError> {
+        let (base_chips, multiplier) = self.base_chips_and_multiplier()?;
+        let rank_scores = RANK_SCORES.as_ref().map_err(Clone::clone)?;
+
+        let chips = rank_scores
+            .iter()
+            .zip(self.scored_counts)
+            .fold(base_chips, |acc, (&score, count)| {
+                acc.saturating_add(score.saturating_mul(usize::from(count)))
+            });
+
+        Ok(chips.saturating_mul(multiplier))
+    }
+
+    /// Returns the base chips and multiplier of the scoring hand.
+    ///
+    /// Returns [`ScorerError::EmptyHandScoredError`] if no cards were played.
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "Intended: Lookup tables hold one entry for every scoring hand ordinal."
+    )]
+    fn base_chips_and_multiplier(&self) -> Result<(usize, usize), ScorerError> {
+        let scoring_hand = self.scoring_hand.ok_or(ScorerError::EmptyHandScoredError)?;
+        Ok(HAND_VALUES.as_ref().map_err(Clone::clone)?[scoring_hand as usize])
+    }
+}
+
+/// Returns the ordinal and count of the [`Rank`] with the most cards,
+/// optionally excluding a rank. Ties are broken in favour of the highest rank,
+/// with [`Rank::Ace`] counted as a high ace.
+fn top_rank_group(rank_counts: &[u8; Rank::COUNT], excluded: Option<usize>) -> (usize, u8) {
+    // Ace is at ordinal 0, so it is visited last among ties by iterating
+    // downwards and then given precedence explicitly.
+    let ace = Rank::Ace as usize;
+    let mut top = (ace, 0);
+
+    for (rank, &count) in rank_counts.iter().enumerate().rev() {
+        if Some(rank) == excluded {
+            continue;
+        }
+        if count > top.1 || (count == top.1 && count != 0 && rank == ace) {
+            top = (rank, count);
+        }
+    }
+
+    top
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::{collection::vec, prelude::*, sample::select};
+
+    use super::*;
+    use crate::deck::DEFAULT_DECK;
+
+    /// Scorer implementation preceding [`HandEvaluation`], kept to verify that
+    /// evaluation results are unchanged.
+    mod reference {
+        use super::super::*;
+        use crate::card::Sortable;
+
+        /// Tests for a straight in a slice of [`Card`].
+        fn test_straight(cards: &[Card]) -> Option<(Option<bool>, Vec<Rank>)> {
+            let ranks = cards.iter().map(|card| card.rank).collect::<Vec<_>>();
+            let rank_bit_mask = ranks
+                .iter()
+                .fold(0, |bit_mask, rank| bit_mask | (1 << (*rank as usize)));
+
+            let is_straight = STRAIGHT_BIT_MASKS
+                .iter()
+                .any(|matcher| matcher == &rank_bit_mask);
+
+            let high_ace = if rank_bit_mask == STRAIGHT_BIT_MASKS[0] {
+                Some(true)
+            } else if rank_bit_mask == STRAIGHT_BIT_MASKS[1] {
+                Some(false)
+            } else {
+                None
+            };
+
+            is_straight.then_some((high_ace, ranks))
+        }
+
+        /// Returns [`ScoringHand`] for played cards using the implementation
+        /// preceding [`HandEvaluation`].
+        pub(super) fn get_scoring_hand(
+            cards: &[Card],
+        ) -> Result<(Option<ScoringHand>, Vec<Rank>), ScorerError> {
+            let sorted_cards = cards.sorted_by_rank();
+            let suit_groups = sorted_cards.grouped_by_suit()?;
+            let rank_groups = sorted_cards.grouped_by_rank()?;
+            let straight_test_result = test_straight(&sorted_cards);
+
+            if suit_groups.is_empty() || rank_groups.is_empty() {
+                return Ok((None, vec![]));
+            }
+
+            if suit_groups[0].1 == 5 && rank_groups[0].1 == 5 {
+                return Ok((Some(ScoringHand::FlushFive), vec![
+                    rank_groups[0].0;
+                    rank_groups[0].1
+                ]));
+            }
+
+            if rank_groups.len() >= 2
+                && suit_groups[0].1 == 5
+                && rank_groups[0].1 == 3
+                && rank_groups[1].1 == 2
+            {
+                let mut played_ranks = vec![];
+                played_ranks.append(&mut vec![rank_groups[0].0; rank_groups[0].1]);
+                played_ranks.append(&mut vec![rank_groups[1].0; rank_groups[1].1]);
+                return Ok((Some(ScoringHand::FlushHouse), played_ranks));
+            }
+
+            if rank_groups[0].1 == 5 {
+                return Ok((Some(ScoringHand::FiveOfAKind), vec![
+                    rank_groups[0].0;
+                    rank_groups[0].1
+                ]));
+            }
+
+            if suit_groups[0].1 == 5 {
+                if let Some(result) = straight_test_result {
+                    if result.0.unwrap_or(false) {
+                        return Ok((Some(ScoringHand::RoyalFlush), result.1));
+                    }
+
+                    return Ok((Some(ScoringHand::StraightFlush), result.1));
+                }
+            }
+
+            if rank_groups[0].1 == 4 {
+                return Ok((Some(ScoringHand::FourOfAKind), vec![
+                    rank_groups[0].0;
+                    rank_groups[0].1
+                ]));
+            }
+
+            if rank_groups.len() >= 2 && rank_groups[0].1 == 3 && rank_groups[1].1 == 2 {
+                let mut played_ranks = vec![];
+                played_ranks.append(&mut vec![rank_groups[0].0; rank_groups[0].1]);
+                played_ranks.append(&mut vec![rank_groups[1].0; rank_groups[1].1]);
+                return Ok((Some(ScoringHand::FullHouse), played_ranks));
+            }
+
+            if suit_groups[0].1 == 5 {
+                return Ok((
+                    Some(ScoringHand::Flush),
+                    cards.iter().map(|card| card.rank).collect(),
+                ));
+            }
+
+            if let Some(result) = straight_test_result {
+                return Ok((Some(ScoringHand::Straight), result.1));
+            }
+
+            if rank_groups[0].1 == 3 {
+                return Ok((Some(ScoringHand::ThreeOfAKind), vec![
+                    rank_groups[0].0;
+                    rank_groups[0].1
+                ]));
+            }
+
+            if rank_groups.len() >= 2 && rank_groups[0].1 == 2 && rank_groups[1].1 == 2 {
+                let mut played_ranks = vec![];
+                played_ranks.append(&mut vec![rank_groups[0].0; rank_groups[0].1]);
+                played_ranks.append(&mut vec![rank_groups[1].0; rank_groups[1].1]);
+                return Ok((Some(ScoringHand::TwoPair), played_ranks));
+            }
+
+            if rank_groups[0].1 == 2 {
+                return Ok((Some(ScoringHand::Pair), vec![
+                    rank_groups[0].0;
+                    rank_groups[0].1
+                ]));
+            }
+
+            Ok((Some(ScoringHand::HighCard), vec![
+                rank_groups[0].0;
+                rank_groups[0].1
+            ]))
+        }
+    }
+
+    /// Asserts that the evaluation of the cards matches the reference.
+    fn assert_matches_reference(cards: &[Card]) {
+        let evaluation = HandEvaluation::evaluate(cards);
+        let (scoring_hand, scored_ranks) = reference::get_scoring_hand(cards).unwrap();
+
+        assert_eq!(evaluation.scoring_hand, scoring_hand, "{cards:?}");
+        if scoring_hand == Some(ScoringHand::HighCard) {
+            // The reference scores an arbitrary single card due to hash map ordering.
+            assert_eq!(scored_ranks.len(), 1, "{cards:?}");
+            assert!(cards.iter().any(|card| card.rank == scored_ranks[0]));
+        } else {
+            assert_eq!(
+                evaluation.scored_ranks().sorted().collect::<Vec<_>>(),
+                scored_ranks.into_iter().sorted().collect::<Vec<_>>(),
+                "{cards:?}"
+            );
+        }
+    }
+
+    /// Strategy generating hands of four or five cards drawn from a few
+    /// consecutive ranks and suits, so that straights, flushes and repeated
+    /// ranks come up often.
+    fn any_large_hand() -> impl Strategy<Value = Vec<Card>> {
+        (0..Rank::COUNT, 1..=5_usize, 1..=Suit::COUNT).prop_flat_map(
+            |(first_rank, rank_count, suit_count)| {
+                let ranks = Rank::iter()
+                    .cycle()
+                    .skip(first_rank)
+                    .take(rank_count)
+                    .collect::<Vec<_>>();
+                let suits = Suit::iter().take(suit_count).collect::<Vec<_>>();
+                vec(
+                    (select(ranks), select(suits)).prop_map(|(rank, suit)| Card { rank, suit }),
+                    4..=5,
+                )
+            },
+        )
+    }
+
+    #[test]
+    fn evaluation_matches_reference_for_small_hands() {
+        for size in 1..=3 {
+            for cards in DEFAULT_DECK
+                .iter()
+                .copied()
+                .combinations_with_replacement(size)
+            {
+                assert_matches_reference(&cards);
+            }
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(4_096))]
+
+        #[test]
+        fn evaluation_matches_reference_for_large_hands(cards in any_large_hand()) {
+            assert_matches_reference(&cards);
+        }
+    }
+
+    #[test]
+    fn saturating_score_matches_score() {
+        for cards in DEFAULT_DECK
+            .iter()
+            .copied()
+            .combinations_with_replacement(3)
+        {
+            let evaluation = HandEvaluation::evaluate(&cards);
+
+            assert_eq!(
+                Score::from(evaluation.saturating_score().unwrap()),
+                evaluation.score().unwrap(),
+                "{cards:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn high_card_scores_highest_rank() {
+        let cards = ["2H", "AS", "KD"]
+            .iter()
+            .map(|card| card.parse::<Card>().unwrap())
+            .collect::<Vec<_>>();
+        let evaluation = HandEvaluation::evaluate(&cards);
+
+        assert_eq!(evaluation.scoring_hand, Some(ScoringHand::HighCard));
+        assert_eq!(evaluation.scored_ranks().collect::<Vec<_>>(), vec![
+            Rank::Ace
+        ]);
+    }
+
+    #[test]
+    fn empty_hand_is_not_scored() {
+        let evaluation = HandEvaluation::evaluate(&[]);
+
+        assert_eq!(evaluation.scoring_hand, None);
+        assert!(matches!(
+            evaluation.score(),
+            Err(ScorerError::EmptyHandScoredError)
+        ));
+    }
+}
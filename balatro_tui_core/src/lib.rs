@@ -1,10 +1,21 @@
 //! Core definitions and implementations for running a game of Balatro TUI
 
+pub mod action;
 pub mod blind;
 pub mod card;
+pub mod challenge;
 pub mod deck;
+pub mod engine;
 pub mod enum_property_ext;
 pub mod error;
+pub mod evaluator;
+pub mod profile;
+pub mod puzzle;
+pub mod replay;
+pub mod rng;
 pub mod round;
 pub mod run;
+pub mod score;
 pub mod scorer;
+pub mod solver;
+pub mod strategy;
@@ -0,0 +1,315 @@
+//! This module provides [`Profile`], the progress of a player persisted
+//! across runs, along with the lifetime [`ProfileStats`] of the player.
+//!
+//! Profiles are versioned. A profile written in an older format is upgraded
+//! with [`Profile::migrate()`] after being loaded, so that changes to the
+//! format keep the progress of the player.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroUsize,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{error::CoreError, score::Score, scorer::ScoringHand};
+
+/// Current version of the [`Profile`] format.
+///
+/// ## Versions
+/// - `0`: Profiles written before versioning, holding only completed
+///   challenges.
+/// - `1`: Adds lifetime [`ProfileStats`].
+pub const PROFILE_VERSION: u32 = 1;
+
+/// Progress of a player persisted across runs.
+///
+/// ```
+/// # use balatro_tui_core::profile::Profile;
+/// let mut profile = Profile::default();
+///
+/// assert!(profile.complete_challenge("one_shot"));
+/// assert!(!profile.complete_challenge("one_shot"));
+/// assert!(profile.has_completed("one_shot"));
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Profile {
+    /// Version of the format the profile was written in. Profiles written
+    /// before versioning have no version and are read as version `0`.
+    #[serde(default)]
+    pub version: u32,
+    /// Identifiers of the challenges completed by the player.
+    #[serde(default)]
+    pub completed_challenges: BTreeSet<String>,
+    /// Lifetime stats of the player.
+    #[serde(default)]
+    pub stats: ProfileStats,
+}
+
+impl Default for Profile {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            version: PROFILE_VERSION,
+            completed_challenges: BTreeSet::new(),
+            stats: ProfileStats::default(),
+        }
+    }
+}
+
+impl Profile {
+    /// Upgrades a loaded profile to the current [`PROFILE_VERSION`].
+    ///
+    /// ```
+    /// # use balatro_tui_core::profile::{Profile, PROFILE_VERSION};
+    /// let mut profile = Profile::default();
+    /// profile.version = 0;
+    ///
+    /// assert_eq!(profile.migrate().unwrap().version, PROFILE_VERSION);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::UnsupportedProfileVersionError`] if the profile was
+    /// written by a newer version of the game, so that it is not overwritten
+    /// with an older format.
+    #[inline]
+    pub fn migrate(mut self) -> Result<Self, CoreError> {
+        if self.version > PROFILE_VERSION {
+            return Err(CoreError::UnsupportedProfileVersionError(self.version));
+        }
+
+        // Version 0 holds completed challenges in the same shape as version 1,
+        // and lifetime stats start from zero as they were not tracked.
+        self.version = PROFILE_VERSION;
+
+        Ok(self)
+    }
+
+    /// Records the completion of a challenge. Returns `true` if the challenge
+    /// was not completed before.
+    #[inline]
+    pub fn complete_challenge(&mut self, id: &str) -> bool {
+        self.completed_challenges.insert(id.to_owned())
+    }
+
+    /// Returns `true` if the challenge has been completed.
+    #[must_use]
+    #[inline]
+    pub fn has_completed(&self, id: &str) -> bool {
+        self.completed_challenges.contains(id)
+    }
+}
+
+/// Number of runs played and won with a deck.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RunRecord {
+    /// Number of finished runs.
+    pub played: usize,
+    /// Number of won runs.
+    pub won: usize,
+}
+
+/// Lifetime stats of a player.
+///
+/// ```
+/// # use std::{num::NonZeroUsize, time::Duration};
+/// # use balatro_tui_core::{profile::ProfileStats, score::Score, scorer::ScoringHand};
+/// let mut stats = ProfileStats::default();
+/// stats.record_hand(ScoringHand::Pair, 2, &Score::from(40_usize));
+/// stats.record_discard(3);
+/// stats.record_run(
+///     "Standard",
+///     true,
+///     NonZeroUsize::MIN,
+///     4,
+///     Some(Duration::from_secs(90)),
+/// );
+///
+/// assert_eq!(stats.most_played_hand(), Some((ScoringHand::Pair, 1)));
+/// assert_eq!(stats.runs_won(), 1);
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct ProfileStats {
+    /// Finished runs by name of the deck played.
+    pub runs: BTreeMap<String, RunRecord>,
+    /// Highest score scored by a single hand.
+    pub best_hand_score: Score,
+    /// Highest ante reached in a finished run. Zero if no run was finished.
+    pub highest_ante: usize,
+    /// Number of times each hand was played.
+    pub hands_played: BTreeMap<ScoringHand, usize>,
+    /// Number of cards played.
+    pub cards_played: usize,
+    /// Number of cards discarded.
+    pub cards_discarded: usize,
+    /// Money gained over finished runs, ie, money held at the end of a run
+    /// beyond the money it started with.
+    pub money_earned: usize,
+    /// Shortest time taken to win a run.
+    pub fastest_win: Option<Duration>,
+}
+
+impl ProfileStats {
+    /// Records a played hand of the given number of cards.
+    #[inline]
+    pub fn record_hand(&mut self, scoring_hand: ScoringHand, cards: usize, score: &Score) {
+        let count = self.hands_played.entry(scoring_hand).or_default();
+        *count = count.saturating_add(1);
+        self.cards_played = self.cards_played.saturating_add(cards);
+        if *score > self.best_hand_score {
+            self.best_hand_score = score.clone();
+        }
+    }
+
+    /// Records a discard of the given number of cards.
+    #[inline]
+    pub const fn record_discard(&mut self, cards: usize) {
+        self.cards_discarded = self.cards_discarded.saturating_add(cards);
+    }
+
+    /// Records a finished run played with the named deck.
+    ///
+    /// The money gained during the run is recorded whether the run was won or
+    /// lost. The duration of the run is only considered for won runs, and may
+    /// be omitted if it is not known, eg, for runs continued from a save.
+    #[inline]
+    pub fn record_run(
+        &mut self,
+        deck: &str,
+        won: bool,
+        ante: NonZeroUsize,
+        money_gained: usize,
+        duration: Option<Duration>,
+    ) {
+        let record = self.runs.entry(deck.to_owned()).or_default();
+        record.played = record.played.saturating_add(1);
+        self.highest_ante = self.highest_ante.max(ante.get());
+        self.money_earned = self.money_earned.saturating_add(money_gained);
+
+        if won {
+            record.won = record.won.saturating_add(1);
+            if let Some(run_duration) = duration {
+                self.fastest_win = Some(
+                    self.fastest_win
+                        .map_or(run_duration, |fastest| fastest.min(run_duration)),
+                );
+            }
+        }
+    }
+
+    /// Returns the most played hand along with the number of times it was
+    /// played. Ties are broken in favor of the higher ranked hand.
+    #[must_use = "Most played hand must be used."]
+    #[inline]
+    pub fn most_played_hand(&self) -> Option<(ScoringHand, usize)> {
+        self.hands_played
+            .iter()
+            .rev()
+            .max_by_key(|&(_, count)| count)
+            .map(|(&scoring_hand, &count)| (scoring_hand, count))
+    }
+
+    /// Returns the total number of finished runs.
+    #[must_use = "Total runs played must be used."]
+    #[inline]
+    pub fn runs_played(&self) -> usize {
+        self.runs.values().map(|record| record.played).sum()
+    }
+
+    /// Returns the total number of won runs.
+    #[must_use = "Total runs won must be used."]
+    #[inline]
+    pub fn runs_won(&self) -> usize {
+        self.runs.values().map(|record| record.won).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unversioned_profile_migrates() {
+        let profile: Profile =
+            serde_json::from_str(r#"{"completed_challenges":["one_shot"]}"#).unwrap();
+
+        assert_eq!(profile.version, 0);
+
+        let migrated = profile.migrate().unwrap();
+
+        assert_eq!(migrated.version, PROFILE_VERSION);
+        assert!(migrated.has_completed("one_shot"));
+        assert_eq!(migrated.stats, ProfileStats::default());
+    }
+
+    #[test]
+    fn newer_profile_is_not_migrated() {
+        let profile: Profile = serde_json::from_str(r#"{"version":99}"#).unwrap();
+
+        assert!(matches!(
+            profile.migrate(),
+            Err(CoreError::UnsupportedProfileVersionError(99))
+        ));
+    }
+
+    #[test]
+    fn stats_round_trip() {
+        let mut profile = Profile::default();
+        profile
+            .stats
+            .record_hand(ScoringHand::Flush, 5, &Score::from(300_usize));
+        profile.stats.record_run(
+            "Standard",
+            true,
+            NonZeroUsize::MIN,
+            4,
+            Some(Duration::from_mins(1)),
+        );
+
+        let serialized = serde_json::to_string(&profile).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Profile>(&serialized).unwrap(),
+            profile
+        );
+    }
+
+    #[test]
+    fn run_records_keep_bests() {
+        let mut stats = ProfileStats::default();
+        stats.record_hand(ScoringHand::Pair, 2, &Score::from(40_usize));
+        stats.record_hand(ScoringHand::Pair, 2, &Score::from(20_usize));
+        stats.record_hand(ScoringHand::Flush, 5, &Score::from(30_usize));
+        stats.record_run(
+            "Standard",
+            true,
+            NonZeroUsize::MIN,
+            4,
+            Some(Duration::from_secs(90)),
+        );
+        stats.record_run(
+            "Standard",
+            true,
+            NonZeroUsize::MIN,
+            2,
+            Some(Duration::from_mins(2)),
+        );
+        stats.record_run("Standard", false, NonZeroUsize::MIN, 3, None);
+        stats.record_run("Custom", true, NonZeroUsize::MIN, 1, None);
+
+        assert_eq!(stats.best_hand_score, Score::from(40_usize));
+        assert_eq!(stats.cards_played, 9);
+        assert_eq!(stats.most_played_hand(), Some((ScoringHand::Pair, 2)));
+        assert_eq!(stats.fastest_win, Some(Duration::from_secs(90)));
+        assert_eq!(stats.money_earned, 10);
+        assert_eq!(stats.runs_played(), 4);
+        assert_eq!(stats.runs_won(), 3);
+        assert_eq!(
+            stats.runs.get("Standard"),
+            Some(&RunRecord { played: 3, won: 2 })
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! This module provides [`Puzzle`], a handcrafted scenario to be solved in a
+//! single round.
+//!
+//! A puzzle fixes the cards in hand, the exact order in which cards are drawn,
+//! the hands and discards available and the score to reach. A run of a puzzle
+//! is created using [`super::engine::Engine::with_puzzle()`] and the puzzle is
+//! solved if the run is won.
+//!
+//! Puzzles may also carry a known solution, which makes them usable as
+//! regression fixtures for the [`super::scorer::Scorer`].
+
+use itertools::Itertools;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    card::{parse_hand, Card},
+    strategy::Move,
+};
+use crate::error::StrumError;
+
+/// A handcrafted scenario to be solved in a single round.
+///
+/// Cards are written as a whitespace separated list in card notation, see
+/// [`parse_hand()`]. Cards are drawn in the order they are listed in
+/// [`Puzzle::draw_order`].
+///
+/// The hand is sorted by rank when the puzzle starts and after every draw, so
+/// indices of the moves in [`Puzzle::solution`] refer to the sorted hand.
+///
+/// ```
+/// # use balatro_tui_core::{engine::{Engine, Outcome}, puzzle::Puzzle, strategy::{apply_move, Move}};
+/// let puzzle: Puzzle = serde_json::from_str(
+///     r#"{
+///         "name": "Pocket Aces",
+///         "hand": "AS AH 7C 3D 2S",
+///         "target_score": 50,
+///         "hands": 1
+///     }"#,
+/// )
+/// .unwrap();
+/// let mut engine = Engine::with_puzzle(&puzzle).unwrap();
+///
+/// assert_eq!(
+///     apply_move(&mut engine, Move::Play(vec![0, 1])).unwrap(),
+///     Outcome::RunFinished(true)
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Puzzle {
+    /// Name of the puzzle.
+    pub name: String,
+    /// Description of the puzzle shown to the player.
+    #[serde(default)]
+    pub description: String,
+    /// Cards in hand at the start of the puzzle.
+    #[serde(
+        deserialize_with = "deserialize_hand_notation",
+        serialize_with = "serialize_hand_notation"
+    )]
+    pub hand: Vec<Card>,
+    /// Cards to be drawn, in the order they are drawn.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_hand_notation",
+        serialize_with = "serialize_hand_notation"
+    )]
+    pub draw_order: Vec<Card>,
+    /// Score to reach to solve the puzzle.
+    pub target_score: usize,
+    /// Number of hands that can be played.
+    pub hands: usize,
+    /// Number of discards available.
+    #[serde(default)]
+    pub discards: usize,
+    /// Known solution of the puzzle, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub solution: Vec<Move>,
+}
+
+/// Deserializes cards from whitespace separated card notation.
+fn deserialize_hand_notation<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Card>, D::Error> {
+    let notation = String::deserialize(deserializer)?;
+    parse_hand(&notation)
+        .map_err(|err: StrumError| D::Error::custom(format!("invalid cards `{notation}`: {err}")))
+}
+
+/// Serializes cards into whitespace separated ASCII card notation.
+fn serialize_hand_notation<S: Serializer>(
+    cards: &[Card],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&cards.iter().map(|card| format!("{card:#}")).join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::{Engine, Outcome},
+        error::CoreError,
+        strategy::apply_move,
+    };
+
+    fn draw_puzzle() -> Puzzle {
+        Puzzle {
+            name: "Draw".to_owned(),
+            hand: parse_hand("KS KH 9C 5D 2S").unwrap(),
+            draw_order: parse_hand("KD 3C").unwrap(),
+            target_score: 100,
+            hands: 1,
+            discards: 1,
+            ..Puzzle::default()
+        }
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let puzzle = draw_puzzle();
+
+        let serialized = serde_json::to_string(&puzzle).unwrap();
+
+        assert!(serialized.contains(r#""hand":"KS KH 9C 5D 2S","draw_order":"KD 3C""#));
+        assert_eq!(serde_json::from_str::<Puzzle>(&serialized).unwrap(), puzzle);
+    }
+
+    #[test]
+    fn cards_are_drawn_in_order() {
+        let mut engine = Engine::with_puzzle(&draw_puzzle()).unwrap();
+
+        assert_eq!(
+            apply_move(&mut engine, Move::Discard(vec![4])).unwrap(),
+            Outcome::HandDiscarded
+        );
+        assert_eq!(
+            *engine.run().round.hand.try_read().unwrap(),
+            parse_hand("KD KH KS 9C 5D").unwrap()
+        );
+        assert_eq!(
+            apply_move(&mut engine, Move::Play(vec![0, 1, 2])).unwrap(),
+            Outcome::RunFinished(true)
+        );
+    }
+
+    #[test]
+    fn puzzle_fails_once_hands_are_exhausted() {
+        let mut engine = Engine::with_puzzle(&draw_puzzle()).unwrap();
+
+        assert_eq!(engine.snapshot().unwrap().target_score.to_string(), "100");
+        assert_eq!(
+            apply_move(&mut engine, Move::Play(vec![0, 1])).unwrap(),
+            Outcome::RunFinished(false)
+        );
+    }
+
+    #[test]
+    fn puzzle_without_hand_is_invalid() {
+        let puzzle = Puzzle {
+            hands: 1,
+            ..Puzzle::default()
+        };
+
+        assert!(matches!(
+            Engine::with_puzzle(&puzzle),
+            Err(CoreError::InvalidPuzzleError(_))
+        ));
+    }
+}
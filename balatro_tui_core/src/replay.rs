@@ -0,0 +1,83 @@
+//! This module provides [`Replay`], a recording of a run that can be shared and
+//! played back.
+//!
+//! A replay only stores the seed of the run and the ordered list of
+//! [`Action`] made by the player. Since the run is fully deterministic from its
+//! seed, re-applying the actions on a new run created with the same seed
+//! reproduces the run exactly. Runs played with a custom deck, a challenge or
+//! a puzzle also store them, as they can not be derived from the seed.
+
+use serde::{Deserialize, Serialize};
+
+use super::{action::Action, deck::Deck, puzzle::Puzzle};
+
+/// Recording of a run, ie, its seed and every [`Action`] made in order.
+///
+/// ```
+/// # use balatro_tui_core::{action::Action, replay::Replay};
+/// let mut replay = Replay::new("ABCDEF".to_owned());
+/// replay.record(Action::Select(0));
+/// replay.record(Action::Play);
+///
+/// assert_eq!(replay.actions, vec![Action::Select(0), Action::Play]);
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Replay {
+    /// Seed of the recorded run. See [`super::run::RunProperties::seed`].
+    pub seed: String,
+    /// Actions made in the run, in order.
+    pub actions: Vec<Action>,
+    /// Initial deck of the recorded run, if it was not the standard deck.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deck: Option<Deck>,
+    /// Identifier of the [`super::challenge::Challenge`] of the recorded run,
+    /// if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<String>,
+    /// [`Puzzle`] of the recorded run, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub puzzle: Option<Puzzle>,
+}
+
+impl Replay {
+    /// Create a new empty replay for a run with the given seed.
+    #[must_use = "Created replay must be used."]
+    #[inline]
+    pub const fn new(seed: String) -> Self {
+        Self {
+            seed,
+            actions: vec![],
+            deck: None,
+            challenge: None,
+            puzzle: None,
+        }
+    }
+
+    /// Append an action to the replay.
+    #[inline]
+    pub fn record(&mut self, action: Action) {
+        self.actions.push(action);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trip() {
+        let mut replay = Replay::new("SEED".to_owned());
+        replay.record(Action::Select(2));
+        replay.record(Action::Deselect(2));
+        replay.record(Action::Select(4));
+        replay.record(Action::Discard);
+
+        let serialized = serde_json::to_string(&replay).unwrap();
+
+        assert_eq!(
+            serialized,
+            r#"{"seed":"SEED","actions":[{"Select":2},{"Deselect":2},{"Select":4},"Discard"]}"#
+        );
+        assert_eq!(serde_json::from_str::<Replay>(&serialized).unwrap(), replay);
+    }
+}
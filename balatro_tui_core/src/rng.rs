@@ -0,0 +1,56 @@
+//! This module provides the seeded random number generator used across a run.
+//!
+//! Every random operation in a run (shuffling, drawing, boss selection) draws
+//! from a single [`RunRng`] seeded from [`super::run::RunProperties::seed`].
+//! This makes a run fully reproducible from its seed and allows its state to
+//! be saved and restored.
+
+use rand::{
+    distributions::{Alphanumeric, DistString},
+    thread_rng, SeedableRng,
+};
+use rand_chacha::ChaCha8Rng;
+
+/// Random number generator used for a run.
+///
+/// The generator is portable across platforms and serializable, so a saved run
+/// continues with the exact same sequence of random values.
+pub type RunRng = ChaCha8Rng;
+
+/// Length of seeds created with [`random_seed()`].
+pub const SEED_LENGTH: usize = 16;
+
+/// FNV-1a offset basis for 64-bit hashes.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// FNV-1a prime for 64-bit hashes.
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Creates a new [`RunRng`] deterministically seeded from a seed string.
+///
+/// The seed string is hashed using FNV-1a which, unlike
+/// [`std::hash::DefaultHasher`], is stable across Rust releases and platforms.
+///
+/// ```
+/// # use rand::Rng;
+/// # use balatro_tui_core::rng::seeded_rng;
+/// let mut first = seeded_rng("ABCDEF");
+/// let mut second = seeded_rng("ABCDEF");
+///
+/// assert_eq!(first.gen::<u64>(), second.gen::<u64>());
+/// ```
+#[must_use = "Created rng must be used."]
+#[inline]
+pub fn seeded_rng(seed: &str) -> RunRng {
+    let hash = seed.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    });
+
+    RunRng::seed_from_u64(hash)
+}
+
+/// Creates a new random alphanumeric seed string for a run.
+#[must_use = "Created seed must be used."]
+#[inline]
+pub fn random_seed() -> String {
+    Alphanumeric.sample_string(&mut thread_rng(), SEED_LENGTH)
+}
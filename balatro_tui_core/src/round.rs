@@ -6,23 +6,32 @@
 //! reaches zero and the [`Round::score`] does not cross the target score of
 //! [`Round::blind`], the round is considered as lost, returning the user to
 //! game over screen.
+//!
+//! [`Round`] can be serialized, but the [`Round::deck`] and [`Round::rng`]
+//! shared with the enclosing [`super::run::Run`] are skipped. They are linked
+//! back when the [`super::run::Run`] is deserialized.
 
 use std::{
     num::NonZeroUsize,
     sync::{Arc, RwLock},
 };
 
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
 use super::{
     blind::Blind,
-    card::{Card, Sortable},
+    card::{Card, SortOrder, Sortable},
     deck::{Deck, DeckExt},
+    rng::RunRng,
+    score::Score,
     scorer::Scorer,
 };
-use crate::error::{ArithmeticError, CoreError};
+use crate::error::{ArithmeticError, CoreError, ScorerError};
 
 /// Abstracts properties that remain persistent across played hands within a
 /// round.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct RoundProperties {
     /// Current ante of the rounds. Game ends after beating ante `8`.
     pub ante: NonZeroUsize,
@@ -36,13 +45,18 @@ pub struct RoundProperties {
 ///
 /// Once the round is over, this struct is destroyed and a new one is created
 /// when a blind is selected again.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Round {
     /// Persistent properties for the round.
     pub properties: RoundProperties,
     /// Shared deck of cards across rounds. Round will start by drawing random
     /// cards from this deck.
+    #[serde(skip)]
     pub deck: Arc<RwLock<Deck>>,
+    /// Shared random number generator of the run. Used to draw cards from
+    /// [`Round::deck`].
+    #[serde(skip, default = "unlinked_rng")]
+    pub rng: Arc<RwLock<RunRng>>,
     /// An instance of a [`Blind`].
     pub blind: Blind,
     /// Number of hands that can be discarded and replaced with newly drawn
@@ -51,35 +65,98 @@ pub struct Round {
     /// Number of hands that can be played in the round.
     pub hands_count: usize,
     /// Score accumulated in a round.
-    pub score: usize,
+    pub score: Score,
     /// An internal state for handling the hover and selection of cards in hand.
     pub hand: Arc<RwLock<Deck>>,
     /// A drainage for played cards; to be flushed into the main deck at the end
     /// of the round.
     pub history: Deck,
+    /// Score required to beat the round, overriding the target score of
+    /// [`Round::blind`], eg, for puzzles.
+    #[serde(default)]
+    pub target_score: Option<Score>,
+    /// Whether cards are drawn from the front of [`Round::deck`] in order
+    /// instead of at random, eg, for puzzles.
+    #[serde(default)]
+    pub ordered_draw: bool,
+    /// Order in which the hand is sorted whenever cards are dealt.
+    #[serde(default)]
+    pub sort_order: SortOrder,
+}
+
+/// Placeholder for [`Round::rng`] until the round is linked to a run.
+fn unlinked_rng() -> Arc<RwLock<RunRng>> {
+    Arc::new(RwLock::new(RunRng::seed_from_u64(0)))
 }
 
 impl Round {
     /// Main entrypoint of the round. Once called, this method prepares the
     /// initial state of the round and initializes internal states.
     pub fn start(&mut self) -> Result<(), CoreError> {
-        self.hand = Arc::from(RwLock::from(
-            self.deck
-                .try_write()?
-                .draw_random(self.properties.hand_size)?,
-        ));
-        self.hand.try_write()?.sort_by_rank();
+        let hand = self.draw(self.properties.hand_size)?;
+        self.hand = Arc::from(RwLock::from(hand));
+        self.hand.try_write()?.sort_by_order(self.sort_order);
 
         Ok(())
     }
 
+    /// Returns the score required to beat the round.
+    #[inline]
+    pub fn get_target_score(&self) -> Result<Score, ScorerError> {
+        self.target_score.as_ref().map_or_else(
+            || self.blind.get_target_score(self.properties.ante),
+            |target_score| Ok(target_score.clone()),
+        )
+    }
+
+    /// Draws cards from the deck, at random or in order as per
+    /// [`Round::ordered_draw`]. Draws only the remaining cards once the deck
+    /// runs low.
+    fn draw(&self, draw_size: usize) -> Result<Deck, CoreError> {
+        let mut deck = self.deck.try_write()?;
+        let available_size = draw_size.min(deck.len());
+        if self.ordered_draw {
+            deck.draw_ordered(available_size)
+        } else {
+            deck.draw_random(available_size, &mut *self.rng.try_write()?)
+        }
+    }
+
     /// Draws new cards at the end of a hand played or discarded and adds
     /// previous cards to history drain.
     fn deal_cards(&mut self, last_cards: &mut Vec<Card>) -> Result<(), CoreError> {
-        let mut new_cards = self.deck.try_write()?.draw_random(last_cards.len())?;
+        let mut new_cards = self.draw(last_cards.len())?;
         self.history.append(last_cards);
         self.hand.try_write()?.append(&mut new_cards);
-        self.hand.try_write()?.sort_by_rank();
+        self.hand.try_write()?.sort_by_order(self.sort_order);
+
+        Ok(())
+    }
+
+    /// Sorts the hand as per the given [`SortOrder`], which is kept for the
+    /// following deals. Returns the previous index of each card in the sorted
+    /// hand.
+    pub fn sort_hand(&mut self, sort_order: SortOrder) -> Result<Vec<usize>, CoreError> {
+        self.sort_order = sort_order;
+
+        let mut hand = self.hand.try_write()?;
+        let mut indexed_cards = hand.iter().copied().enumerate().collect::<Vec<_>>();
+        indexed_cards.sort_by(|&(_, first), &(_, second)| sort_order.compare(&first, &second));
+        let (previous_indices, cards) = indexed_cards.into_iter().unzip();
+        *hand = cards;
+        drop(hand);
+
+        Ok(previous_indices)
+    }
+
+    /// Swaps the cards at the given indices in hand.
+    pub fn swap_cards(&mut self, first: usize, second: usize) -> Result<(), CoreError> {
+        let mut hand = self.hand.try_write()?;
+        if let Some(&idx) = [first, second].iter().find(|&&idx| idx >= hand.len()) {
+            return Err(CoreError::CardIndexError(idx));
+        }
+        hand.swap(first, second);
+        drop(hand);
 
         Ok(())
     }
@@ -95,10 +172,7 @@ impl Round {
             .checked_sub(1)
             .ok_or(ArithmeticError::Overflow("subtraction"))?;
 
-        self.score = self
-            .score
-            .checked_add(Scorer::score_cards(played_cards)?)
-            .ok_or(ArithmeticError::Overflow("addition"))?;
+        self.score += Scorer::score_cards(played_cards)?;
 
         self.deal_cards(played_cards)?;
 
@@ -4,15 +4,27 @@
 //! run is over.
 
 use std::{
+    collections::BTreeMap,
     num::NonZeroUsize,
     sync::{Arc, RwLock},
 };
 
-use super::{deck::Deck, round::Round};
-use crate::error::CoreError;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::{
+    blind::Blind,
+    challenge::ChallengeRule,
+    deck::Deck,
+    rng::RunRng,
+    round::Round,
+    score::Score,
+    scorer::{Scorer, ScoringHand},
+};
+use crate::error::{CoreError, StrumError};
 
 /// Tracks the active state of the run
-#[derive(Copy, Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum RunState {
     /// Represents that the run is ongoing
     #[default]
@@ -23,7 +35,7 @@ pub enum RunState {
 }
 
 /// Persistent details about the run.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct RunProperties {
     /// The number of cards to be fetched in hand during the round.
     pub hand_size: usize,
@@ -35,6 +47,42 @@ pub struct RunProperties {
     pub seed: String,
     /// Initial amount of money that the run starts with.
     pub starting_money: usize,
+    /// Special rules applied to the run, eg, by a
+    /// [`crate::challenge::Challenge`].
+    #[serde(default)]
+    pub rules: Vec<ChallengeRule>,
+}
+
+impl RunProperties {
+    /// Returns `true` if the run applies the given rule.
+    #[must_use]
+    #[inline]
+    pub fn has_rule(&self, rule: ChallengeRule) -> bool {
+        self.rules.contains(&rule)
+    }
+
+    /// Returns the maximum amount of money that can be held, as per the
+    /// [`ChallengeRule::MoneyCap`] rule, if any. The lowest cap applies if
+    /// several are set.
+    #[must_use]
+    pub fn money_cap(&self) -> Option<usize> {
+        self.rules
+            .iter()
+            .filter_map(|rule| match *rule {
+                ChallengeRule::MoneyCap(cap) => Some(cap),
+                ChallengeRule::FaceDown => None,
+            })
+            .min()
+    }
+
+    /// Returns the amount of money that the run starts with, ie, the starting
+    /// money limited by the [`ChallengeRule::MoneyCap`] rule, if any.
+    #[must_use]
+    #[inline]
+    pub fn initial_money(&self) -> usize {
+        self.money_cap()
+            .map_or(self.starting_money, |cap| self.starting_money.min(cap))
+    }
 }
 
 /// [`Run`] struct maintains the working state of a run, along with the rounds
@@ -42,7 +90,12 @@ pub struct RunProperties {
 ///
 /// A single run is maintained from the point a deck is selected to the point of
 /// game over.
-#[derive(Clone, Debug)]
+///
+/// A run can be serialized to save it and deserialized to resume it later. The
+/// [`Run::deck`] and [`Run::rng`] are serialized only once and shared with the
+/// [`Round`] again on deserialization, so that the deck is not duplicated.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(from = "RunData")]
 pub struct Run {
     /// Persistent properties for the run.
     pub properties: RunProperties,
@@ -53,10 +106,83 @@ pub struct Run {
     /// Shared deck of cards across rounds. [`Run`] simply passes this on to the
     /// [`Round`] instance.
     pub deck: Arc<RwLock<Deck>>,
+    /// Random number generator of the run seeded from
+    /// [`RunProperties::seed`]. [`Run`] passes this on to the [`Round`]
+    /// instance.
+    pub rng: Arc<RwLock<RunRng>>,
     /// An instance of a [`Round`].
     pub round: Round,
     /// Used to keep track of the last played [`Round`] number.
     pub upcoming_round_number: NonZeroUsize,
+    /// Number of times each [`ScoringHand`] has been played in the run.
+    pub hands_played: BTreeMap<ScoringHand, usize>,
+}
+
+/// Summary of a [`ScoringHand`] in a run.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HandInfo {
+    /// The summarized hand.
+    pub scoring_hand: ScoringHand,
+    /// Base chips scored by the hand.
+    pub chips: usize,
+    /// Base multiplier scored by the hand.
+    pub multiplier: usize,
+    /// Level of the hand. Hands are not leveled up yet, so every hand is at the
+    /// first level.
+    pub level: NonZeroUsize,
+    /// Number of times the hand has been played in the run.
+    pub played: usize,
+}
+
+/// Summary of a [`Blind`] of the current ante.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BlindInfo {
+    /// The summarized blind.
+    pub blind: Blind,
+    /// Score required to defeat the blind.
+    pub target_score: Score,
+    /// Money rewarded for defeating the blind.
+    pub reward: usize,
+}
+
+/// Deserialization mirror of [`Run`] that is linked into a [`Run`] once the
+/// shared state is restored.
+#[derive(Deserialize)]
+struct RunData {
+    /// See [`Run::properties`].
+    properties: RunProperties,
+    /// See [`Run::run_state`].
+    run_state: RunState,
+    /// See [`Run::money`].
+    money: usize,
+    /// See [`Run::deck`].
+    deck: Arc<RwLock<Deck>>,
+    /// See [`Run::rng`].
+    rng: Arc<RwLock<RunRng>>,
+    /// See [`Run::round`].
+    round: Round,
+    /// See [`Run::upcoming_round_number`].
+    upcoming_round_number: NonZeroUsize,
+    /// See [`Run::hands_played`].
+    #[serde(default)]
+    hands_played: BTreeMap<ScoringHand, usize>,
+}
+
+impl From<RunData> for Run {
+    fn from(data: RunData) -> Self {
+        let mut run = Self {
+            properties: data.properties,
+            run_state: data.run_state,
+            money: data.money,
+            deck: data.deck,
+            rng: data.rng,
+            round: data.round,
+            upcoming_round_number: data.upcoming_round_number,
+            hands_played: data.hands_played,
+        };
+        run.link_round();
+        run
+    }
 }
 
 impl Run {
@@ -64,6 +190,192 @@ impl Run {
     /// a round.
     #[inline]
     pub fn start(&mut self) -> Result<(), CoreError> {
+        self.link_round();
         self.round.start()
     }
+
+    /// Shares [`Run::deck`] and [`Run::rng`] with the contained [`Round`].
+    #[inline]
+    pub fn link_round(&mut self) {
+        self.round.deck = Arc::clone(&self.deck);
+        self.round.rng = Arc::clone(&self.rng);
+    }
+
+    /// Counts a play of the given [`ScoringHand`] in [`Run::hands_played`].
+    #[inline]
+    pub fn record_hand_played(&mut self, scoring_hand: ScoringHand) {
+        let count = self.hands_played.entry(scoring_hand).or_default();
+        *count = count.saturating_add(1);
+    }
+
+    /// Returns the [`HandInfo`] of every [`ScoringHand`], in order of scoring
+    /// precedence.
+    pub fn hand_infos(&self) -> Result<Vec<HandInfo>, StrumError> {
+        ScoringHand::iter()
+            .map(|scoring_hand| {
+                let (chips, multiplier) = Scorer::get_chips_and_multiplier(scoring_hand)?;
+                Ok(HandInfo {
+                    scoring_hand,
+                    chips,
+                    multiplier,
+                    level: NonZeroUsize::MIN,
+                    played: self
+                        .hands_played
+                        .get(&scoring_hand)
+                        .copied()
+                        .unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the [`BlindInfo`] of the blinds of the current ante. The boss
+    /// blind is only included once it has been reached, since the boss is not
+    /// known beforehand.
+    pub fn blind_infos(&self) -> Result<Vec<BlindInfo>, CoreError> {
+        let ante = self.round.properties.ante;
+        let boss = matches!(self.round.blind, Blind::Boss(_)).then_some(self.round.blind);
+
+        [Blind::Small, Blind::Big]
+            .into_iter()
+            .chain(boss)
+            .map(|blind| {
+                Ok(BlindInfo {
+                    blind,
+                    target_score: blind.get_target_score(ante)?,
+                    reward: blind.get_reward()?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        blind::Bosses, card::SortOrder, deck::DeckConstExt, rng::seeded_rng, round::RoundProperties,
+    };
+
+    fn new_run() -> Run {
+        let deck = Arc::new(RwLock::new(Deck::standard()));
+        let rng = Arc::new(RwLock::new(seeded_rng("SEED")));
+        Run {
+            properties: RunProperties {
+                hand_size: 10,
+                max_discards: 3,
+                max_hands: 3,
+                seed: "SEED".to_owned(),
+                starting_money: 10,
+                rules: vec![],
+            },
+            run_state: RunState::Running,
+            money: 10,
+            deck: Arc::clone(&deck),
+            rng: Arc::clone(&rng),
+            round: Round {
+                properties: RoundProperties {
+                    ante: NonZeroUsize::MIN,
+                    hand_size: 10,
+                    round_number: NonZeroUsize::MIN,
+                },
+                deck,
+                rng,
+                blind: Blind::Small,
+                discards_count: 3,
+                hands_count: 3,
+                score: Score::zero(),
+                hand: Arc::new(RwLock::new(vec![])),
+                history: vec![],
+                target_score: None,
+                ordered_draw: false,
+                sort_order: SortOrder::default(),
+            },
+            upcoming_round_number: NonZeroUsize::MIN,
+            hands_played: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn serde_round_trip_shares_deck() {
+        let mut run = new_run();
+        run.start().unwrap();
+
+        let serialized = serde_json::to_string(&run).unwrap();
+        let mut restored = serde_json::from_str::<Run>(&serialized).unwrap();
+
+        assert!(
+            Arc::ptr_eq(&restored.deck, &restored.round.deck),
+            "Deck must be shared between run and round"
+        );
+        assert!(
+            Arc::ptr_eq(&restored.rng, &restored.round.rng),
+            "Rng must be shared between run and round"
+        );
+        assert_eq!(*restored.deck.read().unwrap(), *run.deck.read().unwrap());
+        assert_eq!(
+            *restored.round.hand.read().unwrap(),
+            *run.round.hand.read().unwrap()
+        );
+
+        let mut played = run.round.hand.write().unwrap().drain(..2).collect();
+        let mut restored_played = restored.round.hand.write().unwrap().drain(..2).collect();
+        run.round.discard_hand(&mut played).unwrap();
+        restored.round.discard_hand(&mut restored_played).unwrap();
+
+        assert_eq!(
+            *restored.round.hand.read().unwrap(),
+            *run.round.hand.read().unwrap(),
+            "Restored run must continue with the same random sequence"
+        );
+    }
+
+    #[test]
+    fn hand_infos_count_played_hands() {
+        let mut run = new_run();
+        run.record_hand_played(ScoringHand::Flush);
+        run.record_hand_played(ScoringHand::Flush);
+        run.record_hand_played(ScoringHand::Pair);
+
+        let hand_infos = run.hand_infos().unwrap();
+        let played = |scoring_hand| {
+            hand_infos
+                .iter()
+                .find(|hand_info| hand_info.scoring_hand == scoring_hand)
+                .unwrap()
+                .played
+        };
+
+        assert_eq!(hand_infos.len(), ScoringHand::iter().count());
+        assert_eq!(played(ScoringHand::Flush), 2);
+        assert_eq!(played(ScoringHand::Pair), 1);
+        assert_eq!(played(ScoringHand::HighCard), 0);
+        assert_eq!(
+            hand_infos.first().map(|hand_info| hand_info.scoring_hand),
+            Some(ScoringHand::FlushFive)
+        );
+    }
+
+    #[test]
+    fn blind_infos_include_reached_boss() {
+        let mut run = new_run();
+        let blinds = |current_run: &Run| {
+            current_run
+                .blind_infos()
+                .unwrap()
+                .into_iter()
+                .map(|blind_info| blind_info.blind)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(blinds(&run), [Blind::Small, Blind::Big]);
+
+        run.round.blind = Blind::Boss(Bosses::Hook);
+
+        assert_eq!(blinds(&run), [
+            Blind::Small,
+            Blind::Big,
+            Blind::Boss(Bosses::Hook)
+        ]);
+    }
 }
@@ -0,0 +1,303 @@
+//! This module provides [`Score`], an arbitrary-precision numeric type used for
+//! chips, multipliers, target scores and accumulated round scores.
+//!
+//! Scores in a strong run grow well past the range of any primitive integer.
+//! [`Score`] never overflows and formats large values using the `e` notation
+//! used by the original game.
+
+use std::{
+    fmt::{Display, Error as FmtError, Formatter, Result as FmtResult},
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign},
+    str::FromStr,
+};
+
+use num_bigint::{BigUint, ParseBigIntError};
+use num_traits::ToPrimitive;
+use serde::{
+    de::{Error as DeError, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Number of digits after which a [`Score`] is displayed in `e` notation.
+pub const SCIENTIFIC_NOTATION_THRESHOLD: usize = 11;
+
+/// Number of fractional digits shown for the mantissa of a [`Score`] in `e`
+/// notation.
+const MANTISSA_PRECISION: usize = 2;
+
+/// Arbitrary-precision, non-negative score.
+///
+/// [`Score`] supports addition and multiplication with other scores and with
+/// [`usize`] operands.
+///
+/// ```
+/// # use balatro_tui_core::score::Score;
+/// let chips = Score::from(40_usize) + 12;
+/// let score = chips * 4;
+///
+/// assert_eq!(score, Score::from(208_usize));
+/// assert_eq!(score.to_string(), "208");
+/// ```
+///
+/// [`Score`] is serialized as a decimal string to retain full precision. It
+/// can be deserialized from either a decimal string or an unsigned integer.
+///
+/// Values with more than [`SCIENTIFIC_NOTATION_THRESHOLD`] digits are
+/// displayed in `e` notation.
+///
+/// ```
+/// # use std::str::FromStr;
+/// # use balatro_tui_core::score::Score;
+/// let score = Score::from_str("1234567890123456789012345678901234567890123456").unwrap();
+///
+/// assert_eq!(score.to_string(), "1.23e45");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Score(BigUint);
+
+impl Score {
+    /// Returns a zero score.
+    #[must_use = "Created score must be used."]
+    #[inline]
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the score is zero.
+    #[must_use]
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0 == BigUint::ZERO
+    }
+
+    /// Returns the closest [`f64`] to the score, or [`f64::INFINITY`] if the
+    /// score is out of range.
+    #[must_use]
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::INFINITY)
+    }
+
+    /// Returns the difference between two scores, clamped to zero when `other`
+    /// is larger.
+    #[must_use = "Computed score must be used."]
+    #[inline]
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        if other >= self {
+            Self::zero()
+        } else {
+            Self(self.0.clone() - &other.0)
+        }
+    }
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let digits = self.0.to_string();
+
+        if digits.len() <= SCIENTIFIC_NOTATION_THRESHOLD {
+            return f.write_str(&digits);
+        }
+
+        // Rounds half up to the shown digits, which may carry into an extra
+        // digit, eg, 9.995e11 is shown as 1.00e12
+        let dropped_digits = digits
+            .len()
+            .saturating_sub(MANTISSA_PRECISION.saturating_add(1));
+        let divisor =
+            BigUint::from(10_u8).pow(u32::try_from(dropped_digits).map_err(|_err| FmtError)?);
+        let rounded_digits = ((&self.0 + &divisor / 2_u8) / divisor).to_string();
+        let mut exponent = digits.len().saturating_sub(1);
+        if rounded_digits.len() > MANTISSA_PRECISION.saturating_add(1) {
+            exponent = exponent.saturating_add(1);
+        }
+
+        let mut mantissa_digits = rounded_digits.chars();
+        let leading_digit = mantissa_digits.next().unwrap_or('0');
+        let fractional_digits = mantissa_digits.take(MANTISSA_PRECISION).collect::<String>();
+
+        write!(f, "{leading_digit}.{fractional_digits}e{exponent}")
+    }
+}
+
+impl FromStr for Score {
+    type Err = ParseBigIntError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(BigUint::from_str(s)?))
+    }
+}
+
+impl From<usize> for Score {
+    #[inline]
+    fn from(value: usize) -> Self {
+        Self(BigUint::from(value))
+    }
+}
+
+impl Add for Score {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Add<usize> for Score {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: usize) -> Self::Output {
+        Self(self.0 + rhs)
+    }
+}
+
+impl AddAssign for Score {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Mul for Score {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Mul<usize> for Score {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: usize) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl MulAssign for Score {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl Sum for Score {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl Serialize for Score {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Score {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ScoreVisitor)
+    }
+}
+
+/// [`Visitor`] to deserialize [`Score`] from a decimal string or an unsigned
+/// integer.
+struct ScoreVisitor;
+
+impl Visitor<'_> for ScoreVisitor {
+    type Value = Score;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        formatter.write_str("a non-negative integer or a decimal string")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        Ok(Score(BigUint::from(v)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        Score::from_str(v).map_err(|_err| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_small_score() {
+        assert_eq!(Score::from(0).to_string(), "0");
+        assert_eq!(Score::from(1_234).to_string(), "1234");
+        assert_eq!(Score::from(99_999_999_999).to_string(), "99999999999");
+    }
+
+    #[test]
+    fn display_large_score() {
+        assert_eq!(Score::from(100_000_000_000).to_string(), "1.00e11");
+        assert_eq!(
+            (Score::from(usize::MAX) * Score::from(usize::MAX)).to_string(),
+            "3.40e38"
+        );
+    }
+
+    #[test]
+    fn display_rounds_mantissa() {
+        assert_eq!(Score::from(123_449_999_999).to_string(), "1.23e11");
+        assert_eq!(Score::from(123_450_000_000).to_string(), "1.23e11");
+        assert_eq!(Score::from(123_500_000_000).to_string(), "1.24e11");
+        assert_eq!(Score::from(999_499_999_999).to_string(), "9.99e11");
+        assert_eq!(Score::from(999_500_000_000).to_string(), "1.00e12");
+    }
+
+    #[test]
+    fn arithmetic_does_not_overflow() {
+        let score = (0_usize..10).fold(Score::from(usize::MAX), |acc, _| acc * usize::MAX);
+
+        assert!(
+            score > Score::from(usize::MAX),
+            "Score must grow past usize"
+        );
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let score = Score::from(usize::MAX) * usize::MAX;
+        let serialized = serde_json::to_string(&score).unwrap();
+
+        assert_eq!(serialized, "\"340282366920938463426481119284349108225\"");
+        assert_eq!(serde_json::from_str::<Score>(&serialized).unwrap(), score);
+        assert_eq!(
+            serde_json::from_str::<Score>("300").unwrap(),
+            Score::from(300)
+        );
+    }
+
+    #[test]
+    fn saturating_sub_clamps_to_zero() {
+        assert_eq!(
+            Score::from(10).saturating_sub(&Score::from(4)),
+            Score::from(6)
+        );
+        assert!(Score::from(4).saturating_sub(&Score::from(10)).is_zero());
+    }
+}
@@ -4,34 +4,19 @@
 //! decks as well with [`ScoringHand::FlushFive`], [`ScoringHand::FlushHouse`]
 //! and [`ScoringHand::FiveOfAKind`].
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumCount, EnumIter, EnumProperty, EnumString, IntoStaticStr};
 
-use super::card::{Card, Rank, Sortable};
+use super::{
+    card::{Card, Rank},
+    evaluator::HandEvaluation,
+};
 use crate::{
     enum_property_ext::EnumPropertyExt,
-    error::{ArithmeticError, ScorerError, StrumError},
+    error::{ScorerError, StrumError},
+    score::Score,
 };
 
-/// Bit masks for scoring a straight.
-///
-/// 0th mask represents a high ace straight, ie, A-K-Q-J-10
-/// 1st mask represents a low ace straight, ie, A-2-3-4-5
-///
-/// <div class="warning">Straight scoring operation relies on consistency of
-/// this constant and thus must not be changed</div>
-const STRAIGHT_BIT_MASKS: [u16; 10] = [
-    0b0001_1110_0000_0001,
-    0b0000_0000_0001_1111,
-    0b0000_0000_0011_1110,
-    0b0000_0000_0111_1100,
-    0b0000_0000_1111_1000,
-    0b0000_0001_1111_0000,
-    0b0000_0011_1110_0000,
-    0b0000_0111_1100_0000,
-    0b0000_1111_1000_0000,
-    0b0001_1111_0000_0000,
-];
-
 /// [`ScoringHand`] represents which kind of hand is made when playing a set of
 /// cards.
 ///
@@ -63,6 +48,7 @@ const STRAIGHT_BIT_MASKS: [u16; 10] = [
     Clone,
     Copy,
     Debug,
+    Deserialize,
     Display,
     EnumCount,
     EnumIter,
@@ -74,6 +60,7 @@ const STRAIGHT_BIT_MASKS: [u16; 10] = [
     Ord,
     PartialEq,
     PartialOrd,
+    Serialize,
 )]
 pub enum ScoringHand {
     /// [`ScoringHand::FlushFive`] is scored when played cards have five cards
@@ -180,20 +167,6 @@ pub enum ScoringHand {
     HighCard,
 }
 
-/// Holds information regarding testing for a straight in the played hand.
-#[derive(Clone, Debug)]
-struct StraightTestReport {
-    /// A optional boolean that can indicate the following:
-    /// - [`None`]: No ace was counted in the the straight scoring.
-    /// - [`Some(false)`]: Ace was counted in the the straight scoring and was a
-    ///   low ace, ie, straight was made from ranks `5, 4, 3, 2, A`
-    /// - [`Some(true)`]: Ace was counted in the the straight scoring and was a
-    ///   high ace, ie, straight was made from ranks `A, K, Q, J, 10`
-    pub high_ace: Option<bool>,
-    /// Collection of scored ranks.
-    pub scored_ranks: Vec<Rank>,
-}
-
 /// Container for static scoring methods.
 ///
 /// [`Scorer::score_cards`] is a wrapper that handles scoring for cards. It
@@ -213,160 +186,18 @@ impl Scorer {
         ))
     }
 
-    /// Tests for a straight in a slice of [`Card`] and returns a
-    /// [`StraightTestReport`].
-    fn test_straight(cards: &[Card]) -> Option<StraightTestReport> {
-        let ranks = cards.iter().map(|card| card.rank).collect::<Vec<_>>();
-        let rank_bit_mask = ranks
-            .iter()
-            .fold(0, |bit_mask, rank| bit_mask | (1 << (*rank as usize)));
-
-        let is_straight = STRAIGHT_BIT_MASKS
-            .iter()
-            .any(|matcher| matcher == &rank_bit_mask);
-
-        let high_ace = if rank_bit_mask == STRAIGHT_BIT_MASKS[0] {
-            Some(true)
-        } else if rank_bit_mask == STRAIGHT_BIT_MASKS[1] {
-            Some(false)
-        } else {
-            None
-        };
-
-        is_straight.then_some(StraightTestReport {
-            high_ace,
-            scored_ranks: ranks,
-        })
-    }
-
-    /// Returns [`ScoringHand`] for played cards.
-    #[expect(
-        clippy::indexing_slicing,
-        reason = "Refactor: Current implementation guarantees index accesses are safe, but this can be refactored."
-    )]
+    /// Returns [`ScoringHand`] for played cards along with the scored ranks in
+    /// descending order of [`Rank`] ordinal. See [`HandEvaluation`].
     pub fn get_scoring_hand(
         cards: &[Card],
     ) -> Result<(Option<ScoringHand>, Vec<Rank>), ScorerError> {
-        let sorted_cards = cards.sorted_by_rank();
-        let suit_groups = sorted_cards.grouped_by_suit()?;
-        let rank_groups = sorted_cards.grouped_by_rank()?;
-        let straight_test_result = Self::test_straight(&sorted_cards);
-
-        if suit_groups.is_empty() || rank_groups.is_empty() {
-            return Ok((None, vec![]));
-        }
-
-        if suit_groups[0].1 == 5 && rank_groups[0].1 == 5 {
-            return Ok((Some(ScoringHand::FlushFive), vec![
-                rank_groups[0].0;
-                rank_groups[0].1
-            ]));
-        }
-
-        if rank_groups.len() >= 2
-            && suit_groups[0].1 == 5
-            && rank_groups[0].1 == 3
-            && rank_groups[1].1 == 2
-        {
-            let mut played_ranks = vec![];
-            played_ranks.append(&mut vec![rank_groups[0].0; rank_groups[0].1]);
-            played_ranks.append(&mut vec![rank_groups[1].0; rank_groups[1].1]);
-            return Ok((Some(ScoringHand::FlushHouse), played_ranks));
-        }
-
-        if rank_groups[0].1 == 5 {
-            return Ok((Some(ScoringHand::FiveOfAKind), vec![
-                rank_groups[0].0;
-                rank_groups[0].1
-            ]));
-        }
-
-        if suit_groups[0].1 == 5 {
-            if let Some(result) = straight_test_result {
-                if result.high_ace.unwrap_or(false) {
-                    return Ok((Some(ScoringHand::RoyalFlush), result.scored_ranks));
-                }
-
-                return Ok((Some(ScoringHand::StraightFlush), result.scored_ranks));
-            }
-        }
-
-        if rank_groups[0].1 == 4 {
-            return Ok((Some(ScoringHand::FourOfAKind), vec![
-                rank_groups[0].0;
-                rank_groups[0].1
-            ]));
-        }
-
-        if rank_groups.len() >= 2 && rank_groups[0].1 == 3 && rank_groups[1].1 == 2 {
-            let mut played_ranks = vec![];
-            played_ranks.append(&mut vec![rank_groups[0].0; rank_groups[0].1]);
-            played_ranks.append(&mut vec![rank_groups[1].0; rank_groups[1].1]);
-            return Ok((Some(ScoringHand::FullHouse), played_ranks));
-        }
-
-        if suit_groups[0].1 == 5 {
-            return Ok((
-                Some(ScoringHand::Flush),
-                cards.iter().map(|card| card.rank).collect(),
-            ));
-        }
-
-        if let Some(result) = straight_test_result {
-            return Ok((Some(ScoringHand::Straight), result.scored_ranks));
-        }
-
-        if rank_groups[0].1 == 3 {
-            return Ok((Some(ScoringHand::ThreeOfAKind), vec![
-                rank_groups[0].0;
-                rank_groups[0].1
-            ]));
-        }
-
-        if rank_groups.len() >= 2 && rank_groups[0].1 == 2 && rank_groups[1].1 == 2 {
-            let mut played_ranks = vec![];
-            played_ranks.append(&mut vec![rank_groups[0].0; rank_groups[0].1]);
-            played_ranks.append(&mut vec![rank_groups[1].0; rank_groups[1].1]);
-            return Ok((Some(ScoringHand::TwoPair), played_ranks));
-        }
-
-        if rank_groups[0].1 == 2 {
-            return Ok((Some(ScoringHand::Pair), vec![
-                rank_groups[0].0;
-                rank_groups[0].1
-            ]));
-        }
-
-        Ok((Some(ScoringHand::HighCard), vec![
-            rank_groups[0].0;
-            rank_groups[0].1
-        ]))
+        let evaluation = HandEvaluation::evaluate(cards);
+        Ok((evaluation.scoring_hand, evaluation.scored_ranks().collect()))
     }
 
     /// Score played cards and return the computed score.
-    pub fn score_cards(cards: &[Card]) -> Result<usize, ScorerError> {
-        let (scoring_hand, scored_ranks) = Self::get_scoring_hand(cards)?;
-        let (base_chips, multiplier) =
-            Self::get_chips_and_multiplier(scoring_hand.ok_or(ScorerError::EmptyHandScoredError)?)?;
-        let chips_increment = Self::score_chips_from_ranks(&scored_ranks)?;
-        Ok((base_chips
-            .checked_add(chips_increment)
-            .ok_or(ArithmeticError::Overflow("addition"))?)
-        .checked_mul(multiplier)
-        .ok_or(ArithmeticError::Overflow("multiplication"))?)
-    }
-
-    /// Return total score from [`Rank`] from cards.
-    #[inline]
-    fn score_chips_from_ranks(ranks: &[Rank]) -> Result<usize, ScorerError> {
-        ranks.iter().try_fold(0, |acc, rank| {
-            let score = rank.get_score()?;
-            Ok::<usize, ScorerError>(
-                score
-                    .checked_add(acc)
-                    .ok_or(ArithmeticError::Overflow("addition"))?,
-            )
-        })
+    pub fn score_cards(cards: &[Card]) -> Result<Score, ScorerError> {
+        HandEvaluation::evaluate(cards).score()
     }
 }
 
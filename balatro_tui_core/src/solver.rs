@@ -0,0 +1,296 @@
+//! This module provides solvers that search for the best moves available in a
+//! round.
+//!
+//! [`best_play()`] enumerates every playable subset of a hand and returns the
+//! highest scoring one as a [`BestPlay`].
+//!
+//! [`advise_discards()`] estimates the probability of beating the blind after
+//! each candidate discard from a [`Position`] using Monte-Carlo simulation
+//! over the remaining deck. See its documentation for the assumptions made by
+//! the simulation.
+//!
+//! ```
+//! # use balatro_tui_core::{card::Card, scorer::ScoringHand, solver::best_play};
+//! let hand = ["2H", "9S", "2C", "KD", "9D"]
+//!     .iter()
+//!     .map(|card| card.parse::<Card>())
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//!
+//! let play = best_play(&hand).unwrap().unwrap();
+//!
+//! assert_eq!(play.scoring_hand, ScoringHand::TwoPair);
+//! assert_eq!(play.indices, vec![0, 1, 2, 4]);
+//! ```
+
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    card::Card, engine::MAXIMUM_SELECTABLE_CARDS, evaluator::HandEvaluation, score::Score,
+    scorer::ScoringHand,
+};
+use crate::error::ScorerError;
+
+/// Highest scoring play available in a hand.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct BestPlay {
+    /// Indices of the cards to play, in ascending order.
+    pub indices: Vec<usize>,
+    /// Cards to play, in the same order as [`Self::indices`].
+    pub cards: Vec<Card>,
+    /// Scoring hand made by the cards.
+    pub scoring_hand: ScoringHand,
+    /// Score gained by playing the cards.
+    pub score: Score,
+}
+
+/// Returns the highest scoring play among all subsets of up to
+/// [`MAXIMUM_SELECTABLE_CARDS`] cards of the hand, or [`None`] if the hand is
+/// empty.
+///
+/// Ties are broken in favour of the play using fewer cards, then the play that
+/// comes first in hand order.
+pub fn best_play(hand: &[Card]) -> Result<Option<BestPlay>, ScorerError> {
+    let mut best: Option<(usize, HandEvaluation, Vec<usize>)> = None;
+    let mut cards = Vec::with_capacity(MAXIMUM_SELECTABLE_CARDS);
+
+    for size in 1..=hand.len().min(MAXIMUM_SELECTABLE_CARDS) {
+        for indices in (0..hand.len()).combinations(size) {
+            cards.clear();
+            cards.extend(indices.iter().filter_map(|&idx| hand.get(idx).copied()));
+            let evaluation = HandEvaluation::evaluate(&cards);
+            let score = evaluation.saturating_score()?;
+
+            if best
+                .as_ref()
+                .is_none_or(|&(best_score, ..)| score > best_score)
+            {
+                best = Some((score, evaluation, indices));
+            }
+        }
+    }
+
+    best.map(|(_, evaluation, indices)| {
+        Ok(BestPlay {
+            cards: indices
+                .iter()
+                .filter_map(|&idx| hand.get(idx).copied())
+                .collect(),
+            indices,
+            scoring_hand: evaluation
+                .scoring_hand
+                .ok_or(ScorerError::EmptyHandScoredError)?,
+            score: evaluation.score()?,
+        })
+    })
+    .transpose()
+}
+
+/// State of a round from which candidate discards are evaluated.
+#[derive(Clone, Copy, Debug)]
+pub struct Position<'position> {
+    /// Cards in hand.
+    pub hand: &'position [Card],
+    /// Cards left in the deck. Their order is ignored, as it is unknown to the
+    /// player.
+    pub deck: &'position [Card],
+    /// Hands left to be played in the round.
+    pub hands_left: usize,
+    /// Discards left in the round.
+    pub discards_left: usize,
+    /// Score accumulated in the round.
+    pub score: &'position Score,
+    /// Target score of the blind.
+    pub target_score: &'position Score,
+}
+
+/// Estimated probability of beating the blind after a candidate discard.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DiscardEstimate {
+    /// Indices of the cards to discard, in ascending order. Empty if the
+    /// estimate is for keeping the hand as it is.
+    pub indices: Vec<usize>,
+    /// Estimated probability of beating the blind, between `0.0` and `1.0`.
+    pub win_probability: f64,
+}
+
+/// Estimates the probability of beating the blind after every candidate
+/// discard and returns the estimates in recommended order.
+///
+/// Candidates are every discard of up to [`MAXIMUM_SELECTABLE_CARDS`] cards
+/// from the hand, and keeping the hand as it is. The estimates are sorted from
+/// the most to the least likely to beat the blind.
+///
+/// Every trial shuffles the remaining deck once and plays out the rest of the
+/// round for every candidate with the same draw order. After the candidate
+/// discard, the simulation greedily plays the [`best_play()`] of the hand for
+/// every hand left and uses no further discards, so the estimates are a lower
+/// bound for a player who keeps discarding.
+///
+/// Ties are broken in favour of keeping the hand, then discarding fewer
+/// cards. Only keeping the hand is estimated if no discards are left.
+pub fn advise_discards<R: Rng>(
+    position: Position<'_>,
+    trials: usize,
+    rng: &mut R,
+) -> Result<Vec<DiscardEstimate>, ScorerError> {
+    let mut candidates = vec![vec![]];
+    if position.discards_left != 0 {
+        for size in 1..=position.hand.len().min(MAXIMUM_SELECTABLE_CARDS) {
+            candidates.extend((0..position.hand.len()).combinations(size));
+        }
+    }
+
+    let mut wins = vec![0_usize; candidates.len()];
+    let mut deck = position.deck.to_vec();
+    for _ in 0..trials {
+        deck.shuffle(rng);
+        for (candidate, candidate_wins) in candidates.iter().zip(wins.iter_mut()) {
+            if simulate_round(position, candidate, &deck)? {
+                *candidate_wins = candidate_wins.saturating_add(1);
+            }
+        }
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "Intended: Trial counts are far below the precision limit of f64."
+    )]
+    let mut estimates = candidates
+        .into_iter()
+        .zip(wins)
+        .map(|(indices, candidate_wins)| DiscardEstimate {
+            indices,
+            win_probability: candidate_wins as f64 / trials.max(1) as f64,
+        })
+        .collect::<Vec<_>>();
+    estimates.sort_by(|first, second| second.win_probability.total_cmp(&first.win_probability));
+
+    Ok(estimates)
+}
+
+/// Plays out the rest of the round after discarding the given cards, drawing
+/// cards in the given order. Returns `true` if the blind is beaten.
+fn simulate_round(
+    position: Position<'_>,
+    discarded: &[usize],
+    draw_order: &[Card],
+) -> Result<bool, ScorerError> {
+    let mut draw = draw_order.iter().copied();
+    let mut hand = remove_indices(position.hand, discarded);
+    hand.extend(draw.by_ref().take(discarded.len()));
+    let mut score = position.score.clone();
+
+    for _ in 0..position.hands_left {
+        if score >= *position.target_score {
+            break;
+        }
+        let Some(play) = best_play(&hand)? else {
+            break;
+        };
+        score += play.score;
+        hand = remove_indices(&hand, &play.indices);
+        hand.extend(draw.by_ref().take(play.indices.len()));
+    }
+
+    Ok(score >= *position.target_score)
+}
+
+/// Returns the cards whose indices are not in the given indices.
+fn remove_indices(cards: &[Card], indices: &[usize]) -> Vec<Card> {
+    cards
+        .iter()
+        .enumerate()
+        .filter(|&(idx, _)| !indices.contains(&idx))
+        .map(|(_, &card)| card)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{card::parse_hand, rng::RunRng, scorer::Scorer};
+
+    #[test]
+    fn best_play_of_empty_hand_is_none() {
+        assert_eq!(best_play(&[]).unwrap(), None);
+    }
+
+    #[test]
+    fn best_play_prefers_flush_over_pairs() {
+        let hand = parse_hand("2H 7H 2S 9H KC JH KS 4H QD QC").unwrap();
+
+        let play = best_play(&hand).unwrap().unwrap();
+
+        assert_eq!(play.scoring_hand, ScoringHand::Flush);
+        assert_eq!(play.indices, vec![0, 1, 3, 5, 7]);
+        assert_eq!(play.score, Scorer::score_cards(&play.cards).unwrap());
+    }
+
+    #[test]
+    fn best_play_skips_kickers() {
+        let hand = parse_hand("AS 3D 8C").unwrap();
+
+        let play = best_play(&hand).unwrap().unwrap();
+
+        assert_eq!(play.scoring_hand, ScoringHand::HighCard);
+        assert_eq!(play.cards, parse_hand("AS").unwrap());
+    }
+
+    #[test]
+    fn advise_keeping_a_winning_hand() {
+        let hand = parse_hand("AS AH AD AC KS").unwrap();
+        let deck = parse_hand("2H 3H 4H 5H 6H").unwrap();
+        let score = Score::zero();
+        let target_score = Score::from(100_usize);
+        let position = Position {
+            hand: &hand,
+            deck: &deck,
+            hands_left: 1,
+            discards_left: 1,
+            score: &score,
+            target_score: &target_score,
+        };
+
+        let estimates = advise_discards(position, 8, &mut RunRng::seed_from_u64(0)).unwrap();
+
+        assert_eq!(estimates.len(), 32);
+        assert_eq!(estimates[0].indices, Vec::<usize>::new());
+        assert!((estimates[0].win_probability - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn advise_discarding_for_a_flush() {
+        let hand = parse_hand("2H 7H 9H JH 4C").unwrap();
+        let deck = parse_hand("KH KH KH KH KH KH").unwrap();
+        let score = Score::zero();
+        let target_score = Score::from(200_usize);
+        let position = Position {
+            hand: &hand,
+            deck: &deck,
+            hands_left: 1,
+            discards_left: 1,
+            score: &score,
+            target_score: &target_score,
+        };
+
+        let estimates = advise_discards(position, 4, &mut RunRng::seed_from_u64(0)).unwrap();
+
+        assert_eq!(estimates[0].indices, vec![4]);
+        assert!((estimates[0].win_probability - 1.0).abs() < f64::EPSILON);
+
+        let no_discards = Position {
+            discards_left: 0,
+            ..position
+        };
+        let kept_estimates =
+            advise_discards(no_discards, 4, &mut RunRng::seed_from_u64(0)).unwrap();
+
+        assert_eq!(kept_estimates.len(), 1);
+        assert!(kept_estimates[0].win_probability.abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,215 @@
+//! This module provides the [`Strategy`] trait for bots playing a game through
+//! the [`Engine`], along with a few built-in strategies.
+//!
+//! A strategy decides one [`Move`] at a time, ie, which cards to play or
+//! discard. [`play_game()`] drives a game to completion with a strategy.
+//!
+//! ```
+//! # use balatro_tui_core::{engine::Engine, run::RunState, strategy::{play_game, GreedyStrategy}};
+//! let mut engine = Engine::new("ABCDEF".to_owned()).unwrap();
+//!
+//! let won = play_game(&mut engine, &mut GreedyStrategy).unwrap();
+//!
+//! assert_eq!(engine.run().run_state, RunState::Finished(won));
+//! ```
+//!
+//! Custom strategies can be implemented by implementing [`Strategy`].
+
+use std::collections::HashMap;
+
+use rand::{seq::index::sample, Rng};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    action::Action,
+    card::Suit,
+    engine::{Engine, Outcome, MAXIMUM_SELECTABLE_CARDS},
+    run::RunState,
+    scorer::ScoringHand,
+};
+use crate::error::CoreError;
+
+/// A single decision of a [`Strategy`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Move {
+    /// Play the cards at the given indices in hand.
+    Play(Vec<usize>),
+    /// Discard the cards at the given indices in hand.
+    Discard(Vec<usize>),
+}
+
+/// Defines a policy for playing a game.
+pub trait Strategy {
+    /// Returns the next [`Move`] for the current state of the engine. The run
+    /// is guaranteed to be running.
+    fn next_move(&mut self, engine: &Engine) -> Result<Move, CoreError>;
+}
+
+/// Applies a [`Move`] by replacing the selection with the cards of the move
+/// and then playing or discarding them. Returns the [`Outcome`] of the play
+/// or discard.
+pub fn apply_move(engine: &mut Engine, next_move: Move) -> Result<Outcome, CoreError> {
+    let (indices, action) = match next_move {
+        Move::Play(indices) => (indices, Action::Play),
+        Move::Discard(indices) => (indices, Action::Discard),
+    };
+
+    let selected = engine.selected().iter().copied().collect::<Vec<_>>();
+    for idx in selected {
+        drop(engine.apply(Action::Deselect(idx))?);
+    }
+    for idx in indices {
+        drop(engine.apply(Action::Select(idx))?);
+    }
+
+    engine.apply(action)
+}
+
+/// Plays the game with the given strategy until the run is over. Returns
+/// `true` if the run is won.
+pub fn play_game<S>(engine: &mut Engine, strategy: &mut S) -> Result<bool, CoreError>
+where
+    S: Strategy + ?Sized,
+{
+    loop {
+        if let RunState::Finished(won) = engine.run().run_state {
+            return Ok(won);
+        }
+        let next_move = strategy.next_move(engine)?;
+        drop(apply_move(engine, next_move)?);
+    }
+}
+
+/// Plays or discards a random selection of cards.
+#[derive(Clone, Debug)]
+pub struct RandomStrategy<R: Rng> {
+    /// Source of randomness for the decisions.
+    rng: R,
+}
+
+impl<R: Rng> RandomStrategy<R> {
+    /// Create a new [`RandomStrategy`] using the given source of randomness.
+    #[must_use = "Created strategy must be used."]
+    #[inline]
+    pub const fn new(rng: R) -> Self {
+        Self { rng }
+    }
+}
+
+impl<R: Rng> Strategy for RandomStrategy<R> {
+    fn next_move(&mut self, engine: &Engine) -> Result<Move, CoreError> {
+        let hand_size = engine.run().round.hand.try_read()?.len();
+        let count = self
+            .rng
+            .gen_range(1..=hand_size.clamp(1, MAXIMUM_SELECTABLE_CARDS));
+        let mut indices = sample(&mut self.rng, hand_size, count.min(hand_size)).into_vec();
+        indices.sort_unstable();
+
+        Ok(
+            if engine.run().round.discards_count != 0 && self.rng.gen_bool(0.5) {
+                Move::Discard(indices)
+            } else {
+                Move::Play(indices)
+            },
+        )
+    }
+}
+
+/// Always plays the highest scoring play in hand and never discards.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GreedyStrategy;
+
+impl Strategy for GreedyStrategy {
+    fn next_move(&mut self, engine: &Engine) -> Result<Move, CoreError> {
+        Ok(Move::Play(
+            engine
+                .best_play()?
+                .map(|play| play.indices)
+                .unwrap_or_default(),
+        ))
+    }
+}
+
+/// Discards cards off the most common suit in hand to draw into a flush, and
+/// otherwise plays the highest scoring play in hand.
+///
+/// Discards are used only while the best play is worse than a
+/// [`ScoringHand::Flush`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FlushStrategy;
+
+impl Strategy for FlushStrategy {
+    fn next_move(&mut self, engine: &Engine) -> Result<Move, CoreError> {
+        let best_play = engine.best_play()?;
+        let has_flush = best_play
+            .as_ref()
+            .is_some_and(|play| play.scoring_hand <= ScoringHand::Flush);
+
+        if !has_flush && engine.run().round.discards_count != 0 {
+            let hand = engine.run().round.hand.try_read()?;
+            let mut suit_counts = HashMap::<Suit, usize>::new();
+            for card in hand.iter() {
+                let count = suit_counts.entry(card.suit).or_default();
+                *count = count.saturating_add(1);
+            }
+            let flush_suit = suit_counts
+                .into_iter()
+                .max_by_key(|&(suit, count)| (count, suit))
+                .map(|(suit, _)| suit);
+            let off_suit = hand
+                .iter()
+                .enumerate()
+                .filter(|&(_, card)| Some(card.suit) != flush_suit)
+                .map(|(idx, _)| idx)
+                .take(MAXIMUM_SELECTABLE_CARDS)
+                .collect::<Vec<_>>();
+            drop(hand);
+
+            if !off_suit.is_empty() {
+                return Ok(Move::Discard(off_suit));
+            }
+        }
+
+        Ok(Move::Play(
+            best_play.map(|play| play.indices).unwrap_or_default(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::seeded_rng;
+
+    #[test]
+    fn strategies_finish_games() {
+        let strategies: [Box<dyn Strategy>; 3] = [
+            Box::new(RandomStrategy::new(seeded_rng("SEED"))),
+            Box::new(GreedyStrategy),
+            Box::new(FlushStrategy),
+        ];
+
+        for mut strategy in strategies {
+            let mut engine = Engine::new("SEED".to_owned()).unwrap();
+            let won = play_game(&mut engine, strategy.as_mut()).unwrap();
+
+            assert_eq!(engine.run().run_state, RunState::Finished(won));
+        }
+    }
+
+    #[test]
+    fn apply_move_replaces_selection() {
+        let mut engine = Engine::new("SEED".to_owned()).unwrap();
+        assert_eq!(
+            engine.apply(Action::Select(0)).unwrap(),
+            Outcome::SelectionChanged
+        );
+
+        assert_eq!(
+            apply_move(&mut engine, Move::Discard(vec![1, 2])).unwrap(),
+            Outcome::HandDiscarded
+        );
+        assert!(engine.selected().is_empty());
+        assert_eq!(engine.replay().actions.len(), 5);
+    }
+}
@@ -0,0 +1,223 @@
+//! This module provides the numeric encodings of observations and actions used
+//! by the environments.
+//!
+//! ## Observation
+//!
+//! An observation is a flat vector of [`f32`] of [`observation_size()`] length
+//! laid out as follows:
+//!
+//! 1. For every slot in hand, [`CARD_FEATURES`] values: a one-hot [`Rank`]
+//!    encoding, a one-hot [`Suit`] encoding and a flag that is set if the card
+//!    is selected. Empty slots and the rank and suit of face down cards are all
+//!    zeros.
+//! 2. Count of unseen cards for every [`Rank`], ie, cards left in the deck
+//!    along with face down cards in hand. See [`Engine::unseen_cards()`].
+//! 3. Count of unseen cards for every [`Suit`].
+//! 4. [`RESOURCE_FEATURES`] values: target score of the blind, score of the
+//!    round, hands left, discards left and money.
+//!
+//! ## Action
+//!
+//! Actions are discrete values in the range `0..action_count()`. Values below
+//! the hand size toggle the selection of the card in that slot, followed by
+//! play and discard.
+
+use balatro_tui_core::{
+    action::Action,
+    card::{Card, Rank, Suit},
+    engine::{Engine, Snapshot},
+    error::CoreError,
+};
+use strum::EnumCount;
+
+/// Number of values encoding a single card slot in hand.
+pub const CARD_FEATURES: usize = Rank::COUNT + Suit::COUNT + 1;
+
+/// Number of values encoding the resources of the round.
+pub const RESOURCE_FEATURES: usize = 5;
+
+/// Returns the length of an observation for the given hand size.
+#[must_use]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Intended: Hand sizes are small enough to never overflow."
+)]
+pub const fn observation_size(hand_size: usize) -> usize {
+    hand_size * CARD_FEATURES + Rank::COUNT + Suit::COUNT + RESOURCE_FEATURES
+}
+
+/// Returns the number of discrete actions for the given hand size.
+#[must_use]
+pub const fn action_count(hand_size: usize) -> usize {
+    hand_size.saturating_add(2)
+}
+
+/// Encodes the state of the engine into an observation.
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Intended: Offsets are bounded by the observation size."
+)]
+pub fn encode_observation(engine: &Engine) -> Result<Vec<f32>, CoreError> {
+    let snapshot = engine.snapshot()?;
+    let hand_size = engine.run().properties.hand_size;
+    let mut observation = vec![0.0; observation_size(hand_size)];
+
+    for (slot, visible_card) in snapshot.hand.iter().take(hand_size).enumerate() {
+        let offset = slot * CARD_FEATURES;
+        if let &Some(card) = visible_card {
+            set(&mut observation, offset + card.rank as usize, 1.0);
+            set(
+                &mut observation,
+                offset + Rank::COUNT + card.suit as usize,
+                1.0,
+            );
+        }
+        if snapshot.selected.contains(&slot) {
+            set(&mut observation, offset + CARD_FEATURES - 1, 1.0);
+        }
+    }
+
+    let deck_offset = hand_size * CARD_FEATURES;
+    for card in engine.unseen_cards()? {
+        increment(&mut observation, deck_offset + card.rank as usize);
+        increment(
+            &mut observation,
+            deck_offset + Rank::COUNT + card.suit as usize,
+        );
+    }
+
+    let resource_offset = deck_offset + Rank::COUNT + Suit::COUNT;
+    for (idx, value) in resources(&snapshot).into_iter().enumerate() {
+        set(&mut observation, resource_offset + idx, value);
+    }
+
+    Ok(observation)
+}
+
+/// Decodes a discrete action into an [`Action`] given the current selection.
+/// Returns [`None`] if the value is out of the action range.
+#[must_use]
+pub fn decode_action(engine: &Engine, value: usize) -> Option<Action> {
+    let hand_size = engine.run().properties.hand_size;
+
+    if value < hand_size {
+        return Some(
+            if engine.selected().contains(&value) {
+                Action::Deselect(value)
+            } else {
+                Action::Select(value)
+            },
+        );
+    }
+
+    match value.checked_sub(hand_size)? {
+        0 => Some(Action::Play),
+        1 => Some(Action::Discard),
+        _ => None,
+    }
+}
+
+/// Returns the mask of legal discrete actions, indexed by action value.
+pub fn action_mask(engine: &Engine) -> Result<Vec<bool>, CoreError> {
+    (0..action_count(engine.run().properties.hand_size))
+        .map(|value| {
+            decode_action(engine, value).map_or(Ok(false), |action| engine.is_legal(action))
+        })
+        .collect()
+}
+
+/// Returns the resource values of a snapshot in observation order.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "Intended: Observations are single precision, large scores may lose precision."
+)]
+fn resources(snapshot: &Snapshot) -> [f32; RESOURCE_FEATURES] {
+    [
+        snapshot.target_score.to_f64() as f32,
+        snapshot.score.to_f64() as f32,
+        snapshot.hands_left as f32,
+        snapshot.discards_left as f32,
+        snapshot.money as f32,
+    ]
+}
+
+/// Sets the value at an index of the observation. No-op if out of bounds.
+fn set(observation: &mut [f32], idx: usize, value: f32) {
+    if let Some(slot) = observation.get_mut(idx) {
+        *slot = value;
+    }
+}
+
+/// Increments the value at an index of the observation. No-op if out of
+/// bounds.
+fn increment(observation: &mut [f32], idx: usize) {
+    if let Some(slot) = observation.get_mut(idx) {
+        *slot += 1.0;
+    }
+}
+
+/// Parses card notations into cards.
+pub fn parse_cards(cards: &[String]) -> Result<Vec<Card>, CoreError> {
+    cards.iter().map(|card| Ok(card.parse::<Card>()?)).collect()
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::float_cmp,
+    reason = "Intended: Encoded values are small integers that are exactly representable."
+)]
+mod tests {
+    use balatro_tui_core::engine::{Outcome, STANDARD_HAND_SIZE, STANDARD_MAX_HANDS};
+
+    use super::*;
+
+    #[test]
+    fn observation_encodes_hand_deck_and_resources() {
+        let mut engine = Engine::new("SEED".to_owned()).unwrap();
+        assert_eq!(
+            engine.apply(Action::Select(0)).unwrap(),
+            Outcome::SelectionChanged
+        );
+        let observation = encode_observation(&engine).unwrap();
+        let hand_features = STANDARD_HAND_SIZE * CARD_FEATURES;
+
+        assert_eq!(observation.len(), observation_size(STANDARD_HAND_SIZE));
+        assert_eq!(
+            observation[..hand_features].iter().sum::<f32>(),
+            (STANDARD_HAND_SIZE * 2 + 1) as f32
+        );
+        assert_eq!(observation[CARD_FEATURES - 1], 1.0);
+        assert_eq!(
+            observation[hand_features..hand_features + Rank::COUNT]
+                .iter()
+                .sum::<f32>(),
+            engine.snapshot().unwrap().deck_remaining as f32
+        );
+        assert_eq!(
+            observation[observation.len() - 3],
+            STANDARD_MAX_HANDS as f32
+        );
+    }
+
+    #[test]
+    fn action_mask_matches_legal_actions() {
+        let mut engine = Engine::new("SEED".to_owned()).unwrap();
+        let mask = action_mask(&engine).unwrap();
+
+        assert_eq!(mask.len(), action_count(STANDARD_HAND_SIZE));
+        assert!(mask[..STANDARD_HAND_SIZE].iter().all(|&legal| legal));
+        assert!(!mask[STANDARD_HAND_SIZE], "Play must need a selection");
+
+        assert_eq!(
+            engine.apply(decode_action(&engine, 2).unwrap()).unwrap(),
+            Outcome::SelectionChanged
+        );
+
+        assert_eq!(decode_action(&engine, 2), Some(Action::Deselect(2)));
+        assert!(action_mask(&engine).unwrap()[STANDARD_HAND_SIZE]);
+        assert_eq!(
+            decode_action(&engine, action_count(STANDARD_HAND_SIZE)),
+            None
+        );
+    }
+}
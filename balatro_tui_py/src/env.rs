@@ -0,0 +1,450 @@
+//! This module provides the gym-style environments wrapping the
+//! [`Engine`].
+//!
+//! [`BalatroEnv`] runs a single game while [`VecBalatroEnv`] runs many games
+//! in lock-step with a single call. Both wrap Python independent environments,
+//! [`Env`] and [`VecEnv`] respectively. See [`super::encoding`] for the layout
+//! of observations and actions.
+
+use balatro_tui_core::{
+    engine::Engine,
+    error::{CoreError, ScorerError},
+    rng::random_seed,
+    run::RunState,
+};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
+use thiserror::Error;
+
+use super::encoding::{
+    action_count, action_mask, decode_action, encode_observation, observation_size,
+};
+
+/// Defines errors raised by the environments.
+#[derive(Clone, Debug, Error)]
+pub enum EnvError {
+    /// Signifies that a discrete action was out of the action range.
+    #[error("Action {0} is out of the action range")]
+    ActionOutOfRange(usize),
+
+    /// Signifies that a vectorised step was given a different number of
+    /// actions than the number of environments.
+    #[error("Expected {expected} actions, got {actual}")]
+    ActionCountMismatch {
+        /// Number of environments.
+        expected: usize,
+        /// Number of actions given.
+        actual: usize,
+    },
+
+    /// Provides conversion from [`CoreError`] to [`EnvError`].
+    #[error("Error occurred in engine: {0}")]
+    CoreError(#[from] CoreError),
+
+    /// Provides conversion from [`ScorerError`] to [`EnvError`].
+    #[error("Error occurred in scorer: {0}")]
+    ScorerError(#[from] ScorerError),
+}
+
+impl From<EnvError> for PyErr {
+    #[inline]
+    fn from(source: EnvError) -> Self {
+        PyValueError::new_err(source.to_string())
+    }
+}
+
+/// Result of a single environment step.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    /// Observation after the step.
+    pub observation: Vec<f32>,
+    /// Score gained by the step, relative to the target score of the blind.
+    pub reward: f32,
+    /// Whether the run is over.
+    pub terminated: bool,
+    /// Whether the run was won, if it is over.
+    pub won: Option<bool>,
+}
+
+/// Gym-style environment over a single game, independent of Python.
+#[derive(Clone, Debug)]
+pub struct Env {
+    /// Engine running the current game.
+    engine: Engine,
+}
+
+impl Env {
+    /// Create an environment with a new game.
+    pub fn new(seed: Option<String>) -> Result<Self, EnvError> {
+        Ok(Self {
+            engine: Engine::new(seed.unwrap_or_else(random_seed))?,
+        })
+    }
+
+    /// Start a new game and return its first observation.
+    pub fn reset(&mut self, seed: Option<String>) -> Result<Vec<f32>, EnvError> {
+        *self = Self::new(seed)?;
+        Ok(encode_observation(&self.engine)?)
+    }
+
+    /// Apply a discrete action. See [`super::encoding`].
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "Intended: Rewards are single precision."
+    )]
+    pub fn step(&mut self, value: usize) -> Result<Step, EnvError> {
+        let action = decode_action(&self.engine, value).ok_or(EnvError::ActionOutOfRange(value))?;
+        let last_score = self.engine.run().round.score.clone();
+
+        drop(self.engine.apply(action)?);
+
+        let round = &self.engine.run().round;
+        let target_score = round.get_target_score()?;
+        let reward = round.score.saturating_sub(&last_score).to_f64() / target_score.to_f64();
+        let won = match self.engine.run().run_state {
+            RunState::Running => None,
+            RunState::Finished(won) => Some(won),
+        };
+
+        Ok(Step {
+            observation: encode_observation(&self.engine)?,
+            reward: reward as f32,
+            terminated: won.is_some(),
+            won,
+        })
+    }
+
+    /// Returns the mask of legal discrete actions.
+    pub fn action_mask(&self) -> Result<Vec<bool>, EnvError> {
+        Ok(action_mask(&self.engine)?)
+    }
+
+    /// Returns the engine running the current game.
+    #[must_use]
+    pub const fn engine(&self) -> &Engine {
+        &self.engine
+    }
+}
+
+/// Python result of a single environment step.
+type StepTuple<'py> = (Vec<f32>, f32, bool, bool, Bound<'py, PyDict>);
+
+/// Gym-style environment over a single game.
+///
+/// `step()` returns `(observation, reward, terminated, truncated, info)` where
+/// `info` contains `won` once the run is over.
+#[pyclass(module = "balatro_tui_py")]
+#[derive(Clone, Debug)]
+pub struct BalatroEnv {
+    /// Wrapped environment.
+    env: Env,
+}
+
+#[pymethods]
+impl BalatroEnv {
+    /// Create an environment with a new game.
+    #[new]
+    #[pyo3(signature = (seed = None))]
+    fn new(seed: Option<String>) -> PyResult<Self> {
+        Ok(Self {
+            env: Env::new(seed)?,
+        })
+    }
+
+    /// Length of observations.
+    #[getter]
+    const fn observation_size(&self) -> usize {
+        observation_size(self.env.engine().run().properties.hand_size)
+    }
+
+    /// Number of discrete actions.
+    #[getter]
+    const fn action_count(&self) -> usize {
+        action_count(self.env.engine().run().properties.hand_size)
+    }
+
+    /// Seed of the current game.
+    #[getter]
+    fn seed(&self) -> String {
+        self.env.engine().run().properties.seed.clone()
+    }
+
+    /// Start a new game and return `(observation, info)`.
+    #[pyo3(signature = (seed = None))]
+    fn reset<'py>(
+        &mut self,
+        py: Python<'py>,
+        seed: Option<String>,
+    ) -> PyResult<(Vec<f32>, Bound<'py, PyDict>)> {
+        Ok((self.env.reset(seed)?, PyDict::new(py)))
+    }
+
+    /// Apply a discrete action and return
+    /// `(observation, reward, terminated, truncated, info)`.
+    fn step<'py>(&mut self, py: Python<'py>, action: usize) -> PyResult<StepTuple<'py>> {
+        let step = self.env.step(action)?;
+        let info = PyDict::new(py);
+        if let Some(won) = step.won {
+            info.set_item("won", won)?;
+        }
+        Ok((step.observation, step.reward, step.terminated, false, info))
+    }
+
+    /// Returns the mask of legal discrete actions.
+    fn action_mask(&self) -> PyResult<Vec<bool>> {
+        self.env.action_mask().map_err(PyErr::from)
+    }
+}
+
+/// Result of a vectorised step.
+type VecStep = (
+    Vec<Vec<f32>>,
+    Vec<f32>,
+    Vec<bool>,
+    Vec<bool>,
+    Vec<Option<bool>>,
+);
+
+/// Gym-style environment over many games run in lock-step, independent of
+/// Python.
+///
+/// Games that are over are automatically reset with a random seed on the next
+/// [`VecEnv::step()`].
+#[derive(Clone, Debug)]
+pub struct VecEnv {
+    /// Wrapped environments.
+    envs: Vec<Env>,
+    /// Whether the environment at the same index needs a reset.
+    finished: Vec<bool>,
+}
+
+impl VecEnv {
+    /// Create `num_envs` environments with new games.
+    pub fn new(num_envs: usize) -> Result<Self, EnvError> {
+        Ok(Self {
+            envs: (0..num_envs)
+                .map(|_| Env::new(None))
+                .collect::<Result<_, _>>()?,
+            finished: vec![false; num_envs],
+        })
+    }
+
+    /// Start new games, optionally seeded with one seed per environment, and
+    /// return their observations.
+    pub fn reset(&mut self, seeds: Option<Vec<String>>) -> Result<Vec<Vec<f32>>, EnvError> {
+        let mut seed_iter = seeds.map(IntoIterator::into_iter);
+        self.finished.fill(false);
+
+        self.envs
+            .iter_mut()
+            .map(|env| env.reset(seed_iter.as_mut().and_then(Iterator::next)))
+            .collect()
+    }
+
+    /// Apply one discrete action per environment. Environments whose game was
+    /// over in the previous step are reset instead, ignoring their action.
+    pub fn step(&mut self, actions: &[usize]) -> Result<VecStep, EnvError> {
+        if actions.len() != self.envs.len() {
+            return Err(EnvError::ActionCountMismatch {
+                expected: self.envs.len(),
+                actual: actions.len(),
+            });
+        }
+
+        let mut result: VecStep = (vec![], vec![], vec![], vec![], vec![]);
+
+        for ((env, done), &action) in self
+            .envs
+            .iter_mut()
+            .zip(self.finished.iter_mut())
+            .zip(actions)
+        {
+            if *done {
+                result.0.push(env.reset(None)?);
+                result.1.push(0.0);
+                result.2.push(false);
+                result.3.push(false);
+                result.4.push(None);
+                *done = false;
+                continue;
+            }
+
+            let step = env.step(action)?;
+            *done = step.terminated;
+            result.0.push(step.observation);
+            result.1.push(step.reward);
+            result.2.push(step.terminated);
+            result.3.push(false);
+            result.4.push(step.won);
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the masks of legal discrete actions for every environment.
+    pub fn action_masks(&self) -> Result<Vec<Vec<bool>>, EnvError> {
+        self.envs.iter().map(Env::action_mask).collect()
+    }
+
+    /// Returns the wrapped environments.
+    #[must_use]
+    pub fn envs(&self) -> &[Env] {
+        &self.envs
+    }
+}
+
+/// Vectorised gym-style environment running many games in lock-step.
+///
+/// Games that are over are automatically reset with a random seed on the next
+/// `step()`. `step()` returns
+/// `(observations, rewards, terminations, truncations, wins)` where `wins`
+/// holds whether a game was won for games that finished in the step and
+/// `None` otherwise.
+#[pyclass(module = "balatro_tui_py")]
+#[derive(Clone, Debug)]
+pub struct VecBalatroEnv {
+    /// Wrapped environment.
+    env: VecEnv,
+}
+
+#[pymethods]
+impl VecBalatroEnv {
+    /// Create `num_envs` environments with new games.
+    #[new]
+    fn new(num_envs: usize) -> PyResult<Self> {
+        Ok(Self {
+            env: VecEnv::new(num_envs)?,
+        })
+    }
+
+    /// Number of environments.
+    #[getter]
+    fn num_envs(&self) -> usize {
+        self.env.envs().len()
+    }
+
+    /// Start new games, optionally seeded with one seed per environment, and
+    /// return their observations.
+    #[pyo3(signature = (seeds = None))]
+    fn reset(&mut self, py: Python<'_>, seeds: Option<Vec<String>>) -> PyResult<Vec<Vec<f32>>> {
+        let env = &mut self.env;
+        py.allow_threads(|| env.reset(seeds)).map_err(PyErr::from)
+    }
+
+    /// Apply one discrete action per environment.
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "False positive: Python arguments are extracted by value."
+    )]
+    fn step(&mut self, py: Python<'_>, actions: Vec<usize>) -> PyResult<VecStep> {
+        let env = &mut self.env;
+        py.allow_threads(|| env.step(&actions)).map_err(PyErr::from)
+    }
+
+    /// Returns the masks of legal discrete actions for every environment.
+    fn action_masks(&self) -> PyResult<Vec<Vec<bool>>> {
+        self.env.action_masks().map_err(PyErr::from)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::float_cmp,
+    reason = "Intended: Rewards without score gain are exactly zero."
+)]
+mod tests {
+    use balatro_tui_core::engine::{STANDARD_HAND_SIZE, STANDARD_MAX_HANDS};
+
+    use super::*;
+
+    /// Discrete action playing the selected cards.
+    const PLAY: usize = STANDARD_HAND_SIZE;
+
+    #[test]
+    fn step_rewards_score_relative_to_target_until_terminated() {
+        let mut env = Env::new(Some("SEED".to_owned())).unwrap();
+
+        for hand in 1..=STANDARD_MAX_HANDS {
+            let select = env.step(0).unwrap();
+
+            assert_eq!(select.reward, 0.0);
+            assert!(!select.terminated);
+
+            let last_score = env.engine().run().round.score.clone();
+            let play = env.step(PLAY).unwrap();
+            let round = &env.engine().run().round;
+            let expected_reward = round.score.saturating_sub(&last_score).to_f64()
+                / round
+                    .blind
+                    .get_target_score(round.properties.ante)
+                    .unwrap()
+                    .to_f64();
+
+            assert!(play.reward > 0.0, "Playing a card must score");
+            assert!((f64::from(play.reward) - expected_reward).abs() < 1e-6_f64);
+            assert_eq!(play.terminated, hand == STANDARD_MAX_HANDS);
+            assert_eq!(
+                play.won,
+                (hand == STANDARD_MAX_HANDS).then_some(false),
+                "Single cards cannot beat the blind"
+            );
+        }
+
+        assert!(matches!(
+            env.step(PLAY + 2),
+            Err(EnvError::ActionOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn vec_env_resets_finished_games_on_next_step() {
+        let mut vec_env = VecEnv::new(2).unwrap();
+        drop(
+            vec_env
+                .reset(Some(vec!["FIRST".to_owned(), "SECOND".to_owned()]))
+                .unwrap(),
+        );
+
+        let mut terminations = vec![];
+        for _ in 0..STANDARD_MAX_HANDS {
+            terminations.push(vec_env.step(&[0, 0]).unwrap().2);
+            terminations.push(vec_env.step(&[PLAY, 0]).unwrap().2);
+        }
+
+        assert_eq!(terminations.last(), Some(&vec![true, false]));
+        assert!(terminations
+            .iter()
+            .rev()
+            .skip(1)
+            .all(|step| step == &[false, false]));
+
+        let (_, rewards, terminated, _, wins) = vec_env.step(&[0, 0]).unwrap();
+        let reset_engine = vec_env.envs()[0].engine();
+
+        assert_eq!(rewards[0], 0.0);
+        assert_eq!(terminated, [false, false]);
+        assert_eq!(wins, [None, None]);
+        assert_eq!(reset_engine.run().round.hands_count, STANDARD_MAX_HANDS);
+        assert!(
+            reset_engine.selected().is_empty(),
+            "Action must be ignored on reset"
+        );
+        assert_eq!(
+            vec_env.envs()[1].engine().run().properties.seed,
+            "SECOND",
+            "Running games must not be reset"
+        );
+    }
+
+    #[test]
+    fn vec_env_rejects_wrong_action_count() {
+        let mut vec_env = VecEnv::new(2).unwrap();
+
+        assert!(matches!(
+            vec_env.step(&[0]),
+            Err(EnvError::ActionCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+//! Python bindings for the Balatro TUI engine.
+//!
+//! This crate exposes the headless [`balatro_tui_core::engine::Engine`] to
+//! Python as gym-style environments for training agents. The extension module
+//! is built using [maturin](https://www.maturin.rs), ie,
+//! `maturin develop -m balatro_tui_py/Cargo.toml`.
+//!
+//! ```python
+//! from balatro_tui_py import BalatroEnv
+//!
+//! env = BalatroEnv()
+//! observation, info = env.reset(seed="ABCDEF")
+//! terminated = False
+//! while not terminated:
+//!     mask = env.action_mask()
+//!     action = next(action for action, legal in enumerate(mask) if legal)
+//!     observation, reward, terminated, truncated, info = env.step(action)
+//! ```
+
+use balatro_tui_core::scorer::Scorer;
+use pyo3::prelude::*;
+
+pub mod encoding;
+pub mod env;
+
+use env::{BalatroEnv, EnvError, VecBalatroEnv};
+
+/// Scores played cards given in card notation, eg, `["AH", "10S"]`, and
+/// returns the name of the scoring hand along with the score.
+#[pyfunction]
+#[expect(
+    clippy::needless_pass_by_value,
+    reason = "False positive: Python arguments are extracted by value."
+)]
+fn score_hand(cards: Vec<String>) -> PyResult<(String, String)> {
+    let played_cards = encoding::parse_cards(&cards).map_err(EnvError::from)?;
+    let scoring_hand = Scorer::get_scoring_hand(&played_cards)
+        .map_err(EnvError::from)?
+        .0
+        .map(|scoring_hand| scoring_hand.to_string())
+        .unwrap_or_default();
+    let score = Scorer::score_cards(&played_cards).map_err(EnvError::from)?;
+
+    Ok((scoring_hand, score.to_string()))
+}
+
+/// Python module for the Balatro TUI engine.
+#[pymodule]
+fn balatro_tui_py(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<BalatroEnv>()?;
+    module.add_class::<VecBalatroEnv>()?;
+    module.add_function(wrap_pyfunction!(score_hand, module)?)?;
+    Ok(())
+}
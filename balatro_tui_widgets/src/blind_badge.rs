@@ -1,7 +1,9 @@
+//! This module provides [`BlindBadgeWidget`] to depict a blind as a badge.
+
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Stylize},
+    style::{Color, Style, Stylize},
     symbols::Marker,
     text::Line,
     widgets::{
@@ -10,9 +12,14 @@ use ratatui::{
     },
 };
 
+use super::{text_box::TextBoxWidget, theme::Theme};
+
 /// [`Widget`] for depicting [`balatro_tui_core::blind::Blind`] with text
 /// inside.
 ///
+/// The badge is drawn as a circle, or as a box bordered in the color of the
+/// badge in ASCII mode.
+///
 /// Widget construction uses builder pattern which can be started using the
 /// [`Self::new()`] method.
 ///
@@ -29,8 +36,12 @@ use ratatui::{
 /// ```
 #[derive(Clone, Debug, Default)]
 pub struct BlindBadgeWidget {
+    /// Text to be displayed inside the badge.
     content: String,
+    /// Color of the badge.
     color: Color,
+    /// Colors of the badge.
+    theme: Theme,
 }
 
 impl BlindBadgeWidget {
@@ -41,6 +52,7 @@ impl BlindBadgeWidget {
         Self {
             color: Color::White,
             content: String::new(),
+            theme: Theme::CLASSIC,
         }
     }
 
@@ -64,10 +76,34 @@ impl BlindBadgeWidget {
         self.content = content.into();
         self
     }
+
+    /// Update the theme and return the [`BlindBadgeWidget`] instance.
+    #[must_use = "Blind badge widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
 }
 
 impl Widget for BlindBadgeWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.theme.ascii {
+            let lines = self
+                .content
+                .split_whitespace()
+                .map(|text_chunk| {
+                    Line::from(text_chunk.to_owned())
+                        .centered()
+                        .fg(self.theme.highlight)
+                })
+                .collect::<Vec<_>>();
+            TextBoxWidget::new(lines)
+                .border_block(self.theme.block().border_style(Style::new().fg(self.color)))
+                .render(area, buf);
+            return;
+        }
+
         // Prepare variables
         let bound = f64::from(area.height);
 
@@ -85,13 +121,14 @@ impl Widget for BlindBadgeWidget {
                 self.content
                     .split_whitespace()
                     .map(String::from)
-                    .map(|text_chunk| Line::from(text_chunk).centered().yellow())
+                    .map(|text_chunk| Line::from(text_chunk).centered().fg(self.theme.highlight))
                     .rev()
                     .enumerate()
                     .for_each(|(idx, line)| {
                         ctx.print(-1.0, idx as f64, line);
                     });
             })
+            .background_color(self.theme.background)
             .x_bounds([-bound, bound])
             .y_bounds([-bound, bound]);
         canvas.render(area, buf);
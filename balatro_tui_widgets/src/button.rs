@@ -0,0 +1,145 @@
+//! This module provides [`ButtonWidget`] to render a clickable button.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{Paragraph, Widget},
+};
+
+use super::theme::Theme;
+
+/// Height of [`ButtonWidget`], ie, the label along with its borders.
+pub const BUTTON_HEIGHT: u16 = 3;
+
+/// [`Widget`] to show a bordered button with a centered label.
+///
+/// Disabled buttons are dimmed, and hovered buttons are highlighted with a
+/// thick border. Hit-testing clicks is left to the caller, which knows the area
+/// the button was rendered in.
+///
+/// Widget construction uses builder pattern which can be started using the
+/// [`Self::new()`] method.
+///
+/// ```
+/// # use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget};
+/// # use balatro_tui_widgets::ButtonWidget;
+/// let area = Rect::new(0, 0, 12, 3);
+/// let mut buffer = Buffer::empty(area);
+///
+/// ButtonWidget::new()
+///     .label("Play")
+///     .hovered(true)
+///     .render(area, &mut buffer);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ButtonWidget<'widget> {
+    /// Label of the button.
+    label: &'widget str,
+    /// Whether the button cannot be clicked.
+    disabled: bool,
+    /// Whether the mouse is over the button.
+    hovered: bool,
+    /// Colors of the button.
+    theme: Theme,
+}
+
+impl<'widget> ButtonWidget<'widget> {
+    /// Create new instance of [`ButtonWidget`]
+    #[must_use = "Created button widget instance must be used."]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            label: "",
+            disabled: false,
+            hovered: false,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update the label of the button and return the [`ButtonWidget`]
+    /// instance.
+    #[must_use = "Button widget builder returned instance must be used."]
+    #[inline]
+    pub const fn label(mut self, label: &'widget str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Update whether the button is disabled and return the [`ButtonWidget`]
+    /// instance.
+    #[must_use = "Button widget builder returned instance must be used."]
+    #[inline]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Update whether the mouse is over the button and return the
+    /// [`ButtonWidget`] instance.
+    #[must_use = "Button widget builder returned instance must be used."]
+    #[inline]
+    pub const fn hovered(mut self, hovered: bool) -> Self {
+        self.hovered = hovered;
+        self
+    }
+
+    /// Update the theme and return the [`ButtonWidget`] instance.
+    #[must_use = "Button widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Widget for ButtonWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Prepare widgets
+        let (border_set, style) = if self.disabled {
+            (border::ROUNDED, Style::new().fg(self.theme.disabled))
+        } else if self.hovered {
+            (border::THICK, Style::new().bold().fg(self.theme.highlight))
+        } else {
+            (border::ROUNDED, Style::new().bold())
+        };
+
+        // Render widgets
+        Paragraph::new(Line::from(self.label).centered())
+            .style(style)
+            .block(
+                self.theme
+                    .block()
+                    .border_set(self.theme.border_set(border_set)),
+            )
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Cell;
+
+    use super::*;
+
+    #[test]
+    fn renders_label() {
+        let area = Rect::new(0, 0, 12, BUTTON_HEIGHT);
+        let mut buffer = Buffer::empty(area);
+
+        ButtonWidget::new()
+            .label("Discard")
+            .disabled(true)
+            .render(area, &mut buffer);
+
+        let content = buffer
+            .content()
+            .iter()
+            .map(Cell::symbol)
+            .collect::<String>();
+
+        assert!(content.contains("Discard"));
+    }
+}
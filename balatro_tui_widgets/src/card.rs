@@ -1,23 +1,47 @@
+//! This module provides [`CardWidget`] to render a single card.
+
 use std::default::Default;
 
-use balatro_tui_core::card::Card;
+use balatro_tui_core::card::{Card, Rank};
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Layout, Margin, Rect},
+    layout::{Constraint, Flex, Layout, Margin, Rect},
+    style::Style,
     symbols::border::{self, Set},
     text::Line,
     widgets::{Block, Paragraph, StatefulWidget, Widget},
 };
 
-use super::text_box::TextBoxWidget;
+use super::theme::Theme;
 
 /// Content width for [`CardWidget`].
 pub const CARD_CONTENT_WIDTH: u16 = 12;
 /// Content height for [`CardWidget`].
 pub const CARD_CONTENT_HEIGHT: u16 = 9;
+/// Content width for compact [`CardWidget`].
+pub const CARD_COMPACT_CONTENT_WIDTH: u16 = 6;
+/// Content height for compact [`CardWidget`].
+pub const CARD_COMPACT_CONTENT_HEIGHT: u16 = 4;
+/// Pattern filling the back of a face down [`CardWidget`].
+const CARD_BACK_PATTERN: &str = "\u{2592}";
+/// Pattern filling the back of a face down [`CardWidget`] in ASCII mode.
+const CARD_BACK_ASCII_PATTERN: &str = "#";
+/// Width of the area holding the pips or the art of a [`CardWidget`], between
+/// the rank and suit in its corners.
+const CARD_PIP_AREA_WIDTH: u16 = 5;
+/// Number of rows pips are laid out on.
+const CARD_PIP_ROWS: u16 = 7;
+/// Placeholder for the suit in face card art.
+const ART_SUIT_PLACEHOLDER: char = '*';
 
 /// [`Widget`] to display a [`Card`].
 ///
+/// Cards are colored by their suit as per the [`Theme`]. Like a real card, the
+/// rank and suit are shown in the corners, with as many pips as the rank laid
+/// out in between, or a simple art for aces and face cards. Compact cards, and
+/// cards in areas too small for the pips, only show the rank and suit in the
+/// top-left corner, for rendering on small terminals.
+///
 /// Widget construction uses builder pattern which can be started using the
 /// [`Self::new()`] method.
 ///
@@ -36,11 +60,18 @@ pub const CARD_CONTENT_HEIGHT: u16 = 9;
 /// ```
 ///
 /// A hovered card is represented with border as [`border::THICK`], otherwise
-/// border is set to [`border::ROUNDED`].
+/// border is set to [`border::ROUNDED`]. A face down card hides its rank and
+/// suit behind a pattern.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct CardWidget {
     /// Type of border to display on card
     border_set: Set,
+    /// Whether the card is face down.
+    face_down: bool,
+    /// Whether the card is rendered compact.
+    compact: bool,
+    /// Colors of the card.
+    theme: Theme,
 }
 
 impl CardWidget {
@@ -50,6 +81,9 @@ impl CardWidget {
     pub const fn new() -> Self {
         Self {
             border_set: border::ROUNDED,
+            face_down: false,
+            compact: false,
+            theme: Theme::CLASSIC,
         }
     }
 
@@ -57,7 +91,12 @@ impl CardWidget {
     #[must_use = "Card widget builder returned instance must be used."]
     #[inline]
     pub const fn bordered(border_set: Set) -> Self {
-        Self { border_set }
+        Self {
+            border_set,
+            face_down: false,
+            compact: false,
+            theme: Theme::CLASSIC,
+        }
     }
 
     /// Update the border set of the card and return the [`CardWidget`]
@@ -68,6 +107,32 @@ impl CardWidget {
         self.border_set = border_set;
         self
     }
+
+    /// Update whether the card is face down and return the [`CardWidget`]
+    /// instance.
+    #[must_use = "Card widget builder returned instance must be used."]
+    #[inline]
+    pub const fn face_down(mut self, face_down: bool) -> Self {
+        self.face_down = face_down;
+        self
+    }
+
+    /// Update whether the card is rendered compact and return the
+    /// [`CardWidget`] instance.
+    #[must_use = "Card widget builder returned instance must be used."]
+    #[inline]
+    pub const fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Update the theme of the card and return the [`CardWidget`] instance.
+    #[must_use = "Card widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
 }
 
 impl StatefulWidget for CardWidget {
@@ -75,43 +140,234 @@ impl StatefulWidget for CardWidget {
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         // Prepare areas
+        let (content_width, content_height) = if self.compact {
+            (CARD_COMPACT_CONTENT_WIDTH, CARD_COMPACT_CONTENT_HEIGHT)
+        } else {
+            (CARD_CONTENT_WIDTH, CARD_CONTENT_HEIGHT)
+        };
         let mut inner_area =
-            Layout::vertical([Constraint::Length(CARD_CONTENT_HEIGHT)]).areas::<1>(area)[0];
+            Layout::vertical([Constraint::Length(content_height)]).areas::<1>(area)[0];
         inner_area =
-            Layout::horizontal([Constraint::Length(CARD_CONTENT_WIDTH)]).areas::<1>(inner_area)[0];
-        let [top_area, middle_area, bottom_area] = Layout::vertical([
-            Constraint::Length(2),
-            Constraint::Fill(1),
-            Constraint::Length(2),
-        ])
-        .areas(inner_area.inner(Margin::new(1, 1)));
+            Layout::horizontal([Constraint::Length(content_width)]).areas::<1>(inner_area)[0];
 
         // Render containers
         Block::bordered()
-            .border_set(self.border_set)
+            .border_set(self.theme.border_set(self.border_set))
+            .border_style(Style::new().fg(self.theme.border))
+            .style(
+                if self.face_down {
+                    self.theme.style()
+                } else {
+                    self.theme.style().fg(self.theme.suit_color(state.suit))
+                },
+            )
             .render(inner_area, buf);
 
         // Render widgets
-        Paragraph::new(format!(
-            "{}\r\n{}",
-            state.rank.get_display(),
-            state.suit.get_display()
-        ))
-        .left_aligned()
-        .render(top_area, buf);
-        TextBoxWidget::new([Line::from(format!(
-            "{}{}",
-            state.rank.get_display(),
-            state.suit.get_display()
-        ))
-        .centered()])
-        .render(middle_area, buf);
-        Paragraph::new(format!(
-            "{}\r\n{}",
-            state.suit.get_display(),
-            state.rank.get_display()
-        ))
-        .right_aligned()
-        .render(bottom_area, buf);
+        if self.face_down {
+            let pattern = self
+                .theme
+                .glyph(CARD_BACK_PATTERN, CARD_BACK_ASCII_PATTERN)
+                .repeat(usize::from(inner_area.width));
+            Paragraph::new(vec![Line::from(pattern); usize::from(inner_area.height)])
+                .render(inner_area.inner(Margin::new(1, 1)), buf);
+            return;
+        }
+
+        let content_area = inner_area.inner(Margin::new(1, 1));
+        if self.compact
+            || content_area.width < CARD_PIP_AREA_WIDTH.saturating_add(4)
+            || content_area.height < CARD_PIP_ROWS
+        {
+            Paragraph::new(format!(
+                "{}\r\n{}",
+                state.rank.get_display(),
+                self.theme.suit_symbol(state.suit)
+            ))
+            .left_aligned()
+            .render(content_area, buf);
+            return;
+        }
+
+        let [top_area, _, bottom_area] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(2),
+        ])
+        .areas(content_area);
+        let [pip_area] = Layout::horizontal([Constraint::Length(CARD_PIP_AREA_WIDTH)])
+            .flex(Flex::Center)
+            .areas(content_area);
+        let suit = self.theme.suit_symbol(state.suit);
+
+        Paragraph::new(format!("{}\r\n{suit}", state.rank.get_display()))
+            .left_aligned()
+            .render(top_area, buf);
+        Paragraph::new(format!("{suit}\r\n{}", state.rank.get_display()))
+            .right_aligned()
+            .render(bottom_area, buf);
+        if let Some(pips) = pip_positions(state.rank) {
+            let columns = Layout::horizontal([Constraint::Length(1); 3])
+                .flex(Flex::SpaceBetween)
+                .split(pip_area);
+            let rows = Layout::vertical(vec![Constraint::Length(1); usize::from(CARD_PIP_ROWS)])
+                .flex(Flex::SpaceBetween)
+                .split(pip_area);
+            for &(column, row) in pips {
+                if let (Some(column_area), Some(row_area)) = (columns.get(column), rows.get(row)) {
+                    buf.set_string(column_area.x, row_area.y, &suit, Style::new());
+                }
+            }
+        } else {
+            let art = face_art(state.rank)
+                .iter()
+                .map(|line| Line::from(line.replace(ART_SUIT_PLACEHOLDER, &suit)))
+                .collect::<Vec<_>>();
+            let [art_area] = Layout::vertical([Constraint::Length(
+                u16::try_from(art.len()).unwrap_or(u16::MAX),
+            )])
+            .flex(Flex::Center)
+            .areas(pip_area);
+            Paragraph::new(art).centered().render(art_area, buf);
+        }
+    }
+}
+
+/// Returns the pips of the given rank as `(column, row)` positions on a grid of
+/// three columns and [`CARD_PIP_ROWS`] rows, or [`None`] for aces and face
+/// cards which are drawn with [`face_art()`].
+const fn pip_positions(rank: Rank) -> Option<&'static [(usize, usize)]> {
+    match rank {
+        Rank::Two => Some(&[(1, 0), (1, 6)]),
+        Rank::Three => Some(&[(1, 0), (1, 3), (1, 6)]),
+        Rank::Four => Some(&[(0, 0), (2, 0), (0, 6), (2, 6)]),
+        Rank::Five => Some(&[(0, 0), (2, 0), (1, 3), (0, 6), (2, 6)]),
+        Rank::Six => Some(&[(0, 0), (2, 0), (0, 3), (2, 3), (0, 6), (2, 6)]),
+        Rank::Seven => Some(&[(0, 0), (2, 0), (1, 1), (0, 3), (2, 3), (0, 6), (2, 6)]),
+        Rank::Eight => Some(&[
+            (0, 0),
+            (2, 0),
+            (1, 1),
+            (0, 3),
+            (2, 3),
+            (1, 5),
+            (0, 6),
+            (2, 6),
+        ]),
+        Rank::Nine => Some(&[
+            (0, 0),
+            (2, 0),
+            (0, 2),
+            (2, 2),
+            (1, 3),
+            (0, 4),
+            (2, 4),
+            (0, 6),
+            (2, 6),
+        ]),
+        Rank::Ten => Some(&[
+            (0, 0),
+            (2, 0),
+            (1, 1),
+            (0, 2),
+            (2, 2),
+            (0, 4),
+            (2, 4),
+            (1, 5),
+            (0, 6),
+            (2, 6),
+        ]),
+        Rank::Ace | Rank::Jack | Rank::Queen | Rank::King => None,
+    }
+}
+
+/// Returns the art of aces and face cards, with [`ART_SUIT_PLACEHOLDER`] in
+/// place of the suit. Numbered ranks have no art.
+const fn face_art(rank: Rank) -> &'static [&'static str] {
+    match rank {
+        Rank::Ace => &["  ^  ", " / \\ ", "< * >", " \\ / ", "  v  "],
+        Rank::Jack => &[" ___ ", "|o o|", " \\_/ ", "/ * \\"],
+        Rank::Queen => &[" @@@ ", "|o o|", " \\-/ ", "/ * \\"],
+        Rank::King => &[" WWW ", "|o o|", " \\=/ ", "/ * \\"],
+        Rank::Two
+        | Rank::Three
+        | Rank::Four
+        | Rank::Five
+        | Rank::Six
+        | Rank::Seven
+        | Rank::Eight
+        | Rank::Nine
+        | Rank::Ten => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use balatro_tui_core::card::Suit;
+    use ratatui::buffer::Cell;
+
+    use super::*;
+
+    fn render(card: Card, width: u16) -> String {
+        let area = Rect::new(0, 0, width, CARD_CONTENT_HEIGHT);
+        let mut buffer = Buffer::empty(area);
+
+        CardWidget::new()
+            .theme(Theme {
+                ascii: true,
+                ..Theme::CLASSIC
+            })
+            .render(area, &mut buffer, &mut card.clone());
+
+        buffer
+            .content()
+            .iter()
+            .map(Cell::symbol)
+            .collect::<String>()
+    }
+
+    #[test]
+    fn renders_pips_matching_rank() {
+        let ranks = [
+            Rank::Two,
+            Rank::Three,
+            Rank::Four,
+            Rank::Five,
+            Rank::Six,
+            Rank::Seven,
+            Rank::Eight,
+            Rank::Nine,
+            Rank::Ten,
+        ];
+        for (rank, pips) in ranks.into_iter().zip(2_usize..) {
+            let content = render(
+                Card {
+                    rank,
+                    suit: Suit::Heart,
+                },
+                CARD_CONTENT_WIDTH,
+            );
+
+            // Pips along with the suits in the corners
+            assert_eq!(
+                content.matches('H').count(),
+                pips.saturating_add(2),
+                "{rank}"
+            );
+        }
+    }
+
+    #[test]
+    fn falls_back_to_compact_face_when_narrow() {
+        let content = render(
+            Card {
+                rank: Rank::King,
+                suit: Suit::Spade,
+            },
+            8,
+        );
+
+        assert_eq!(content.matches('S').count(), 1);
+        assert!(!content.contains("|o o|"));
     }
 }
@@ -1,3 +1,6 @@
+//! This module provides [`CardListWidget`] to render a selectable list of
+//! cards along with its [`CardListWidgetState`].
+
 use std::sync::{Arc, RwLock};
 
 use balatro_tui_core::card::Card;
@@ -5,12 +8,18 @@ use bit_set::BitSet;
 use itertools::Itertools;
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Layout, Offset, Rect},
+    layout::{Constraint, Layout, Offset, Position, Rect},
     symbols::border,
     widgets::StatefulWidget,
 };
 
-use super::CardWidget;
+use super::{theme::Theme, CardWidget};
+
+/// Rows by which selected cards are raised above the list.
+const SELECTED_CARD_OFFSET: i32 = 5;
+
+/// Rows by which selected cards are raised above a compact list.
+const COMPACT_SELECTED_CARD_OFFSET: i32 = 2;
 use crate::error::{ArithmeticError, WidgetError};
 
 /// Provide bidirectional circular cursor for iterators with ability to select
@@ -70,6 +79,9 @@ pub struct CardListWidgetState {
     pub selected: BitSet,
     /// Optional limit defines the maximum cards that can be selected.
     pub selection_limit: Option<usize>,
+    /// Areas of the cards as last rendered by [`CardListWidget`]. Used for
+    /// hit-testing mouse events with [`Self::card_at()`].
+    pub areas: Vec<Rect>,
 }
 
 impl CardListWidgetState {
@@ -97,6 +109,17 @@ impl CardListWidgetState {
         self.cards = cards;
         self.pos = None;
         self.selected.clear();
+        self.areas.clear();
+    }
+
+    /// Returns the index of the card rendered at the given position, if any.
+    ///
+    /// Cards are hit-tested against the areas they were last rendered at, ie,
+    /// selected cards are hit at their raised position.
+    #[must_use = "Hit card index must be used."]
+    #[inline]
+    pub fn card_at(&self, position: Position) -> Option<usize> {
+        self.areas.iter().position(|area| area.contains(position))
     }
 }
 
@@ -107,6 +130,7 @@ impl From<Arc<RwLock<Vec<Card>>>> for CardListWidgetState {
             pos: None,
             selected: BitSet::new(),
             selection_limit: None,
+            areas: vec![],
         }
     }
 }
@@ -214,14 +238,52 @@ impl SelectableList for CardListWidgetState {
 /// CardListWidget::new().render(area, &mut buffer, &mut card_list);
 /// ```
 #[derive(Clone, Copy, Debug, Default)]
-pub struct CardListWidget;
+pub struct CardListWidget {
+    /// Whether the cards are face down.
+    face_down: bool,
+    /// Whether the cards are rendered compact.
+    compact: bool,
+    /// Colors of the cards.
+    theme: Theme,
+}
 
 impl CardListWidget {
     /// Create new instance of [`CardListWidget`].
     #[must_use]
     #[inline]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            face_down: false,
+            compact: false,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update whether the cards are face down and return the
+    /// [`CardListWidget`] instance.
+    #[must_use = "Card list widget builder returned instance must be used."]
+    #[inline]
+    pub const fn face_down(mut self, face_down: bool) -> Self {
+        self.face_down = face_down;
+        self
+    }
+
+    /// Update whether the cards are rendered compact and return the
+    /// [`CardListWidget`] instance.
+    #[must_use = "Card list widget builder returned instance must be used."]
+    #[inline]
+    pub const fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Update the theme of the cards and return the [`CardListWidget`]
+    /// instance.
+    #[must_use = "Card list widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
     }
 }
 
@@ -236,10 +298,18 @@ impl StatefulWidget for CardListWidget {
         )]
         let cards = state.cards.try_read().unwrap();
 
+        // Prepare variables
+        let selected_offset = if self.compact {
+            COMPACT_SELECTED_CARD_OFFSET
+        } else {
+            SELECTED_CARD_OFFSET
+        };
+
         // Prepare areas
         let deck_areas = Layout::horizontal(vec![Constraint::Fill(1); cards.len()]).split(area);
 
         // Render widgets
+        state.areas.clear();
         cards
             .clone()
             .into_iter()
@@ -247,8 +317,12 @@ impl StatefulWidget for CardListWidget {
             .enumerate()
             .for_each(|(idx, (mut card, mut card_area))| {
                 if state.selected.contains(idx) {
-                    card_area = card_area.offset(Offset { x: 0, y: -5 });
+                    card_area = card_area.offset(Offset {
+                        x: 0,
+                        y: selected_offset.saturating_neg(),
+                    });
                 }
+                state.areas.push(card_area);
 
                 CardWidget::bordered(
                     if state.pos == Some(idx) {
@@ -257,7 +331,68 @@ impl StatefulWidget for CardListWidget {
                         border::ROUNDED
                     },
                 )
+                .face_down(self.face_down)
+                .compact(self.compact)
+                .theme(self.theme)
                 .render(card_area, buf, &mut card);
             });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use balatro_tui_core::card::{Rank, Suit};
+    use ratatui::buffer::Cell;
+
+    use super::*;
+
+    #[test]
+    fn hit_tests_rendered_cards() {
+        let area = Rect::new(0, 5, 40, 10);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 40, 15));
+        let mut state = CardListWidgetState::from(Arc::from(RwLock::from(vec![
+            Card {
+                rank: Rank::Ace,
+                suit: Suit::Club,
+            },
+            Card {
+                rank: Rank::Two,
+                suit: Suit::Heart,
+            },
+        ])));
+        assert!(state.selected.insert(1));
+
+        CardListWidget::new().render(area, &mut buffer, &mut state);
+
+        assert_eq!(state.card_at(Position::new(5, 10)), Some(0));
+        assert_eq!(state.card_at(Position::new(25, 0)), Some(1));
+        assert_eq!(state.card_at(Position::new(25, 14)), None);
+    }
+
+    #[test]
+    fn renders_ascii_only() {
+        let area = Rect::new(0, 0, 40, 15);
+        let mut buffer = Buffer::empty(area);
+        let mut state = CardListWidgetState::from(Arc::from(RwLock::from(vec![Card {
+            rank: Rank::Ten,
+            suit: Suit::Heart,
+        }])));
+        state.pos = Some(0);
+
+        CardListWidget::new()
+            .theme(Theme {
+                ascii: true,
+                ..Theme::CLASSIC
+            })
+            .render(area, &mut buffer, &mut state);
+
+        let content = buffer
+            .content()
+            .iter()
+            .map(Cell::symbol)
+            .collect::<String>();
+
+        assert!(content.is_ascii());
+        assert!(content.contains("10"));
+    }
+}
@@ -0,0 +1,156 @@
+//! This module provides [`ChallengeListWidget`] to pick a challenge from a
+//! list of challenges.
+
+use std::collections::BTreeSet;
+
+use balatro_tui_core::challenge::Challenge;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::{Clear, Paragraph, Widget},
+};
+
+use super::theme::Theme;
+
+/// Marker shown next to completed challenges.
+const COMPLETED_MARKER: &str = "\u{2713}";
+/// Marker shown next to completed challenges in ASCII mode.
+const COMPLETED_ASCII_MARKER: &str = "*";
+
+/// [`Widget`] to show a list of challenges along with their descriptions.
+///
+/// The highlighted challenge is marked with a cursor and completed challenges
+/// are marked with a check mark.
+///
+/// Widget construction uses builder pattern which can be started using the
+/// [`Self::new()`] method.
+///
+/// ```
+/// # use std::collections::BTreeSet;
+/// # use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget};
+/// # use balatro_tui_core::challenge::Challenge;
+/// # use balatro_tui_widgets::ChallengeListWidget;
+/// let area = Rect::new(0, 0, 100, 100);
+/// let mut buffer = Buffer::empty(area);
+/// let challenges = vec![Challenge {
+///     id: "one_shot".to_owned(),
+///     name: "One Shot".to_owned(),
+///     description: "A single hand to beat the blind.".to_owned(),
+///     ..Challenge::default()
+/// }];
+/// let completed = BTreeSet::from(["one_shot".to_owned()]);
+///
+/// ChallengeListWidget::new()
+///     .challenges(&challenges)
+///     .completed(&completed)
+///     .highlighted(0)
+///     .render(area, &mut buffer);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChallengeListWidget<'widget> {
+    /// Challenges to be listed.
+    challenges: &'widget [Challenge],
+    /// Identifiers of completed challenges.
+    completed: Option<&'widget BTreeSet<String>>,
+    /// Index of the highlighted challenge.
+    highlighted: usize,
+    /// Colors of the challenge list.
+    theme: Theme,
+}
+
+impl<'widget> ChallengeListWidget<'widget> {
+    /// Create new instance of [`ChallengeListWidget`]
+    #[must_use = "Created challenge list widget instance must be used."]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            challenges: &[],
+            completed: None,
+            highlighted: 0,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update the listed challenges and return the [`ChallengeListWidget`]
+    /// instance.
+    #[must_use = "Challenge list widget builder returned instance must be used."]
+    #[inline]
+    pub const fn challenges(mut self, challenges: &'widget [Challenge]) -> Self {
+        self.challenges = challenges;
+        self
+    }
+
+    /// Update the identifiers of completed challenges and return the
+    /// [`ChallengeListWidget`] instance.
+    #[must_use = "Challenge list widget builder returned instance must be used."]
+    #[inline]
+    pub const fn completed(mut self, completed: &'widget BTreeSet<String>) -> Self {
+        self.completed = Some(completed);
+        self
+    }
+
+    /// Update the index of the highlighted challenge and return the
+    /// [`ChallengeListWidget`] instance.
+    #[must_use = "Challenge list widget builder returned instance must be used."]
+    #[inline]
+    pub const fn highlighted(mut self, highlighted: usize) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
+    /// Update the theme and return the [`ChallengeListWidget`] instance.
+    #[must_use = "Challenge list widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Widget for ChallengeListWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Prepare widgets
+        let lines = self
+            .challenges
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, challenge)| {
+                let cursor = if idx == self.highlighted { "> " } else { "  " };
+                let marker = if self
+                    .completed
+                    .is_some_and(|completed| completed.contains(&challenge.id))
+                {
+                    self.theme.glyph(COMPLETED_MARKER, COMPLETED_ASCII_MARKER)
+                } else {
+                    " "
+                };
+                let name = format!("{cursor}{marker} {}", challenge.name);
+                [
+                    if idx == self.highlighted {
+                        Line::from(name.bold().fg(self.theme.highlight))
+                    } else {
+                        Line::from(name.bold())
+                    },
+                    Line::from(format!("    {}", challenge.description).italic()),
+                    Line::default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        // Prepare areas
+        let [list_area, help_area] = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        // Render widgets
+        Clear.render(area, buf);
+        Paragraph::new(lines)
+            .block(self.theme.block().title("Challenges"))
+            .render(list_area, buf);
+        Line::from("Up/Down: choose, Enter: start, Esc: back")
+            .centered()
+            .render(help_area, buf);
+    }
+}
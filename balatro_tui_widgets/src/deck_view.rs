@@ -0,0 +1,336 @@
+//! This module provides [`DeckViewWidget`] to show the composition of the deck
+//! during a round.
+
+use std::{collections::BTreeSet, iter::once};
+
+use balatro_tui_core::card::{Card, Rank, Suit};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Cell, Clear, Row, Table, Widget},
+};
+
+use super::theme::Theme;
+
+/// Width of the columns of [`DeckViewWidget`] holding a card, eg, `10♥`.
+const CARD_COLUMN_WIDTH: u16 = 3;
+
+/// Width of the columns of [`DeckViewWidget`] holding labels and counts.
+const LABEL_COLUMN_WIDTH: u16 = 5;
+
+/// [`Widget`] to show a suit × rank grid of the full deck of the run.
+///
+/// Cards left in the draw pile are shown in the color of their suit, cards in
+/// hand are highlighted and cards played or discarded this round are dimmed. A
+/// card with copies in more than one place is shown as the first of these that
+/// holds a copy. The cards left in the draw pile are counted per suit, per rank
+/// and in total.
+///
+/// While cards in hand are face down, they are unknown to the player and
+/// expected to be passed along with the draw pile, eg, from
+/// [`balatro_tui_core::engine::Engine::unseen_cards()`]. These are then shown
+/// and counted as unseen cards.
+///
+/// Widget construction uses builder pattern which can be started using the
+/// [`Self::new()`] method.
+///
+/// ```
+/// # use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget};
+/// # use balatro_tui_core::card::{Card, Rank, Suit};
+/// # use balatro_tui_widgets::DeckViewWidget;
+/// let area = Rect::new(0, 0, 100, 100);
+/// let mut buffer = Buffer::empty(area);
+/// let hand = vec![Card {
+///     rank: Rank::Ace,
+///     suit: Suit::Heart,
+/// }];
+/// let remaining = vec![Card {
+///     rank: Rank::King,
+///     suit: Suit::Spade,
+/// }];
+///
+/// DeckViewWidget::new()
+///     .hand(&hand)
+///     .remaining(&remaining)
+///     .render(area, &mut buffer);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DeckViewWidget<'widget> {
+    /// Cards in hand.
+    hand: &'widget [Card],
+    /// Cards played or discarded in the round.
+    history: &'widget [Card],
+    /// Cards left in the draw pile.
+    remaining: &'widget [Card],
+    /// Whether cards in hand are face down.
+    face_down: bool,
+    /// Colors of the deck view.
+    theme: Theme,
+}
+
+impl<'widget> DeckViewWidget<'widget> {
+    /// Create new instance of [`DeckViewWidget`]
+    #[must_use = "Created deck view widget instance must be used."]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            hand: &[],
+            history: &[],
+            remaining: &[],
+            face_down: false,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update the cards in hand and return the [`DeckViewWidget`] instance.
+    #[must_use = "Deck view widget builder returned instance must be used."]
+    #[inline]
+    pub const fn hand(mut self, hand: &'widget [Card]) -> Self {
+        self.hand = hand;
+        self
+    }
+
+    /// Update the cards played or discarded in the round and return the
+    /// [`DeckViewWidget`] instance.
+    #[must_use = "Deck view widget builder returned instance must be used."]
+    #[inline]
+    pub const fn history(mut self, history: &'widget [Card]) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Update the cards left in the draw pile and return the
+    /// [`DeckViewWidget`] instance.
+    #[must_use = "Deck view widget builder returned instance must be used."]
+    #[inline]
+    pub const fn remaining(mut self, remaining: &'widget [Card]) -> Self {
+        self.remaining = remaining;
+        self
+    }
+
+    /// Update whether cards in hand are face down and return the
+    /// [`DeckViewWidget`] instance.
+    #[must_use = "Deck view widget builder returned instance must be used."]
+    #[inline]
+    pub const fn face_down(mut self, face_down: bool) -> Self {
+        self.face_down = face_down;
+        self
+    }
+
+    /// Update the theme and return the [`DeckViewWidget`] instance.
+    #[must_use = "Deck view widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Returns the number of cards left in the draw pile matching the
+    /// predicate.
+    fn count_remaining<P: Fn(&Card) -> bool>(&self, predicate: P) -> usize {
+        self.remaining.iter().filter(|card| predicate(card)).count()
+    }
+
+    /// Returns the cell of the card of the given rank and suit, styled as per
+    /// where its copies are.
+    fn card_cell(&self, rank: Rank, suit: Suit) -> Cell<'widget> {
+        let card = Card { rank, suit };
+        let label = format!("{}{}", rank.get_display(), self.theme.suit_symbol(suit));
+        let style = if self.remaining.contains(&card) {
+            Style::new().bold().fg(self.theme.suit_color(suit))
+        } else if self.hand.contains(&card) {
+            Style::new().reversed().fg(self.theme.highlight)
+        } else if self.history.contains(&card) {
+            Style::new().crossed_out().fg(self.theme.disabled)
+        } else {
+            return Cell::default();
+        };
+
+        Cell::from(label).style(style)
+    }
+}
+
+impl Widget for DeckViewWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Prepare variables
+        let cards = self
+            .hand
+            .iter()
+            .chain(self.history)
+            .chain(self.remaining)
+            .collect::<Vec<_>>();
+        let ranks = cards.iter().map(|card| card.rank).collect::<BTreeSet<_>>();
+        let suits = cards.iter().map(|card| card.suit).collect::<BTreeSet<_>>();
+
+        // Prepare widgets
+        let header = Row::new(
+            once(Cell::default())
+                .chain(
+                    ranks
+                        .iter()
+                        .rev()
+                        .map(|rank| Cell::from(rank.get_display())),
+                )
+                .chain([Cell::from("Left")]),
+        )
+        .bold();
+        let suit_rows = suits.iter().map(|&suit| {
+            Row::new(
+                once(Cell::from(self.theme.suit_symbol(suit)).fg(self.theme.suit_color(suit)))
+                    .chain(ranks.iter().rev().map(|&rank| self.card_cell(rank, suit)))
+                    .chain([Cell::from(
+                        self.count_remaining(|card| card.suit == suit).to_string(),
+                    )]),
+            )
+        });
+        let footer = Row::new(
+            once(Cell::from("Left"))
+                .chain(ranks.iter().rev().map(|&rank| {
+                    Cell::from(self.count_remaining(|card| card.rank == rank).to_string())
+                }))
+                .chain([Cell::from(self.remaining.len().to_string())]),
+        )
+        .bold();
+        let widths = once(Constraint::Length(LABEL_COLUMN_WIDTH))
+            .chain(ranks.iter().map(|_| Constraint::Length(CARD_COLUMN_WIDTH)))
+            .chain([Constraint::Length(LABEL_COLUMN_WIDTH)]);
+        let table = Table::new(suit_rows, widths).header(header).footer(footer);
+        let played = Span::from("Played or discarded")
+            .crossed_out()
+            .fg(self.theme.disabled);
+        let (legend_line, summary_text) = if self.face_down {
+            (
+                Line::from(vec![Span::from("Unseen").bold(), "  ".into(), played]),
+                format!(
+                    "{} of {} cards unseen, including the cards in hand",
+                    self.remaining.len(),
+                    cards.len()
+                ),
+            )
+        } else {
+            (
+                Line::from(vec![
+                    Span::from("Draw pile").bold(),
+                    "  ".into(),
+                    Span::from("In hand").reversed().fg(self.theme.highlight),
+                    "  ".into(),
+                    played,
+                ]),
+                format!(
+                    "{} of {} cards left in the draw pile",
+                    self.remaining.len(),
+                    cards.len()
+                ),
+            )
+        };
+        let legend = legend_line.centered();
+        let summary = Line::from(summary_text).centered();
+
+        // Prepare areas
+        let block = self.theme.block().title("Deck");
+        let [table_area, _, legend_area, summary_area] = Layout::vertical([
+            Constraint::Length(
+                u16::try_from(suits.len())
+                    .unwrap_or(u16::MAX)
+                    .saturating_add(2),
+            ),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(block.inner(area));
+
+        // Render widgets
+        Clear.render(area, buf);
+        block.render(area, buf);
+        Widget::render(table, table_area, buf);
+        legend.render(legend_area, buf);
+        summary.render(summary_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Cell as BufferCell;
+
+    use super::*;
+
+    #[test]
+    fn counts_remaining_cards() {
+        let area = Rect::new(0, 0, 70, 12);
+        let mut buffer = Buffer::empty(area);
+        let hand = vec![Card {
+            rank: Rank::Ace,
+            suit: Suit::Heart,
+        }];
+        let history = vec![Card {
+            rank: Rank::Two,
+            suit: Suit::Club,
+        }];
+        let remaining = vec![
+            Card {
+                rank: Rank::King,
+                suit: Suit::Heart,
+            },
+            Card {
+                rank: Rank::Two,
+                suit: Suit::Heart,
+            },
+        ];
+
+        DeckViewWidget::new()
+            .hand(&hand)
+            .history(&history)
+            .remaining(&remaining)
+            .render(area, &mut buffer);
+
+        let content = buffer
+            .content()
+            .iter()
+            .map(BufferCell::symbol)
+            .collect::<String>();
+
+        assert!(content.contains("2 of 4 cards left in the draw pile"));
+    }
+
+    #[test]
+    fn counts_face_down_hand_as_unseen() {
+        let area = Rect::new(0, 0, 70, 12);
+        let mut buffer = Buffer::empty(area);
+        let history = vec![Card {
+            rank: Rank::Two,
+            suit: Suit::Club,
+        }];
+        let unseen = vec![
+            Card {
+                rank: Rank::Ace,
+                suit: Suit::Heart,
+            },
+            Card {
+                rank: Rank::King,
+                suit: Suit::Heart,
+            },
+            Card {
+                rank: Rank::Two,
+                suit: Suit::Heart,
+            },
+        ];
+
+        DeckViewWidget::new()
+            .face_down(true)
+            .history(&history)
+            .remaining(&unseen)
+            .render(area, &mut buffer);
+
+        let content = buffer
+            .content()
+            .iter()
+            .map(BufferCell::symbol)
+            .collect::<String>();
+
+        assert!(content.contains("3 of 4 cards unseen"));
+        assert!(!content.contains("In hand"));
+    }
+}
@@ -0,0 +1,161 @@
+//! This module provides [`DiscardAdvisorWidget`] to show the estimated
+//! probability of beating the blind after the best candidate discards.
+
+use balatro_tui_core::{card::Card, solver::DiscardEstimate};
+use itertools::Itertools;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    text::Line,
+    widgets::Widget,
+};
+
+use super::{text_box::TextBoxWidget, theme::Theme};
+
+/// Number of candidate discards listed by [`DiscardAdvisorWidget`].
+pub const DISCARD_ADVISOR_ROWS: usize = 5;
+
+/// Content height for [`DiscardAdvisorWidget`]
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "Intended: Row count is a small constant."
+)]
+pub const DISCARD_ADVISOR_CONTENT_HEIGHT: u16 = DISCARD_ADVISOR_ROWS as u16 + 4;
+
+/// [`Widget`] to show the candidate discards most likely to beat the blind.
+///
+/// Estimates are expected in recommended order, as returned by
+/// [`balatro_tui_core::solver::advise_discards()`]. While estimates are not
+/// available, a placeholder text is shown instead. The number of trials the
+/// estimates are based on is shown in the title, if set.
+///
+/// Widget construction uses builder pattern which can be started using the
+/// [`Self::new()`] method.
+///
+/// ```
+/// # use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget};
+/// # use balatro_tui_core::{card::Card, solver::DiscardEstimate};
+/// # use balatro_tui_widgets::DiscardAdvisorWidget;
+/// let area = Rect::new(0, 0, 100, 100);
+/// let mut buffer = Buffer::empty(area);
+/// let hand = vec!["KS".parse::<Card>().unwrap(), "2H".parse::<Card>().unwrap()];
+/// let estimates = vec![DiscardEstimate {
+///     indices: vec![1],
+///     win_probability: 0.5,
+/// }];
+///
+/// DiscardAdvisorWidget::new()
+///     .hand(&hand)
+///     .estimates(Some(&estimates))
+///     .trials(Some(16))
+///     .render(area, &mut buffer);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DiscardAdvisorWidget<'widget> {
+    /// Cards in hand that the estimate indices refer to.
+    hand: &'widget [Card],
+    /// Estimates of candidate discards in recommended order, if available.
+    estimates: Option<&'widget [DiscardEstimate]>,
+    /// Number of trials the estimates are based on, if known.
+    trials: Option<usize>,
+    /// Colors of the advisor.
+    theme: Theme,
+}
+
+impl<'widget> DiscardAdvisorWidget<'widget> {
+    /// Create new instance of [`DiscardAdvisorWidget`]
+    #[must_use = "Created discard advisor widget instance must be used."]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            hand: &[],
+            estimates: None,
+            trials: None,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update the cards in hand and return the [`DiscardAdvisorWidget`]
+    /// instance.
+    #[must_use = "Discard advisor widget builder returned instance must be used."]
+    #[inline]
+    pub const fn hand(mut self, hand: &'widget [Card]) -> Self {
+        self.hand = hand;
+        self
+    }
+
+    /// Update the estimates of candidate discards and return the
+    /// [`DiscardAdvisorWidget`] instance.
+    #[must_use = "Discard advisor widget builder returned instance must be used."]
+    #[inline]
+    pub const fn estimates(mut self, estimates: Option<&'widget [DiscardEstimate]>) -> Self {
+        self.estimates = estimates;
+        self
+    }
+
+    /// Update the number of trials the estimates are based on and return the
+    /// [`DiscardAdvisorWidget`] instance.
+    #[must_use = "Discard advisor widget builder returned instance must be used."]
+    #[inline]
+    pub const fn trials(mut self, trials: Option<usize>) -> Self {
+        self.trials = trials;
+        self
+    }
+
+    /// Update the theme and return the [`DiscardAdvisorWidget`] instance.
+    #[must_use = "Discard advisor widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Widget for DiscardAdvisorWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Prepare widgets
+        let content = self.estimates.map_or_else(
+            || vec![Line::from("Estimating...").centered()],
+            |estimates| {
+                estimates
+                    .iter()
+                    .take(DISCARD_ADVISOR_ROWS)
+                    .map(|estimate| {
+                        let discard = if estimate.indices.is_empty() {
+                            "Keep hand".to_owned()
+                        } else {
+                            format!(
+                                "Discard {}",
+                                estimate
+                                    .indices
+                                    .iter()
+                                    .filter_map(|&idx| self.hand.get(idx))
+                                    .map(|card| self.theme.card_symbol(card))
+                                    .join(" ")
+                            )
+                        };
+                        Line::from(format!(
+                            "{discard}: {:.0}%",
+                            estimate.win_probability * 100.0_f64
+                        ))
+                        .centered()
+                    })
+                    .collect()
+            },
+        );
+
+        // Prepare areas
+        let [inner_area] = Layout::vertical([Constraint::Length(DISCARD_ADVISOR_CONTENT_HEIGHT)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        // Render widgets
+        TextBoxWidget::new(content)
+            .border_block(self.theme.block())
+            .title(self.trials.map_or_else(
+                || "Discard Advisor".to_owned(),
+                |trials| format!("Discard Advisor ({trials} trials)"),
+            ))
+            .render(inner_area, buf);
+    }
+}
@@ -1,29 +1,42 @@
 //! Widgets and components for displaying elements of `BalatroTUI` on the
 //! terminal.
 
-#![expect(
-    clippy::missing_docs_in_private_items,
-    reason = "Intended: This module's contents are re-exported."
-)]
-
 mod blind_badge;
+mod button;
 mod card;
 mod card_list;
+mod challenge_list;
+mod deck_view;
+mod discard_advisor;
 pub mod error;
+mod menu;
+mod play_hint;
+mod profile_stats;
 mod round_info;
 mod round_score;
+mod run_info;
 mod run_stats;
 mod scorer_preview;
 mod splash_screen;
 mod text_box;
+mod theme;
 mod utility;
 
 pub use blind_badge::*;
+pub use button::*;
 pub use card::*;
 pub use card_list::*;
+pub use challenge_list::*;
+pub use deck_view::*;
+pub use discard_advisor::*;
+pub use menu::*;
+pub use play_hint::*;
+pub use profile_stats::*;
 pub use round_info::*;
 pub use round_score::*;
+pub use run_info::*;
 pub use run_stats::*;
 pub use scorer_preview::*;
 pub use splash_screen::*;
 pub use text_box::*;
+pub use theme::*;
@@ -0,0 +1,204 @@
+//! This module provides [`MenuWidget`] to pick an item from a menu, eg, the
+//! title screen or the pause menu.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Clear, Paragraph, Widget},
+};
+use tui_big_text::{BigText, PixelSize};
+
+use super::theme::Theme;
+
+/// Height of the splash rendered by [`MenuWidget`] with [`PixelSize::Full`].
+const MENU_SPLASH_HEIGHT: u16 = 8;
+
+/// Horizontal padding around the items of [`MenuWidget`], including borders.
+const MENU_PADDING: u16 = 8;
+
+/// [`Widget`] to show a menu of items, one of which is highlighted.
+///
+/// Disabled items are dimmed, and an optional splash is rendered in large text
+/// above the items.
+///
+/// Widget construction uses builder pattern which can be started using the
+/// [`Self::new()`] method.
+///
+/// ```
+/// # use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget};
+/// # use balatro_tui_widgets::MenuWidget;
+/// let area = Rect::new(0, 0, 100, 100);
+/// let mut buffer = Buffer::empty(area);
+/// let items = vec![
+///     "New Run".to_owned(),
+///     "Continue".to_owned(),
+///     "Quit".to_owned(),
+/// ];
+///
+/// MenuWidget::new()
+///     .splash("Balatro")
+///     .title("Menu")
+///     .items(&items)
+///     .disabled(&[1])
+///     .highlighted(0)
+///     .render(area, &mut buffer);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MenuWidget<'widget> {
+    /// Text to be rendered in large text above the menu.
+    splash: &'widget str,
+    /// Title of the menu.
+    title: &'widget str,
+    /// Labels of the items of the menu.
+    items: &'widget [String],
+    /// Indices of the disabled items.
+    disabled: &'widget [usize],
+    /// Index of the highlighted item.
+    highlighted: usize,
+    /// Colors of the menu.
+    theme: Theme,
+}
+
+impl<'widget> MenuWidget<'widget> {
+    /// Create new instance of [`MenuWidget`]
+    #[must_use = "Created menu widget instance must be used."]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            splash: "",
+            title: "",
+            items: &[],
+            disabled: &[],
+            highlighted: 0,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update the splash text and return the [`MenuWidget`] instance.
+    #[must_use = "Menu widget builder returned instance must be used."]
+    #[inline]
+    pub const fn splash(mut self, splash: &'widget str) -> Self {
+        self.splash = splash;
+        self
+    }
+
+    /// Update the title of the menu and return the [`MenuWidget`] instance.
+    #[must_use = "Menu widget builder returned instance must be used."]
+    #[inline]
+    pub const fn title(mut self, title: &'widget str) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Update the labels of the items and return the [`MenuWidget`] instance.
+    #[must_use = "Menu widget builder returned instance must be used."]
+    #[inline]
+    pub const fn items(mut self, items: &'widget [String]) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Update the indices of the disabled items and return the [`MenuWidget`]
+    /// instance.
+    #[must_use = "Menu widget builder returned instance must be used."]
+    #[inline]
+    pub const fn disabled(mut self, disabled: &'widget [usize]) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Update the index of the highlighted item and return the [`MenuWidget`]
+    /// instance.
+    #[must_use = "Menu widget builder returned instance must be used."]
+    #[inline]
+    pub const fn highlighted(mut self, highlighted: usize) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
+    /// Update the theme and return the [`MenuWidget`] instance.
+    #[must_use = "Menu widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Widget for MenuWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Prepare widgets
+        let lines = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| {
+                if self.disabled.contains(&idx) {
+                    Line::from(format!("  {item}").fg(self.theme.disabled))
+                } else if idx == self.highlighted {
+                    Line::from(format!("> {item}").bold().fg(self.theme.highlight))
+                } else {
+                    Line::from(format!("  {item}").bold())
+                }
+            })
+            .collect::<Vec<_>>();
+        let splash_height = if self.splash.is_empty() {
+            0
+        } else if self.theme.ascii {
+            1
+        } else {
+            MENU_SPLASH_HEIGHT
+        };
+        let list_height = u16::try_from(lines.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+        let list_width = lines
+            .iter()
+            .map(Line::width)
+            .chain([self.title.len()])
+            .max()
+            .and_then(|width| u16::try_from(width).ok())
+            .unwrap_or(u16::MAX)
+            .saturating_add(MENU_PADDING);
+
+        // Prepare areas
+        let [splash_area, _, list_area, _, help_area] = Layout::vertical([
+            Constraint::Length(splash_height),
+            Constraint::Length(splash_height.min(1)),
+            Constraint::Length(list_height),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .flex(Flex::Center)
+        .areas(area);
+        let [centered_list_area] = Layout::horizontal([Constraint::Length(list_width)])
+            .flex(Flex::Center)
+            .areas(list_area);
+
+        // Render widgets
+        Clear.render(area, buf);
+        Block::new().style(self.theme.style()).render(area, buf);
+        if !self.splash.is_empty() {
+            if self.theme.ascii {
+                Line::from(self.splash.bold())
+                    .centered()
+                    .render(splash_area, buf);
+            } else {
+                BigText::builder()
+                    .lines([self.splash.bold().into_centered_line()])
+                    .pixel_size(PixelSize::Full)
+                    .centered()
+                    .build()
+                    .render(splash_area, buf);
+            }
+        }
+        Paragraph::new(lines)
+            .block(self.theme.block().title(self.title))
+            .render(centered_list_area, buf);
+        Line::from("Up/Down: choose, Enter: select")
+            .centered()
+            .render(help_area, buf);
+    }
+}
@@ -0,0 +1,111 @@
+//! This module provides [`PlayHintWidget`] to show the best play available in
+//! hand along with its projected score.
+
+use balatro_tui_core::solver::BestPlay;
+use itertools::Itertools;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    text::Line,
+    widgets::Widget,
+};
+
+use super::{text_box::TextBoxWidget, theme::Theme, utility::get_line_with_chips};
+
+/// Content height for [`PlayHintWidget`]
+pub const PLAY_HINT_CONTENT_HEIGHT: u16 = 6;
+
+/// [`Widget`] to show a hint of the best play available in hand.
+///
+/// The hint shows the scoring hand, the cards to play and the score that would
+/// be gained by playing them. If no play is available, a placeholder text is
+/// shown instead.
+///
+/// Widget construction uses builder pattern which can be started using the
+/// [`Self::new()`] method.
+///
+/// ```
+/// # use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget};
+/// # use balatro_tui_core::{card::Card, score::Score, scorer::ScoringHand, solver::BestPlay};
+/// # use balatro_tui_widgets::PlayHintWidget;
+/// let area = Rect::new(0, 0, 100, 100);
+/// let mut buffer = Buffer::empty(area);
+/// let play = BestPlay {
+///     indices: vec![0, 3],
+///     cards: vec!["KS".parse::<Card>().unwrap(), "KH".parse::<Card>().unwrap()],
+///     scoring_hand: ScoringHand::Pair,
+///     score: Score::from(60_usize),
+/// };
+///
+/// PlayHintWidget::new().play(Some(&play)).render(area, &mut buffer);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlayHintWidget<'widget> {
+    /// Best play to be shown, if any.
+    play: Option<&'widget BestPlay>,
+    /// Colors of the hint.
+    theme: Theme,
+}
+
+impl<'widget> PlayHintWidget<'widget> {
+    /// Create new instance of [`PlayHintWidget`]
+    #[must_use = "Created play hint widget instance must be used."]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            play: None,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update the best play to be shown and return the [`PlayHintWidget`]
+    /// instance.
+    #[must_use = "Play hint widget builder returned instance must be used."]
+    #[inline]
+    pub const fn play(mut self, play: Option<&'widget BestPlay>) -> Self {
+        self.play = play;
+        self
+    }
+
+    /// Update the theme and return the [`PlayHintWidget`] instance.
+    #[must_use = "Play hint widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Widget for PlayHintWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Prepare widgets
+        let content = self.play.map_or_else(
+            || vec![Line::from("No play available").centered()],
+            |play| {
+                vec![
+                    Line::from(format!(
+                        "{}: {}",
+                        play.scoring_hand,
+                        play.cards
+                            .iter()
+                            .map(|card| self.theme.card_symbol(card))
+                            .join(" ")
+                    ))
+                    .centered(),
+                    get_line_with_chips(play.score.to_string(), self.theme).centered(),
+                ]
+            },
+        );
+
+        // Prepare areas
+        let [inner_area] = Layout::vertical([Constraint::Length(PLAY_HINT_CONTENT_HEIGHT)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        // Render widgets
+        TextBoxWidget::new(content)
+            .border_block(self.theme.block())
+            .title("Hint")
+            .render(inner_area, buf);
+    }
+}
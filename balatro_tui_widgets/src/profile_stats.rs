@@ -0,0 +1,174 @@
+//! This module provides [`ProfileStatsWidget`] to show the lifetime stats of
+//! the player.
+
+use balatro_tui_core::profile::ProfileStats;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Stylize,
+    text::Line,
+    widgets::{Clear, Paragraph, Widget},
+};
+
+use super::theme::Theme;
+
+/// Placeholder shown for stats that have no value yet.
+const NO_VALUE_TEXT: &str = "-";
+
+/// [`Widget`] to show the lifetime [`ProfileStats`] of the player.
+///
+/// Widget construction uses builder pattern which can be started using the
+/// [`Self::new()`] method.
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// # use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget};
+/// # use balatro_tui_core::profile::ProfileStats;
+/// # use balatro_tui_widgets::ProfileStatsWidget;
+/// let area = Rect::new(0, 0, 100, 100);
+/// let mut buffer = Buffer::empty(area);
+/// let mut stats = ProfileStats::default();
+/// stats.record_run("Standard", false, NonZeroUsize::MIN, 0, None);
+///
+/// ProfileStatsWidget::new()
+///     .stats(&stats)
+///     .render(area, &mut buffer);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProfileStatsWidget<'widget> {
+    /// Stats to be shown.
+    stats: Option<&'widget ProfileStats>,
+    /// Colors of the stats.
+    theme: Theme,
+}
+
+impl<'widget> ProfileStatsWidget<'widget> {
+    /// Create new instance of [`ProfileStatsWidget`]
+    #[must_use = "Created profile stats widget instance must be used."]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            stats: None,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update the stats to be shown and return the [`ProfileStatsWidget`]
+    /// instance.
+    #[must_use = "Profile stats widget builder returned instance must be used."]
+    #[inline]
+    pub const fn stats(mut self, stats: &'widget ProfileStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// Update the theme and return the [`ProfileStatsWidget`] instance.
+    #[must_use = "Profile stats widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Widget for ProfileStatsWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Prepare widgets
+        let mut lines = Vec::new();
+        if let Some(stats) = self.stats {
+            let stat_line = |label: &str, value: String| {
+                Line::from(vec![format!("{label}: ").bold(), value.into()])
+            };
+
+            lines.push(stat_line(
+                "Runs won",
+                format!("{} of {}", stats.runs_won(), stats.runs_played()),
+            ));
+            lines.extend(stats.runs.iter().map(|(deck, record)| {
+                Line::from(format!("  {deck}: {} of {}", record.won, record.played).italic())
+            }));
+            lines.push(Line::default());
+            lines.push(stat_line("Best hand", stats.best_hand_score.to_string()));
+            lines.push(stat_line(
+                "Highest ante",
+                if stats.highest_ante == 0 {
+                    NO_VALUE_TEXT.to_owned()
+                } else {
+                    stats.highest_ante.to_string()
+                },
+            ));
+            lines.push(stat_line(
+                "Most played hand",
+                stats.most_played_hand().map_or_else(
+                    || NO_VALUE_TEXT.to_owned(),
+                    |(scoring_hand, count)| format!("{scoring_hand} ({count} times)"),
+                ),
+            ));
+            lines.push(stat_line("Cards played", stats.cards_played.to_string()));
+            lines.push(stat_line(
+                "Cards discarded",
+                stats.cards_discarded.to_string(),
+            ));
+            lines.push(stat_line(
+                "Money earned",
+                format!("${}", stats.money_earned),
+            ));
+            lines.push(stat_line(
+                "Fastest win",
+                stats.fastest_win.map_or_else(
+                    || NO_VALUE_TEXT.to_owned(),
+                    |fastest| {
+                        let seconds = fastest.as_secs();
+                        format!("{}:{:02}", seconds.div_euclid(60), seconds.rem_euclid(60))
+                    },
+                ),
+            ));
+        }
+
+        // Render widgets
+        Clear.render(area, buf);
+        Paragraph::new(lines)
+            .block(self.theme.block().title("Stats"))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroUsize, time::Duration};
+
+    use balatro_tui_core::{score::Score, scorer::ScoringHand};
+    use ratatui::buffer::Cell;
+
+    use super::*;
+
+    #[test]
+    fn renders_stats() {
+        let area = Rect::new(0, 0, 40, 14);
+        let mut buffer = Buffer::empty(area);
+        let mut stats = ProfileStats::default();
+        stats.record_hand(ScoringHand::Flush, 5, &Score::from(120_usize));
+        stats.record_run(
+            "Standard",
+            true,
+            NonZeroUsize::MIN,
+            4,
+            Some(Duration::from_secs(75)),
+        );
+
+        ProfileStatsWidget::new()
+            .stats(&stats)
+            .render(area, &mut buffer);
+
+        let content = buffer
+            .content()
+            .iter()
+            .map(Cell::symbol)
+            .collect::<String>();
+
+        assert!(content.contains("Runs won: 1 of 1"));
+        assert!(content.contains("Standard: 1 of 1"));
+        assert!(content.contains("Most played hand: Flush (1 times)"));
+        assert!(content.contains("Fastest win: 1:15"));
+    }
+}
@@ -1,12 +1,19 @@
+//! This module provides [`RoundInfoWidget`] to show blind, target score and
+//! reward of the running round.
+
+use balatro_tui_core::score::Score;
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Flex, Layout, Margin, Rect},
     style::{Color, Stylize},
     text::Line,
-    widgets::Widget,
+    widgets::{Paragraph, Widget},
 };
 
-use super::{blind_badge::BlindBadgeWidget, text_box::TextBoxWidget, utility::get_line_with_chips};
+use super::{
+    blind_badge::BlindBadgeWidget, text_box::TextBoxWidget, theme::Theme,
+    utility::get_line_with_chips,
+};
 
 /// Content height for [`RoundInfoWidget`].
 pub const ROUND_INFO_CONTENT_HEIGHT: u16 = 9;
@@ -20,11 +27,14 @@ const KERNING_MULTIPLIER: u16 = 2;
 /// - Target score
 /// - Reward for defeating blind
 ///
+/// In compact mode, the details are condensed into a single line.
+///
 /// Widget construction uses builder pattern which can be started using the
 /// [`Self::new()`] method.
 ///
 /// ```
 /// # use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget, style::Color};
+/// # use balatro_tui_core::score::Score;
 /// # use balatro_tui_widgets::RoundInfoWidget;
 /// let area = Rect::new(0, 0, 100, 100);
 /// let mut buffer = Buffer::empty(area);
@@ -33,7 +43,7 @@ const KERNING_MULTIPLIER: u16 = 2;
 ///     .blind_color(Color::Red)
 ///     .blind_text("Small Blind".to_string())
 ///     .reward(5)
-///     .target_score(500)
+///     .target_score(Score::from(500_usize))
 ///     .render(area, &mut buffer);
 /// ```
 #[derive(Clone, Debug, Default)]
@@ -45,19 +55,25 @@ pub struct RoundInfoWidget {
     /// Reward for clearing the blind
     reward: usize,
     /// Target score required to clear the blind
-    target_score: usize,
+    target_score: Score,
+    /// Whether to render the details in a single line
+    compact: bool,
+    /// Colors of the round info.
+    theme: Theme,
 }
 
 impl RoundInfoWidget {
     /// Create new instance of [`RoundInfoWidget`]
     #[must_use = "Created round info widget instance must be used."]
     #[inline]
-    pub const fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             blind_color: Color::White,
             blind_text: String::new(),
             reward: 0,
-            target_score: 0,
+            target_score: Score::zero(),
+            compact: false,
+            theme: Theme::CLASSIC,
         }
     }
 
@@ -82,7 +98,7 @@ impl RoundInfoWidget {
     /// Update the target score and return the [`RoundInfoWidget`] instance.
     #[must_use = "Round info widget builder returned instance must be used."]
     #[inline]
-    pub const fn target_score(mut self, target_score: usize) -> Self {
+    pub fn target_score(mut self, target_score: Score) -> Self {
         self.target_score = target_score;
         self
     }
@@ -94,17 +110,50 @@ impl RoundInfoWidget {
         self.reward = reward;
         self
     }
+
+    /// Update whether to render the details in a single line and return the
+    /// [`RoundInfoWidget`] instance.
+    #[must_use = "Round info widget builder returned instance must be used."]
+    #[inline]
+    pub const fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Update the theme and return the [`RoundInfoWidget`] instance.
+    #[must_use = "Round info widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
 }
 
 impl Widget for RoundInfoWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.compact {
+            let mut spans = vec![
+                self.blind_text.fg(self.blind_color).bold(),
+                "  Target ".into(),
+            ];
+            spans.extend(get_line_with_chips(self.target_score.to_string(), self.theme).spans);
+            spans.extend([
+                "  Reward ".into(),
+                "$".repeat(self.reward).fg(self.theme.money).bold(),
+            ]);
+            Paragraph::new(Line::from(spans))
+                .style(self.theme.style())
+                .render(area, buf);
+            return;
+        }
+
         // Prepare variables
         let round_info_content = [
             Line::from("Score at least").centered(),
-            get_line_with_chips(self.target_score.to_string(), Color::Red).centered(),
+            get_line_with_chips(self.target_score.to_string(), self.theme).centered(),
             Line::from(vec![
                 "Reward: ".into(),
-                "$".repeat(self.reward).yellow().bold(),
+                "$".repeat(self.reward).fg(self.theme.money).bold(),
             ])
             .centered(),
         ];
@@ -123,8 +172,10 @@ impl Widget for RoundInfoWidget {
         BlindBadgeWidget::new()
             .color(self.blind_color)
             .content(self.blind_text)
+            .theme(self.theme)
             .render(blind_badge_area.inner(Margin::new(2, 1)), buf);
-        TextBoxWidget::bordered(round_info_content)
+        TextBoxWidget::new(round_info_content)
+            .border_block(self.theme.block())
             .flex(Flex::SpaceAround)
             .render(round_info_area, buf);
     }
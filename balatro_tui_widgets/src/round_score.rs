@@ -1,46 +1,86 @@
+//! This module provides [`RoundScoreWidget`] to show the score accumulated in
+//! the running round.
+
+use balatro_tui_core::score::Score;
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Flex, Layout, Rect},
-    style::Color,
     text::Line,
-    widgets::{StatefulWidget, Widget},
+    widgets::{Paragraph, StatefulWidget, Widget},
 };
 
-use super::{text_box::TextBoxWidget, utility::get_line_with_chips};
+use super::{text_box::TextBoxWidget, theme::Theme, utility::get_line_with_chips};
 
 /// Content height for [`RoundScoreWidget`]
 pub const ROUND_SCORE_CONTENT_HEIGHT: u16 = 5;
 
 /// [`Widget`] to show current score in the running round.
 ///
+/// Large scores are formatted in `e` notation as described in [`Score`].
+///
 /// Widget construction uses builder pattern which can be started using the
 /// [`Self::new()`] method.
 ///
 /// ```
 /// # use ratatui::{buffer::Buffer, layout::Rect, prelude::StatefulWidget};
+/// # use balatro_tui_core::score::Score;
 /// # use balatro_tui_widgets::RoundScoreWidget;
 /// let area = Rect::new(0, 0, 100, 100);
 /// let mut buffer = Buffer::empty(area);
-/// let mut score = 2000;
+/// let mut score = Score::from(2000_usize);
 ///
 /// RoundScoreWidget::new().render(area, &mut buffer, &mut score);
 /// ```
 #[derive(Clone, Copy, Debug, Default)]
-pub struct RoundScoreWidget;
+pub struct RoundScoreWidget {
+    /// Whether to render the score in a single line
+    compact: bool,
+    /// Colors of the round score.
+    theme: Theme,
+}
 
 impl RoundScoreWidget {
     /// Create new instance of [`RoundScoreWidget`]
     #[must_use = "Created round score widget instance must be used."]
     #[inline]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            compact: false,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update whether to render the score in a single line and return the
+    /// [`RoundScoreWidget`] instance.
+    #[must_use = "Round score widget builder returned instance must be used."]
+    #[inline]
+    pub const fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Update the theme and return the [`RoundScoreWidget`] instance.
+    #[must_use = "Round score widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
     }
 }
 
 impl StatefulWidget for RoundScoreWidget {
-    type State = usize;
+    type State = Score;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if self.compact {
+            let mut line = get_line_with_chips(state.to_string(), self.theme);
+            line.spans.insert(0, "Round Score ".into());
+            Paragraph::new(line.right_aligned())
+                .style(self.theme.style())
+                .render(area, buf);
+            return;
+        }
+
         // Prepare widgets
         let round_score_content = [Line::from("Round Score").centered()];
 
@@ -56,7 +96,8 @@ impl StatefulWidget for RoundScoreWidget {
 
         // Render widgets
         TextBoxWidget::new(round_score_content).render(round_score_text_area, buf);
-        TextBoxWidget::bordered([get_line_with_chips(state.to_string(), Color::Red).centered()])
+        TextBoxWidget::new([get_line_with_chips(state.to_string(), self.theme).centered()])
+            .border_block(self.theme.block())
             .render(round_score_value_area, buf);
     }
 }
@@ -0,0 +1,246 @@
+//! This module provides [`RunInfoWidget`] to show the scoring hands and the
+//! blinds of the run.
+
+use balatro_tui_core::{
+    blind::Blind,
+    run::{BlindInfo, HandInfo},
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Cell, Clear, Paragraph, Row, Table, Widget, Wrap},
+};
+
+use super::theme::Theme;
+
+/// Width of the columns of [`RunInfoWidget`] holding counts, eg, the level of
+/// a hand.
+const COUNT_COLUMN_WIDTH: u16 = 6;
+
+/// Width of the column of [`RunInfoWidget`] holding the target score of a
+/// blind.
+const TARGET_COLUMN_WIDTH: u16 = 12;
+
+/// [`Widget`] to show the chips, multiplier, level and number of plays of every
+/// scoring hand in the run, followed by the blinds of the current ante with
+/// their target scores and rewards.
+///
+/// The blind being played is highlighted. When it is a boss blind, the power
+/// of the boss is described below the blinds.
+///
+/// Widget construction uses builder pattern which can be started using the
+/// [`Self::new()`] method.
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// # use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget};
+/// # use balatro_tui_core::{blind::Blind, run::{BlindInfo, HandInfo}, scorer::ScoringHand};
+/// # use balatro_tui_widgets::RunInfoWidget;
+/// let area = Rect::new(0, 0, 100, 100);
+/// let mut buffer = Buffer::empty(area);
+/// let hands = vec![HandInfo {
+///     scoring_hand: ScoringHand::Pair,
+///     chips: 10,
+///     multiplier: 2,
+///     level: NonZeroUsize::MIN,
+///     played: 1,
+/// }];
+/// let blinds = vec![BlindInfo {
+///     blind: Blind::Small,
+///     target_score: 300_usize.into(),
+///     reward: 3,
+/// }];
+///
+/// RunInfoWidget::new()
+///     .hands(&hands)
+///     .blinds(&blinds)
+///     .current_blind(Blind::Small)
+///     .render(area, &mut buffer);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunInfoWidget<'widget> {
+    /// Summaries of the scoring hands.
+    hands: &'widget [HandInfo],
+    /// Summaries of the blinds of the current ante.
+    blinds: &'widget [BlindInfo],
+    /// Blind being played.
+    current_blind: Blind,
+    /// Colors of the run info.
+    theme: Theme,
+}
+
+impl<'widget> RunInfoWidget<'widget> {
+    /// Create new instance of [`RunInfoWidget`]
+    #[must_use = "Created run info widget instance must be used."]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            hands: &[],
+            blinds: &[],
+            current_blind: Blind::Small,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update the scoring hand summaries and return the [`RunInfoWidget`]
+    /// instance.
+    #[must_use = "Run info widget builder returned instance must be used."]
+    #[inline]
+    pub const fn hands(mut self, hands: &'widget [HandInfo]) -> Self {
+        self.hands = hands;
+        self
+    }
+
+    /// Update the blind summaries and return the [`RunInfoWidget`] instance.
+    #[must_use = "Run info widget builder returned instance must be used."]
+    #[inline]
+    pub const fn blinds(mut self, blinds: &'widget [BlindInfo]) -> Self {
+        self.blinds = blinds;
+        self
+    }
+
+    /// Update the blind being played and return the [`RunInfoWidget`]
+    /// instance.
+    #[must_use = "Run info widget builder returned instance must be used."]
+    #[inline]
+    pub const fn current_blind(mut self, current_blind: Blind) -> Self {
+        self.current_blind = current_blind;
+        self
+    }
+
+    /// Update the theme and return the [`RunInfoWidget`] instance.
+    #[must_use = "Run info widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Widget for RunInfoWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Prepare widgets
+        let hand_rows = self.hands.iter().map(|hand_info| {
+            Row::new([
+                Cell::from(hand_info.scoring_hand.to_string()),
+                Cell::from(hand_info.level.to_string()),
+                Cell::from(hand_info.chips.to_string()).fg(self.theme.chips),
+                Cell::from(hand_info.multiplier.to_string()).fg(self.theme.multiplier),
+                Cell::from(hand_info.played.to_string()),
+            ])
+        });
+        let hands_table = Table::new(hand_rows, [
+            Constraint::Fill(1),
+            Constraint::Length(COUNT_COLUMN_WIDTH),
+            Constraint::Length(COUNT_COLUMN_WIDTH),
+            Constraint::Length(COUNT_COLUMN_WIDTH),
+            Constraint::Length(COUNT_COLUMN_WIDTH),
+        ])
+        .header(Row::new(["Hand", "Lvl.", "Chips", "Mult", "Played"]).bold());
+        let blind_rows = self.blinds.iter().map(|blind_info| {
+            let name = match blind_info.blind {
+                Blind::Boss(boss) => boss.to_string(),
+                Blind::Small | Blind::Big => blind_info.blind.to_string(),
+            };
+            let style = if blind_info.blind == self.current_blind {
+                Style::new().reversed()
+            } else {
+                Style::new()
+            };
+            Row::new([
+                Cell::from(name).fg(self.theme.blind_color(blind_info.blind)),
+                Cell::from(blind_info.target_score.to_string()),
+                Cell::from(format!("{}$", blind_info.reward)).fg(self.theme.money),
+            ])
+            .style(style)
+        });
+        let blinds_table = Table::new(blind_rows, [
+            Constraint::Fill(1),
+            Constraint::Length(TARGET_COLUMN_WIDTH),
+            Constraint::Length(COUNT_COLUMN_WIDTH),
+        ])
+        .header(Row::new(["Blind", "Target", "Reward"]).bold());
+        let boss_description = if let Blind::Boss(boss) = self.current_blind {
+            boss.get_description()
+                .map(|description| Line::from(format!("{boss}: {description}")))
+                .unwrap_or_default()
+        } else {
+            Line::default()
+        };
+
+        // Prepare areas
+        let block = self.theme.block().title("Run Info");
+        let [hands_area, _, blinds_area, _, boss_area] = Layout::vertical([
+            Constraint::Length(
+                u16::try_from(self.hands.len())
+                    .unwrap_or(u16::MAX)
+                    .saturating_add(1),
+            ),
+            Constraint::Length(1),
+            Constraint::Length(
+                u16::try_from(self.blinds.len())
+                    .unwrap_or(u16::MAX)
+                    .saturating_add(1),
+            ),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(block.inner(area));
+
+        // Render widgets
+        Clear.render(area, buf);
+        block.render(area, buf);
+        Widget::render(hands_table, hands_area, buf);
+        Widget::render(blinds_table, blinds_area, buf);
+        Paragraph::new(boss_description)
+            .wrap(Wrap { trim: true })
+            .render(boss_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use balatro_tui_core::{blind::Bosses, scorer::ScoringHand};
+    use ratatui::buffer::Cell as BufferCell;
+
+    use super::*;
+
+    #[test]
+    fn describes_current_boss() {
+        let area = Rect::new(0, 0, 60, 12);
+        let mut buffer = Buffer::empty(area);
+        let hands = vec![HandInfo {
+            scoring_hand: ScoringHand::Flush,
+            chips: 35,
+            multiplier: 4,
+            level: NonZeroUsize::MIN,
+            played: 2,
+        }];
+        let boss = Blind::Boss(Bosses::Wall);
+        let blinds = vec![BlindInfo {
+            blind: boss,
+            target_score: 400_usize.into(),
+            reward: 5,
+        }];
+
+        RunInfoWidget::new()
+            .hands(&hands)
+            .blinds(&blinds)
+            .current_blind(boss)
+            .render(area, &mut buffer);
+
+        let content = buffer
+            .content()
+            .iter()
+            .map(BufferCell::symbol)
+            .collect::<String>();
+
+        assert!(content.contains("Flush"));
+        assert!(content.contains("The Wall"));
+        assert!(content.contains("Extra large blind"));
+    }
+}
@@ -1,13 +1,17 @@
+//! This module provides [`RunStatsWidget`] to show resources and progress of
+//! the run.
+
 use std::num::NonZeroUsize;
 
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Flex, Layout, Margin, Rect},
+    style::Stylize,
     text::Line,
-    widgets::{StatefulWidget, Widget},
+    widgets::{Paragraph, StatefulWidget, Widget},
 };
 
-use super::text_box::TextBoxWidget;
+use super::{text_box::TextBoxWidget, theme::Theme};
 
 /// Content height for [`RunStatsWidget`].
 const RUN_STATS_CONTENT_HEIGHT: u16 = 15;
@@ -49,14 +53,39 @@ pub struct RunStatsWidgetState {
 /// RunStatsWidget::new().render(area, &mut buffer, &mut state);
 /// ```
 #[derive(Clone, Copy, Debug, Default)]
-pub struct RunStatsWidget;
+pub struct RunStatsWidget {
+    /// Whether to render the stats in a single line
+    compact: bool,
+    /// Colors of the run stats.
+    theme: Theme,
+}
 
 impl RunStatsWidget {
     /// Create new instance of [`RunStatsWidget`]
     #[must_use = "Created run stats widget instance must be used."]
     #[inline]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            compact: false,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update whether to render the stats in a single line and return the
+    /// [`RunStatsWidget`] instance.
+    #[must_use = "Run stats widget builder returned instance must be used."]
+    #[inline]
+    pub const fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Update the theme and return the [`RunStatsWidget`] instance.
+    #[must_use = "Run stats widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
     }
 }
 
@@ -64,6 +93,16 @@ impl StatefulWidget for RunStatsWidget {
     type State = RunStatsWidgetState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if self.compact {
+            Paragraph::new(format!(
+                "Hands {}  Discards {}  {}$  Ante {}  Round {}",
+                state.hands, state.discards, state.money, state.ante, state.round
+            ))
+            .style(self.theme.style())
+            .render(area, buf);
+            return;
+        }
+
         // Prepare areas
         let [inner_area] = Layout::vertical([Constraint::Length(RUN_STATS_CONTENT_HEIGHT)])
             .flex(Flex::Center)
@@ -80,19 +119,26 @@ impl StatefulWidget for RunStatsWidget {
             Layout::horizontal([Constraint::Fill(1); 2]).areas(round_meta_info_area);
 
         // Render widgets
-        TextBoxWidget::bordered([Line::from(state.hands.to_string()).centered()])
+        TextBoxWidget::new([Line::from(state.hands.to_string()).centered()])
+            .border_block(self.theme.block())
             .title("Hands")
             .render(hands_count_area, buf);
-        TextBoxWidget::bordered([Line::from(state.discards.to_string()).centered()])
+        TextBoxWidget::new([Line::from(state.discards.to_string()).centered()])
+            .border_block(self.theme.block())
             .title("Discards")
             .render(discards_count_area, buf);
-        TextBoxWidget::bordered([Line::from(format!("{}$", state.money)).centered()])
-            .title("Money")
-            .render(money_area, buf);
-        TextBoxWidget::bordered([Line::from(state.ante.to_string()).centered()])
+        TextBoxWidget::new([
+            Line::from(format!("{}$", state.money).fg(self.theme.money)).centered()
+        ])
+        .border_block(self.theme.block())
+        .title("Money")
+        .render(money_area, buf);
+        TextBoxWidget::new([Line::from(state.ante.to_string()).centered()])
+            .border_block(self.theme.block())
             .title("Ante")
             .render(ante_area, buf);
-        TextBoxWidget::bordered([Line::from(state.round.to_string()).centered()])
+        TextBoxWidget::new([Line::from(state.round.to_string()).centered()])
+            .border_block(self.theme.block())
             .title("Round")
             .render(round_number_area, buf);
     }
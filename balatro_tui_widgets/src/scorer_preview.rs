@@ -1,26 +1,35 @@
+//! This module provides [`ScorerPreviewWidget`] to preview the scoring hand
+//! for selected cards.
+
 use std::num::NonZeroUsize;
 
+use balatro_tui_core::score::Score;
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Flex, Layout, Rect},
-    text::Line,
-    widgets::{StatefulWidget, Widget},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Paragraph, StatefulWidget, Widget},
 };
 
-use super::text_box::TextBoxWidget;
+use super::{text_box::TextBoxWidget, theme::Theme};
 
 /// Content height for [`ScorerPreviewWidget`].
 const SCORER_PREVIEW_CONTENT_HEIGHT: u16 = 10;
+/// Sign between chips and multiplier.
+const MULTIPLY_SIGN: &str = "\u{d7}";
+/// Sign between chips and multiplier in ASCII mode.
+const MULTIPLY_ASCII_SIGN: &str = "x";
 
 /// Render state for [`ScorerPreviewWidget`].
 #[derive(Clone, Debug)]
 pub struct ScorerPreviewWidgetState {
     /// Number of chips counted for the scoring hand.
-    pub chips: usize,
+    pub chips: Score,
     /// Level of the scored hand.
     pub level: NonZeroUsize,
     /// Multiplier for the scoring hand.
-    pub multiplier: usize,
+    pub multiplier: Score,
     /// Text content representing the scoring hand. If [`None`],
     /// [`ScorerPreviewWidget`] does not display the scoring hand text.
     pub scoring_hand_text: Option<String>,
@@ -28,20 +37,23 @@ pub struct ScorerPreviewWidgetState {
 
 /// [`Widget`] to show live scorer preview.
 ///
+/// Large chips and multiplier values are formatted in `e` notation as described
+/// in [`Score`].
+///
 /// Widget construction uses builder pattern which can be started using the
 /// [`Self::new()`] method.
 ///
 /// ```
 /// # use std::num::NonZeroUsize;
 /// # use ratatui::{buffer::Buffer, layout::Rect, prelude::StatefulWidget};
-/// # use balatro_tui_core::scorer::ScoringHand;
+/// # use balatro_tui_core::{score::Score, scorer::ScoringHand};
 /// # use balatro_tui_widgets::{ScorerPreviewWidget, ScorerPreviewWidgetState};
 /// let area = Rect::new(0, 0, 100, 100);
 /// let mut buffer = Buffer::empty(area);
 /// let mut cards = ScorerPreviewWidgetState {
-///     chips: 10,
+///     chips: Score::from(10_usize),
 ///     level: NonZeroUsize::new(2).unwrap(),
-///     multiplier: 5,
+///     multiplier: Score::from(5_usize),
 ///     scoring_hand_text: Some(ScoringHand::FourOfAKind.to_string()),
 /// };
 ///
@@ -51,27 +63,53 @@ pub struct ScorerPreviewWidgetState {
 /// ```
 /// # use std::num::NonZeroUsize;
 /// # use ratatui::{buffer::Buffer, layout::Rect, prelude::StatefulWidget};
+/// # use balatro_tui_core::score::Score;
 /// # use balatro_tui_widgets::{ScorerPreviewWidget, ScorerPreviewWidgetState};
 /// let area = Rect::new(0, 0, 100, 100);
 /// let mut buffer = Buffer::empty(area);
 /// let mut cards = ScorerPreviewWidgetState {
-///     chips: 10,
+///     chips: Score::from(10_usize),
 ///     level: NonZeroUsize::new(2).unwrap(),
-///     multiplier: 5,
+///     multiplier: Score::from(5_usize),
 ///     scoring_hand_text: None,
 /// };
 ///
 /// ScorerPreviewWidget::new().render(area, &mut buffer, &mut cards)
 /// ```
 #[derive(Clone, Copy, Debug, Default)]
-pub struct ScorerPreviewWidget;
+pub struct ScorerPreviewWidget {
+    /// Whether to render the preview in a single line
+    compact: bool,
+    /// Colors of the preview.
+    theme: Theme,
+}
 
 impl ScorerPreviewWidget {
     /// Create new instance of [`ScorerPreviewWidget`]
     #[must_use = "Created score preview widget instance must be used."]
     #[inline]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            compact: false,
+            theme: Theme::CLASSIC,
+        }
+    }
+
+    /// Update whether to render the preview in a single line and return the
+    /// [`ScorerPreviewWidget`] instance.
+    #[must_use = "Score preview widget builder returned instance must be used."]
+    #[inline]
+    pub const fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Update the theme and return the [`ScorerPreviewWidget`] instance.
+    #[must_use = "Score preview widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
     }
 }
 
@@ -79,6 +117,25 @@ impl StatefulWidget for ScorerPreviewWidget {
     type State = ScorerPreviewWidgetState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if self.compact {
+            let mut spans = state
+                .scoring_hand_text
+                .as_ref()
+                .map(|hand| Span::from(format!("{hand} [lvl. {}]  ", state.level)))
+                .into_iter()
+                .collect::<Vec<_>>();
+            spans.extend([
+                state.chips.to_string().fg(self.theme.chips),
+                format!(" {} ", self.theme.glyph(MULTIPLY_SIGN, MULTIPLY_ASCII_SIGN)).into(),
+                state.multiplier.to_string().fg(self.theme.multiplier),
+            ]);
+            Paragraph::new(Line::from(spans))
+                .style(self.theme.style())
+                .right_aligned()
+                .render(area, buf);
+            return;
+        }
+
         // Prepare areas
         let [inner_area] = Layout::vertical([Constraint::Length(SCORER_PREVIEW_CONTENT_HEIGHT)])
             .flex(Flex::Center)
@@ -100,11 +157,17 @@ impl StatefulWidget for ScorerPreviewWidget {
                 .constraints([Constraint::Length(1)])
                 .render(scoring_hand_text_area, buf);
         }
-        TextBoxWidget::bordered([Line::from(state.chips.to_string()).centered()])
+        TextBoxWidget::new([Line::from(state.chips.to_string().fg(self.theme.chips)).centered()])
+            .border_block(self.theme.block())
             .render(chips_area, buf);
-        TextBoxWidget::new([Line::from("\u{d7}".to_owned()).centered()])
-            .render(multiply_sign_area, buf);
-        TextBoxWidget::bordered([Line::from(state.multiplier.to_string()).centered()])
-            .render(multiplier_area, buf);
+        TextBoxWidget::new([
+            Line::from(self.theme.glyph(MULTIPLY_SIGN, MULTIPLY_ASCII_SIGN)).centered(),
+        ])
+        .render(multiply_sign_area, buf);
+        TextBoxWidget::new([
+            Line::from(state.multiplier.to_string().fg(self.theme.multiplier)).centered(),
+        ])
+        .border_block(self.theme.block())
+        .render(multiplier_area, buf);
     }
 }
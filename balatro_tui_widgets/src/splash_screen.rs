@@ -1,18 +1,23 @@
+//! This module provides [`SplashScreenWidget`] to display end of game splash
+//! screens.
+
 use std::cmp::max;
 
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Flex, Layout, Rect},
-    style::Stylize,
+    style::{Style, Stylize},
     symbols::border,
     text::Line,
     widgets::{Block, Clear, StatefulWidget, Widget},
 };
 use tui_big_text::{BigText, PixelSize};
 
-use crate::TextBoxWidget;
+use crate::{TextBoxWidget, Theme};
 
+/// Width of a character rendered by [`BigText`] with [`PixelSize::Full`].
 const FULL_PIXEL_WIDTH: usize = 8;
+/// Width of a character rendered by [`BigText`] with [`PixelSize::Quadrant`].
 const QUADRANT_PIXEL_WIDTH: usize = 4;
 
 /// [`Widget`] to display end splash screen.
@@ -40,6 +45,8 @@ pub struct SplashScreenWidget<'widget> {
     splash: &'widget str,
     /// Supporting message text to be displayed on the splash screen.
     message: &'widget str,
+    /// Colors of the splash screen.
+    theme: Theme,
 }
 
 impl<'widget> SplashScreenWidget<'widget> {
@@ -49,7 +56,8 @@ impl<'widget> SplashScreenWidget<'widget> {
     pub const fn new() -> Self {
         Self {
             splash: "",
-            message: ""
+            message: "",
+            theme: Theme::CLASSIC,
         }
     }
 
@@ -68,6 +76,14 @@ impl<'widget> SplashScreenWidget<'widget> {
         self.message = message;
         self
     }
+
+    /// Update the theme and return the [`SplashScreenWidget`] instance.
+    #[must_use = "Splash screen widget builder returned instance must be used."]
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
 }
 
 impl<'widget> StatefulWidget for SplashScreenWidget<'widget> {
@@ -79,23 +95,22 @@ impl<'widget> StatefulWidget for SplashScreenWidget<'widget> {
         let message_line = self.message.italic().into_centered_line();
         let stat_lines = state
             .iter()
-            .map(|&(key, value)| vec![key.bold(), "\t\t".into(), value.yellow()].into())
+            .map(|&(key, value)| {
+                vec![key.bold(), "\t\t".into(), value.fg(self.theme.highlight)].into()
+            })
             .collect::<Vec<Line<'_>>>();
-        let render_big = (area.width as usize)
-            > max(
-                splash_line
-                    .width()
-                    .checked_add(1)
-                    .unwrap_or(usize::MAX)
-                    .checked_mul(FULL_PIXEL_WIDTH)
-                    .unwrap_or(usize::MAX),
-                message_line
-                    .width()
-                    .checked_add(1)
-                    .unwrap_or(usize::MAX)
-                    .checked_mul(QUADRANT_PIXEL_WIDTH)
-                    .unwrap_or(usize::MAX),
-            );
+        let render_big = !self.theme.ascii
+            && (area.width as usize)
+                > max(
+                    splash_line
+                        .width()
+                        .saturating_add(1)
+                        .saturating_mul(FULL_PIXEL_WIDTH),
+                    message_line
+                        .width()
+                        .saturating_add(1)
+                        .saturating_mul(QUADRANT_PIXEL_WIDTH),
+                );
 
         // Prepare areas
         let [splash_area, message_area, mut details_area] = Layout::vertical([
@@ -104,10 +119,8 @@ impl<'widget> StatefulWidget for SplashScreenWidget<'widget> {
             Constraint::Length(
                 stat_lines
                     .len()
-                    .checked_mul(2)
-                    .unwrap_or(usize::MAX)
-                    .checked_add(3)
-                    .unwrap_or(usize::MAX)
+                    .saturating_mul(2)
+                    .saturating_add(3)
                     .try_into()
                     .unwrap_or(u16::MAX),
             ),
@@ -121,20 +134,26 @@ impl<'widget> StatefulWidget for SplashScreenWidget<'widget> {
         // Render widgets
         Clear.render(area, buf);
         Block::bordered()
-            .border_set(border::DOUBLE)
+            .border_set(self.theme.border_set(border::DOUBLE))
+            .border_style(Style::new().fg(self.theme.border))
+            .style(self.theme.style())
             .render(area, buf);
-        BigText::builder()
-            .lines([splash_line])
-            .pixel_size(
-                if render_big {
-                    PixelSize::Full
-                } else {
-                    PixelSize::Quadrant
-                },
-            )
-            .centered()
-            .build()
-            .render(splash_area, buf);
+        if self.theme.ascii {
+            TextBoxWidget::new([splash_line]).render(splash_area, buf);
+        } else {
+            BigText::builder()
+                .lines([splash_line])
+                .pixel_size(
+                    if render_big {
+                        PixelSize::Full
+                    } else {
+                        PixelSize::Quadrant
+                    },
+                )
+                .centered()
+                .build()
+                .render(splash_area, buf);
+        }
 
         if render_big {
             BigText::builder()
@@ -148,7 +167,8 @@ impl<'widget> StatefulWidget for SplashScreenWidget<'widget> {
         }
 
         // TODO: Convert to table
-        TextBoxWidget::bordered(stat_lines)
+        TextBoxWidget::new(stat_lines)
+            .border_block(self.theme.block())
             .padding(4)
             .render(details_area, buf);
     }
@@ -1,3 +1,5 @@
+//! This module provides [`TextBoxWidget`] to render aligned text content.
+
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Flex, Layout, Margin, Rect},
@@ -0,0 +1,254 @@
+//! This module provides [`Theme`], the colors and glyphs shared by all
+//! widgets.
+//!
+//! Built-in themes are provided as associated constants of [`Theme`], ie,
+//! [`Theme::CLASSIC`], [`Theme::FOUR_COLOR`], [`Theme::HIGH_CONTRAST`] and
+//! [`Theme::COLORBLIND`]. Any of them can be rendered using ASCII characters
+//! only by setting [`Theme::ascii`].
+
+use balatro_tui_core::{
+    blind::Blind,
+    card::{Card, Suit},
+};
+use ratatui::{
+    style::{Color, Style},
+    symbols::border::{self, Set},
+    widgets::Block,
+};
+
+/// ASCII replacement of rounded and plain borders.
+const ASCII_BORDER: Set = Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
+/// ASCII replacement of thick and double borders.
+const ASCII_THICK_BORDER: Set = Set {
+    top_left: "#",
+    top_right: "#",
+    bottom_left: "#",
+    bottom_right: "#",
+    vertical_left: "#",
+    vertical_right: "#",
+    horizontal_top: "=",
+    horizontal_bottom: "=",
+};
+
+/// Colors and glyphs used by widgets to render the game.
+///
+/// Every widget accepts a theme using its `theme()` builder method, and
+/// renders with [`Theme::CLASSIC`] by default.
+///
+/// ```
+/// # use ratatui::style::Color;
+/// # use balatro_tui_core::card::Suit;
+/// # use balatro_tui_widgets::Theme;
+/// let theme = Theme {
+///     heart: Color::Magenta,
+///     ..Theme::FOUR_COLOR
+/// };
+///
+/// assert_eq!(theme.suit_color(Suit::Heart), Color::Magenta);
+/// assert_eq!(theme.suit_color(Suit::Club), Color::Green);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Theme {
+    /// Color of club cards.
+    pub club: Color,
+    /// Color of diamond cards.
+    pub diamond: Color,
+    /// Color of heart cards.
+    pub heart: Color,
+    /// Color of spade cards.
+    pub spade: Color,
+    /// Color of chips, including scores shown with a chip icon.
+    pub chips: Color,
+    /// Color of multipliers.
+    pub multiplier: Color,
+    /// Color of money.
+    pub money: Color,
+    /// Color of the small blind.
+    pub small_blind: Color,
+    /// Color of the big blind.
+    pub big_blind: Color,
+    /// Color of boss blinds.
+    pub boss_blind: Color,
+    /// Color of highlighted items, eg, the highlighted menu item.
+    pub highlight: Color,
+    /// Color of disabled items.
+    pub disabled: Color,
+    /// Color of borders.
+    pub border: Color,
+    /// Color of the background.
+    pub background: Color,
+    /// Whether to render using ASCII characters only, for terminals that lack
+    /// suit, chip and box drawing glyphs.
+    pub ascii: bool,
+}
+
+impl Default for Theme {
+    #[inline]
+    fn default() -> Self {
+        Self::CLASSIC
+    }
+}
+
+impl Theme {
+    /// Classic two-color deck on the default colors of the terminal.
+    pub const CLASSIC: Self = Self {
+        club: Color::Reset,
+        diamond: Color::Red,
+        heart: Color::Red,
+        spade: Color::Reset,
+        chips: Color::Blue,
+        multiplier: Color::Red,
+        money: Color::Yellow,
+        small_blind: Color::Blue,
+        big_blind: Color::Green,
+        boss_blind: Color::Red,
+        highlight: Color::Yellow,
+        disabled: Color::DarkGray,
+        border: Color::Reset,
+        background: Color::Reset,
+        ascii: false,
+    };
+    /// Colorblind-safe colors, based on the Okabe-Ito palette, that avoid
+    /// telling suits apart by red and green.
+    pub const COLORBLIND: Self = Self {
+        club: Color::Rgb(86, 180, 233),
+        diamond: Color::Rgb(230, 159, 0),
+        heart: Color::Rgb(213, 94, 0),
+        spade: Color::Reset,
+        chips: Color::Rgb(0, 114, 178),
+        multiplier: Color::Rgb(213, 94, 0),
+        money: Color::Rgb(240, 228, 66),
+        small_blind: Color::Rgb(86, 180, 233),
+        big_blind: Color::Rgb(204, 121, 167),
+        boss_blind: Color::Rgb(213, 94, 0),
+        highlight: Color::Rgb(240, 228, 66),
+        disabled: Color::DarkGray,
+        border: Color::Reset,
+        background: Color::Reset,
+        ascii: false,
+    };
+    /// Four-color deck where every suit has its own color.
+    pub const FOUR_COLOR: Self = Self {
+        club: Color::Green,
+        diamond: Color::Blue,
+        ..Self::CLASSIC
+    };
+    /// Bright colors on a black background.
+    pub const HIGH_CONTRAST: Self = Self {
+        club: Color::White,
+        diamond: Color::LightRed,
+        heart: Color::LightRed,
+        spade: Color::White,
+        chips: Color::LightCyan,
+        multiplier: Color::LightRed,
+        money: Color::LightYellow,
+        small_blind: Color::LightBlue,
+        big_blind: Color::LightGreen,
+        boss_blind: Color::LightRed,
+        highlight: Color::LightYellow,
+        disabled: Color::Gray,
+        border: Color::White,
+        background: Color::Black,
+        ascii: false,
+    };
+
+    /// Returns the color of cards of the given suit.
+    #[must_use = "Suit color must be used."]
+    #[inline]
+    pub const fn suit_color(&self, suit: Suit) -> Color {
+        match suit {
+            Suit::Club => self.club,
+            Suit::Diamond => self.diamond,
+            Suit::Heart => self.heart,
+            Suit::Spade => self.spade,
+        }
+    }
+
+    /// Returns the color of the given blind.
+    #[must_use = "Blind color must be used."]
+    #[inline]
+    pub const fn blind_color(&self, blind: Blind) -> Color {
+        match blind {
+            Blind::Small => self.small_blind,
+            Blind::Big => self.big_blind,
+            Blind::Boss(_) => self.boss_blind,
+        }
+    }
+
+    /// Returns the glyph of the given suit, ie, `C`, `D`, `H` or `S` in ASCII
+    /// mode.
+    #[must_use = "Suit symbol must be used."]
+    #[inline]
+    pub fn suit_symbol(&self, suit: Suit) -> String {
+        if self.ascii {
+            suit.get_ascii()
+        } else {
+            suit.get_display()
+        }
+    }
+
+    /// Returns the rank and suit of the given card, eg, `10H` in ASCII mode.
+    #[must_use = "Card symbol must be used."]
+    #[inline]
+    pub fn card_symbol(&self, card: &Card) -> String {
+        if self.ascii {
+            format!("{card:#}")
+        } else {
+            card.to_string()
+        }
+    }
+
+    /// Returns the `unicode` glyph, or its `ascii` replacement in ASCII mode.
+    #[must_use = "Glyph must be used."]
+    #[inline]
+    pub const fn glyph<'glyph>(&self, unicode: &'glyph str, ascii: &'glyph str) -> &'glyph str {
+        if self.ascii {
+            ascii
+        } else {
+            unicode
+        }
+    }
+
+    /// Returns the given border set, or its ASCII replacement in ASCII mode.
+    /// Thick and double borders are replaced with `#` and `=` so that they
+    /// remain distinguishable from other borders.
+    #[must_use = "Border set must be used."]
+    #[inline]
+    pub fn border_set(&self, set: Set) -> Set {
+        if !self.ascii {
+            set
+        } else if set == border::THICK || set == border::DOUBLE {
+            ASCII_THICK_BORDER
+        } else {
+            ASCII_BORDER
+        }
+    }
+
+    /// Returns the base style of widgets, ie, the background color.
+    #[must_use = "Base style must be used."]
+    #[inline]
+    pub const fn style(&self) -> Style {
+        Style::new().bg(self.background)
+    }
+
+    /// Returns a bordered [`Block`] styled with the border and background
+    /// colors.
+    #[must_use = "Themed block must be used."]
+    #[inline]
+    pub fn block(&self) -> Block<'static> {
+        Block::bordered()
+            .border_set(self.border_set(border::ROUNDED))
+            .border_style(Style::new().fg(self.border))
+            .style(self.style())
+    }
+}
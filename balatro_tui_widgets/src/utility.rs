@@ -1,15 +1,21 @@
+//! This module provides utility functions shared across widgets.
+
 use ratatui::{
-    style::{Color, Style, Styled},
+    style::{Style, Styled},
     text::{Line, Span},
 };
 
-/// Returns line widget with chip icon prepended
+use super::theme::Theme;
+
+/// Returns line widget with chip icon prepended, or `$` in ASCII mode.
 pub(crate) fn get_line_with_chips<'widget, T: Into<Span<'widget>>>(
     content: T,
-    color: Color,
+    theme: Theme,
 ) -> Line<'widget> {
     Line::from(vec![
-        "\u{26c0}".set_style(Style::new().fg(color)),
+        theme
+            .glyph("\u{26c0}", "$")
+            .set_style(Style::new().fg(theme.chips)),
         "  ".into(),
         content.into(),
     ])